@@ -0,0 +1,84 @@
+//! Criterion baselines for the seed-search hot path and the record/header
+//! codec, so changes to `find_seed_match`, the hashers, or the header
+//! bit-packing can be checked against a measured before/after rather than
+//! vibes.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use telomere::hasher::{Blake3Expander, SeedExpander, Sha256Expander};
+use telomere::{
+    compress_with_config, decode_lotus_header, decompress, encode_lotus_header, find_seed_match,
+    pack_bits, Config,
+};
+
+fn bench_expand_seed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expand_seed");
+    let seed = [0x2a_u8; 2];
+    let mut out = [0u8; 64];
+    group.bench_function("blake3", |b| {
+        b.iter(|| Blake3Expander.expand_into(black_box(&seed), &mut out))
+    });
+    group.bench_function("sha256", |b| {
+        b.iter(|| Sha256Expander.expand_into(black_box(&seed), &mut out))
+    });
+    group.finish();
+}
+
+fn bench_find_seed_match(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_seed_match");
+    for &max_seed_len in &[1usize, 2, 3] {
+        // A slice that can't match any seed at this depth: forces the full
+        // brute-force scan of every length bucket, the worst case callers
+        // hit on a miss.
+        let slice = vec![0x5a_u8; 4];
+        group.bench_with_input(
+            BenchmarkId::from_parameter(max_seed_len),
+            &max_seed_len,
+            |b, &max_seed_len| {
+                b.iter(|| {
+                    find_seed_match(black_box(&slice), max_seed_len, &Blake3Expander).unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_header_codec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("header_codec");
+    group.bench_function("encode_v1_record", |b| {
+        b.iter(|| encode_lotus_header(black_box(3), black_box(65535)).unwrap())
+    });
+    let bits = encode_lotus_header(3, 65535).unwrap();
+    let bytes = pack_bits(&bits);
+    group.bench_function("decode_v1_record", |b| {
+        b.iter(|| decode_lotus_header(black_box(&bytes)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_round_trip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("round_trip_small_file");
+    let config = Config {
+        block_size: 4,
+        max_seed_len: 2,
+        ..Config::default()
+    };
+    let original = vec![0xAB_u8; 4096];
+    group.bench_function("compress", |b| {
+        b.iter(|| compress_with_config(black_box(&original), &config).unwrap())
+    });
+    let encoded = compress_with_config(&original, &config).unwrap();
+    group.bench_function("decompress", |b| {
+        b.iter(|| decompress(black_box(&encoded), &config).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_expand_seed,
+    bench_find_seed_match,
+    bench_header_codec,
+    bench_round_trip
+);
+criterion_main!(benches);
@@ -0,0 +1,348 @@
+//! Per-block seed-category classification against a precomputed hash table.
+//!
+//! Backs `telomere analyze`: for each fixed-size block of an input file,
+//! reports whether [`crate::hash_table`] has a known seed that hashes to the
+//! block's digest prefix, and if so the shortest such seed's length. This
+//! replaces the `block_histogram` bin, which duplicated the hash table's
+//! mmap/binary-search logic instead of calling into it.
+
+use crate::hash_table::{find_hash_table, seed_bit_length, HashEntry};
+use crate::header::v1_record_bit_len;
+use crate::seed_index::seed_to_index;
+use crate::TelomereError;
+#[cfg(feature = "native-io")]
+use csv::Writer;
+use serde::Serialize;
+use std::fs::File;
+use std::path::Path;
+
+/// Shortest known-seed length that reproduces a block, or `Literal` if none
+/// of the table's seeds fall within the requested bit-length range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SeedCategory {
+    #[serde(rename = "seed-1")]
+    Seed1,
+    #[serde(rename = "seed-2")]
+    Seed2,
+    #[serde(rename = "seed-3")]
+    Seed3,
+    #[serde(rename = "literal")]
+    Literal,
+}
+
+impl std::fmt::Display for SeedCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SeedCategory::Seed1 => "seed-1",
+            SeedCategory::Seed2 => "seed-2",
+            SeedCategory::Seed3 => "seed-3",
+            SeedCategory::Literal => "literal",
+        })
+    }
+}
+
+/// One block's classification, in input order.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockRecord {
+    pub index: usize,
+    pub category: SeedCategory,
+}
+
+/// Aggregate counts across a [`classify_blocks`] run.
+#[derive(Debug, Clone, Serialize)]
+pub struct HistogramSummary {
+    pub total_blocks: usize,
+    pub seed_1: u64,
+    pub seed_2: u64,
+    pub seed_3: u64,
+    pub literal: u64,
+}
+
+impl HistogramSummary {
+    fn pct(count: u64, total: usize) -> f64 {
+        if total == 0 {
+            0.0
+        } else {
+            100.0 * count as f64 / total as f64
+        }
+    }
+
+    /// Logs the histogram via `tracing` rather than printing directly, so
+    /// embedders can route or silence it like the rest of the library's
+    /// output (see [`crate::print_compression_status`]).
+    pub fn print_summary(&self) {
+        tracing::info!("#blocks: {}", self.total_blocks);
+        tracing::info!(
+            "#1-byte seed: {} ({:.1}%)",
+            self.seed_1,
+            Self::pct(self.seed_1, self.total_blocks)
+        );
+        tracing::info!(
+            "#2-byte seed: {} ({:.1}%)",
+            self.seed_2,
+            Self::pct(self.seed_2, self.total_blocks)
+        );
+        tracing::info!(
+            "#3-byte seed: {} ({:.1}%)",
+            self.seed_3,
+            Self::pct(self.seed_3, self.total_blocks)
+        );
+        tracing::info!(
+            "#literal: {} ({:.1}%)",
+            self.literal,
+            Self::pct(self.literal, self.total_blocks)
+        );
+    }
+}
+
+/// Shortest table entry, if any, whose seed reproduces `block` and whose
+/// seed bit length falls within `min_bits..=max_bits`. `entries` must be
+/// sorted by `hash_prefix` ascending, as
+/// [`crate::hash_table::build_hash_table`] returns them.
+fn find_best_entry<'a>(
+    entries: &'a [HashEntry],
+    block: &[u8],
+    min_bits: u32,
+    max_bits: u32,
+) -> Option<&'a HashEntry> {
+    let (_, matches) = find_hash_table(entries, block);
+    matches
+        .into_iter()
+        .filter(|entry| {
+            let len = entry.seed_len as usize;
+            if len == 0 || len > entry.seed.len() {
+                return false;
+            }
+            let bits = seed_bit_length(&entry.seed[..len]);
+            bits >= min_bits && bits <= max_bits
+        })
+        .min_by_key(|entry| entry.seed_len)
+}
+
+/// Classify the shortest seed length, if any, matching `block`'s digest
+/// prefix within `min_bits..=max_bits`.
+fn classify_block(entries: &[HashEntry], block: &[u8], min_bits: u32, max_bits: u32) -> SeedCategory {
+    match find_best_entry(entries, block, min_bits, max_bits).map(|entry| entry.seed_len) {
+        Some(1) => SeedCategory::Seed1,
+        Some(2) => SeedCategory::Seed2,
+        Some(3) => SeedCategory::Seed3,
+        _ => SeedCategory::Literal,
+    }
+}
+
+/// Exact wire bits saved by encoding `block` as a v1 arity-1 record against
+/// its shortest known-seed match, or `0` if the table has none within
+/// `min_bits..=max_bits`. Used to build a compressibility heatmap: regions
+/// where this stays near zero are resisting the generative search.
+fn block_bits_saved(entries: &[HashEntry], block: &[u8], min_bits: u32, max_bits: u32) -> i64 {
+    let Some(entry) = find_best_entry(entries, block, min_bits, max_bits) else {
+        return 0;
+    };
+    let len = entry.seed_len as usize;
+    let seed_index = seed_to_index(&entry.seed[..len], len);
+    let Ok(total_bits) = v1_record_bit_len(1, seed_index as u64) else {
+        return 0;
+    };
+    block.len() as i64 * 8 - total_bits as i64
+}
+
+/// Split `input` into `block_size`-byte chunks (the final chunk may be
+/// shorter) and classify each against `entries`. Returns the per-block
+/// records in input order alongside the aggregate histogram.
+pub fn classify_blocks(
+    input: &[u8],
+    block_size: usize,
+    entries: &[HashEntry],
+    min_bits: u32,
+    max_bits: u32,
+) -> (Vec<BlockRecord>, HistogramSummary) {
+    let mut records = Vec::with_capacity(input.len().div_ceil(block_size.max(1)));
+    let mut summary = HistogramSummary {
+        total_blocks: 0,
+        seed_1: 0,
+        seed_2: 0,
+        seed_3: 0,
+        literal: 0,
+    };
+
+    for (index, block) in input.chunks(block_size.max(1)).enumerate() {
+        let category = classify_block(entries, block, min_bits, max_bits);
+        match category {
+            SeedCategory::Seed1 => summary.seed_1 += 1,
+            SeedCategory::Seed2 => summary.seed_2 += 1,
+            SeedCategory::Seed3 => summary.seed_3 += 1,
+            SeedCategory::Literal => summary.literal += 1,
+        }
+        summary.total_blocks += 1;
+        records.push(BlockRecord { index, category });
+    }
+
+    (records, summary)
+}
+
+/// Write `records` as `index,category` rows to `path`.
+#[cfg(feature = "native-io")]
+pub fn write_records_csv(records: &[BlockRecord], path: &Path) -> Result<(), TelomereError> {
+    let file = File::create(path).map_err(TelomereError::from)?;
+    let mut wtr = Writer::from_writer(file);
+    wtr.write_record(["index", "category"])
+        .map_err(|e| TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    for record in records {
+        wtr.write_record([record.index.to_string(), record.category.to_string()])
+            .map_err(|e| TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    }
+    wtr.flush().map_err(TelomereError::from)
+}
+
+/// Write `records` as pretty-printed JSON to `path`.
+pub fn write_records_json(records: &[BlockRecord], path: &Path) -> Result<(), TelomereError> {
+    let mut file = File::create(path).map_err(TelomereError::from)?;
+    serde_json::to_writer_pretty(&mut file, records)
+        .map_err(|e| TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    use std::io::Write;
+    file.write_all(b"\n").map_err(TelomereError::from)
+}
+
+/// One window's compressibility score, in input order.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompressibilityWindow {
+    pub window_index: usize,
+    pub start_block: usize,
+    pub block_count: usize,
+    pub bits_saved: i64,
+}
+
+/// Split `input` into `block_size`-byte blocks, group consecutive blocks
+/// into `window_blocks`-sized windows (the final window may be shorter),
+/// and sum each window's [`block_bits_saved`] against `entries`.
+///
+/// This is the data behind the compressibility heatmap: a window whose
+/// `bits_saved` stays near zero is a region of the file the generative
+/// search could not find short seeds for.
+pub fn compute_compressibility_windows(
+    input: &[u8],
+    block_size: usize,
+    window_blocks: usize,
+    entries: &[HashEntry],
+    min_bits: u32,
+    max_bits: u32,
+) -> Vec<CompressibilityWindow> {
+    let window_blocks = window_blocks.max(1);
+    input
+        .chunks(block_size.max(1))
+        .collect::<Vec<_>>()
+        .chunks(window_blocks)
+        .enumerate()
+        .map(|(window_index, window)| CompressibilityWindow {
+            window_index,
+            start_block: window_index * window_blocks,
+            block_count: window.len(),
+            bits_saved: window
+                .iter()
+                .map(|block| block_bits_saved(entries, block, min_bits, max_bits))
+                .sum(),
+        })
+        .collect()
+}
+
+/// Write `windows` as `window_index,start_block,block_count,bits_saved`
+/// rows to `path`.
+#[cfg(feature = "native-io")]
+pub fn write_windows_csv(windows: &[CompressibilityWindow], path: &Path) -> Result<(), TelomereError> {
+    let file = File::create(path).map_err(TelomereError::from)?;
+    let mut wtr = Writer::from_writer(file);
+    wtr.write_record(["window_index", "start_block", "block_count", "bits_saved"])
+        .map_err(|e| TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    for window in windows {
+        wtr.write_record([
+            window.window_index.to_string(),
+            window.start_block.to_string(),
+            window.block_count.to_string(),
+            window.bits_saved.to_string(),
+        ])
+        .map_err(|e| TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    }
+    wtr.flush().map_err(TelomereError::from)
+}
+
+/// Write `windows` as pretty-printed JSON to `path`.
+pub fn write_windows_json(windows: &[CompressibilityWindow], path: &Path) -> Result<(), TelomereError> {
+    let mut file = File::create(path).map_err(TelomereError::from)?;
+    serde_json::to_writer_pretty(&mut file, windows)
+        .map_err(|e| TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    use std::io::Write;
+    file.write_all(b"\n").map_err(TelomereError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_table::build_hash_table;
+
+    #[test]
+    fn classifies_known_single_byte_seed_as_seed_1() {
+        let entries = build_hash_table(1).unwrap();
+        let category = classify_block(&entries, &[0x2A], 1, 256);
+        assert_eq!(category, SeedCategory::Seed1);
+    }
+
+    #[test]
+    fn classifies_unmatched_block_as_literal() {
+        let entries = build_hash_table(1).unwrap();
+        let category = classify_block(&entries, b"not a known seed span", 1, 256);
+        assert_eq!(category, SeedCategory::Literal);
+    }
+
+    #[test]
+    fn classify_blocks_tallies_histogram_and_records_in_order() {
+        let entries = build_hash_table(1).unwrap();
+        // 0x00 has a seed_bit_length of 0 (an all-zero seed carries no
+        // significant bits), so it falls below min_bits=1 and classifies as
+        // a literal; only 0x2A counts as a Seed1 match.
+        let input = [0x2A, 0x00];
+        let (records, summary) = classify_blocks(&input, 1, &entries, 1, 256);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].index, 0);
+        assert_eq!(records[0].category, SeedCategory::Seed1);
+        assert_eq!(records[1].category, SeedCategory::Literal);
+        assert_eq!(summary.total_blocks, 2);
+        assert_eq!(summary.seed_1, 1);
+        assert_eq!(summary.literal, 1);
+    }
+
+    #[test]
+    fn compressibility_window_scores_known_seed_at_its_record_cost() {
+        let entries = build_hash_table(1).unwrap();
+        // 0x2A is a known 1-byte seed (index 42), so the block is scored at
+        // its exact wire cost: 8 bits minus the arity-1 record for seed 42.
+        // That record is longer than the byte itself, so the score is negative.
+        let windows = compute_compressibility_windows(&[0x2A], 1, 1, &entries, 1, 256);
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].start_block, 0);
+        assert_eq!(windows[0].block_count, 1);
+        let record_bits = v1_record_bit_len(1, 42).unwrap() as i64;
+        assert_eq!(windows[0].bits_saved, 8 - record_bits);
+        assert!(windows[0].bits_saved < 0);
+    }
+
+    #[test]
+    fn compressibility_window_scores_unmatched_block_as_zero() {
+        let entries = build_hash_table(1).unwrap();
+        let windows =
+            compute_compressibility_windows(b"not a known seed span", 32, 1, &entries, 1, 256);
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].bits_saved, 0);
+    }
+
+    #[test]
+    fn compressibility_windows_group_multiple_blocks() {
+        let entries = build_hash_table(1).unwrap();
+        let input = [0x2A, 0x00, 0xFF];
+        let windows = compute_compressibility_windows(&input, 1, 2, &entries, 1, 256);
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows[0].block_count, 2);
+        assert_eq!(windows[0].start_block, 0);
+        assert_eq!(windows[1].block_count, 1);
+        assert_eq!(windows[1].start_block, 2);
+    }
+}
@@ -0,0 +1,176 @@
+//! `futures_io::AsyncRead`/`AsyncWrite` adaptors over the `.tlmr` codec,
+//! behind the `async-io` feature, for embedders (async file servers,
+//! `async-compression`-style pipelines) that want Telomere to sit next to
+//! gzip/zstd codecs without pulling the whole crate onto an async runtime.
+//!
+//! These mirror [`crate::TelomereReader`]/[`crate::TelomereWriter`] and
+//! share their limitation: the codec only knows how to (de)compress a
+//! complete buffer, so [`AsyncTelomereReader`] buffers `inner` to EOF before
+//! decoding, and [`AsyncTelomereWriter`] only compresses and flushes on
+//! [`AsyncTelomereWriter::poll_close`].
+
+use crate::{compress_with_config, decompress_with_limit, Config, TelomereError};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+fn to_io_error(err: TelomereError) -> io::Error {
+    match err {
+        TelomereError::Io(e) => e,
+        other => io::Error::new(io::ErrorKind::InvalidData, other.to_string()),
+    }
+}
+
+/// Async counterpart to [`crate::TelomereReader`].
+pub struct AsyncTelomereReader<R> {
+    inner: R,
+    config: Config,
+    limit: usize,
+    encoded: Vec<u8>,
+    decoded: Option<Vec<u8>>,
+    position: usize,
+}
+
+impl<R: AsyncRead + Unpin> AsyncTelomereReader<R> {
+    pub fn new(inner: R, config: Config) -> Self {
+        Self::with_limit(inner, config, usize::MAX)
+    }
+
+    pub fn with_limit(inner: R, config: Config, limit: usize) -> Self {
+        Self {
+            inner,
+            config,
+            limit,
+            encoded: Vec::new(),
+            decoded: None,
+            position: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncTelomereReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if this.decoded.is_none() {
+            let mut scratch = [0u8; 8192];
+            loop {
+                match Pin::new(&mut this.inner).poll_read(cx, &mut scratch) {
+                    Poll::Ready(Ok(0)) => {
+                        let decoded =
+                            decompress_with_limit(&this.encoded, &this.config, this.limit)
+                                .map_err(to_io_error)?;
+                        this.decoded = Some(decoded);
+                        break;
+                    }
+                    Poll::Ready(Ok(n)) => this.encoded.extend_from_slice(&scratch[..n]),
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+        let decoded = this.decoded.as_ref().expect("decoded above");
+        let remaining = &decoded[this.position..];
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        this.position += n;
+        Poll::Ready(Ok(n))
+    }
+}
+
+/// Async counterpart to [`crate::TelomereWriter`].
+pub struct AsyncTelomereWriter<W> {
+    inner: W,
+    config: Config,
+    buffer: Vec<u8>,
+    closing: Option<(Vec<u8>, usize)>,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncTelomereWriter<W> {
+    pub fn new(inner: W, config: Config) -> Self {
+        Self {
+            inner,
+            config,
+            buffer: Vec::new(),
+            closing: None,
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncTelomereWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Nothing to push to `inner` yet: the codec only compresses a
+        // complete buffer, emitted from `poll_close`.
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.closing.is_none() {
+            let encoded = match compress_with_config(&this.buffer, &this.config) {
+                Ok(encoded) => encoded,
+                Err(e) => return Poll::Ready(Err(to_io_error(e))),
+            };
+            this.closing = Some((encoded, 0));
+        }
+        let (encoded, pos) = this.closing.as_mut().expect("set above");
+        while *pos < encoded.len() {
+            match Pin::new(&mut this.inner).poll_write(cx, &encoded[*pos..]) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write compressed output",
+                    )))
+                }
+                Poll::Ready(Ok(n)) => *pos += n,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+
+    #[test]
+    fn async_round_trip_matches_sync_codec() {
+        futures::executor::block_on(async {
+            let config = Config {
+                block_size: 4,
+                max_seed_len: 1,
+                ..Config::default()
+            };
+            let original = b"abcdabcdabcdabcd".to_vec();
+
+            let mut writer = AsyncTelomereWriter::new(Cursor::new(Vec::new()), config.clone());
+            writer.write_all(&original).await.unwrap();
+            writer.close().await.unwrap();
+            let encoded = writer.inner.into_inner();
+
+            let expected = compress_with_config(&original, &config).unwrap();
+            assert_eq!(encoded, expected);
+
+            let mut reader = AsyncTelomereReader::new(Cursor::new(encoded), config);
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).await.unwrap();
+            assert_eq!(out, original);
+        });
+    }
+}
@@ -0,0 +1,254 @@
+//! `telomere mount ARCHIVE MOUNTPOINT`: a read-only FUSE view over a
+//! `dir.tar.tlmr` archive (see `telomere::archive`), so a compressed
+//! archive can be browsed and read from like any other directory without
+//! extracting it to disk first.
+//!
+//! The whole archive is decoded once, eagerly, at mount time and served
+//! out of memory — the tar member list maps to a flat root directory of
+//! inodes. A tar member's path can contain `/` for nested directories,
+//! which isn't a valid single path component, so [`flatten_name`] rewrites
+//! it to `_` (with a `~N` suffix on collision) rather than building real
+//! subdirectory inodes. Lazy, seek-only decode driven by the on-disk
+//! random-access seed index (`telomere::seed_expansion_index`) is follow-up
+//! work; today's `.tlmr` payloads aren't block-addressable from a tar
+//! member's byte range.
+
+use clap::Parser;
+use fuser::{FileAttr, FileType, Filesystem, MountOption, ReplyAttr, ReplyData, ReplyDirectory, ReplyEntry, Request};
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::io::Read;
+use std::path::PathBuf;
+use std::time::{Duration, UNIX_EPOCH};
+use telomere::{Config, TelomereReader};
+
+const TTL: Duration = Duration::from_secs(1);
+const ROOT_INO: u64 = 1;
+
+/// Mount a `dir.tar.tlmr` archive read-only over FUSE.
+#[derive(Parser)]
+struct Args {
+    /// Path to the `dir.tar.tlmr` archive
+    archive: PathBuf,
+    /// Directory to mount the archive's contents at
+    mountpoint: PathBuf,
+    /// Block size the archive was compressed with
+    #[arg(long, default_value_t = 4)]
+    block_size: usize,
+    /// Maximum seed length the archive was compressed with
+    #[arg(long, default_value_t = 1)]
+    max_seed_len: usize,
+}
+
+struct ArchiveEntry {
+    name: String,
+    data: Vec<u8>,
+}
+
+struct ArchiveFs {
+    entries: Vec<ArchiveEntry>,
+    name_to_ino: HashMap<String, u64>,
+}
+
+impl ArchiveFs {
+    fn file_attr(&self, ino: u64, size: u64) -> FileAttr {
+        let now = UNIX_EPOCH;
+        FileAttr {
+            ino,
+            size,
+            blocks: size.div_ceil(512),
+            atime: now,
+            mtime: now,
+            ctime: now,
+            crtime: now,
+            kind: FileType::RegularFile,
+            perm: 0o444,
+            nlink: 1,
+            uid: 0,
+            gid: 0,
+            rdev: 0,
+            blksize: 512,
+            flags: 0,
+        }
+    }
+
+    fn dir_attr(&self, ino: u64) -> FileAttr {
+        let now = UNIX_EPOCH;
+        FileAttr {
+            ino,
+            size: 0,
+            blocks: 0,
+            atime: now,
+            mtime: now,
+            ctime: now,
+            crtime: now,
+            kind: FileType::Directory,
+            perm: 0o555,
+            nlink: 2,
+            uid: 0,
+            gid: 0,
+            rdev: 0,
+            blksize: 512,
+            flags: 0,
+        }
+    }
+
+    /// Entry inodes start at 2; inode 1 is the mount root.
+    fn entry(&self, ino: u64) -> Option<&ArchiveEntry> {
+        let idx = ino.checked_sub(2)? as usize;
+        self.entries.get(idx)
+    }
+}
+
+impl Filesystem for ArchiveFs {
+    fn lookup(&mut self, _req: &Request, parent: u64, name: &OsStr, reply: ReplyEntry) {
+        if parent != ROOT_INO {
+            reply.error(libc::ENOENT);
+            return;
+        }
+        let Some(name) = name.to_str() else {
+            reply.error(libc::ENOENT);
+            return;
+        };
+        match self.name_to_ino.get(name) {
+            Some(&ino) => {
+                let size = self.entry(ino).map(|e| e.data.len() as u64).unwrap_or(0);
+                reply.entry(&TTL, &self.file_attr(ino, size), 0);
+            }
+            None => reply.error(libc::ENOENT),
+        }
+    }
+
+    fn getattr(&mut self, _req: &Request, ino: u64, _fh: Option<u64>, reply: ReplyAttr) {
+        if ino == ROOT_INO {
+            reply.attr(&TTL, &self.dir_attr(ROOT_INO));
+            return;
+        }
+        match self.entry(ino) {
+            Some(entry) => reply.attr(&TTL, &self.file_attr(ino, entry.data.len() as u64)),
+            None => reply.error(libc::ENOENT),
+        }
+    }
+
+    fn read(
+        &mut self,
+        _req: &Request,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        size: u32,
+        _flags: i32,
+        _lock_owner: Option<u64>,
+        reply: ReplyData,
+    ) {
+        let Some(entry) = self.entry(ino) else {
+            reply.error(libc::ENOENT);
+            return;
+        };
+        let offset = offset.max(0) as usize;
+        if offset >= entry.data.len() {
+            reply.data(&[]);
+            return;
+        }
+        let end = (offset + size as usize).min(entry.data.len());
+        reply.data(&entry.data[offset..end]);
+    }
+
+    fn readdir(
+        &mut self,
+        _req: &Request,
+        ino: u64,
+        _fh: u64,
+        offset: i64,
+        mut reply: ReplyDirectory,
+    ) {
+        if ino != ROOT_INO {
+            reply.error(libc::ENOENT);
+            return;
+        }
+        let mut all = vec![
+            (ROOT_INO, FileType::Directory, ".".to_string()),
+            (ROOT_INO, FileType::Directory, "..".to_string()),
+        ];
+        for (idx, entry) in self.entries.iter().enumerate() {
+            all.push((idx as u64 + 2, FileType::RegularFile, entry.name.clone()));
+        }
+        for (i, (ino, kind, name)) in all.into_iter().enumerate().skip(offset as usize) {
+            if reply.add(ino, (i + 1) as i64, kind, name) {
+                break;
+            }
+        }
+        reply.ok();
+    }
+}
+
+/// Flatten a tar member's path into a single valid FUSE dentry name for the
+/// flat root directory this filesystem exposes (see the module doc comment
+/// for why there's no real subdirectory structure yet). A path separator is
+/// not a valid single path component, so `/` is rewritten to `_`; a nested
+/// entry like `"sub/b.txt"` becomes `"sub_b.txt"` at the root.
+///
+/// That rewrite can collide two distinct paths into the same flat name
+/// (e.g. `"a/b"` and `"a_b"` both flatten to `"a_b"`). Rather than silently
+/// dropping the later entry, give it a numeric `~N` suffix so every archive
+/// member stays reachable, even if its name at the mount root isn't the one
+/// it had in the archive.
+fn flatten_name(path: &str, used: &HashMap<String, u64>) -> String {
+    let flat = path.replace('/', "_");
+    if !used.contains_key(&flat) {
+        return flat;
+    }
+    let mut suffix = 1u64;
+    loop {
+        let candidate = format!("{flat}~{suffix}");
+        if !used.contains_key(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let config = Config {
+        block_size: args.block_size,
+        max_seed_len: args.max_seed_len,
+        ..Config::default()
+    };
+
+    let file = std::fs::File::open(&args.archive)?;
+    let mut reader = TelomereReader::new(file, config);
+    let mut tar_bytes = Vec::new();
+    reader.read_to_end(&mut tar_bytes)?;
+
+    let mut archive = tar::Archive::new(tar_bytes.as_slice());
+    let mut entries = Vec::new();
+    let mut name_to_ino = HashMap::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let name = flatten_name(&name, &name_to_ino);
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        name_to_ino.insert(name.clone(), entries.len() as u64 + 2);
+        entries.push(ArchiveEntry { name, data });
+    }
+
+    let fs = ArchiveFs {
+        entries,
+        name_to_ino,
+    };
+    let options = vec![MountOption::RO, MountOption::FSName("telomere".to_string())];
+    fuser::mount2(fs, &args.mountpoint, &options)?;
+    Ok(())
+}
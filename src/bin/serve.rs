@@ -0,0 +1,151 @@
+//! `telomere serve`: a small blocking HTTP server exposing the codec as
+//! `POST /compress` and `POST /decompress`, for fleets that want a central
+//! Telomere service instead of linking the crate into every caller.
+//!
+//! Request/response bodies are raw bytes (not multipart or JSON), and the
+//! config is carried in query parameters, e.g.
+//! `POST /compress?block_size=4&max_seed_len=2`.
+
+use clap::Parser;
+use std::io::Read;
+use telomere::{compress_with_config, decompress_with_limit, Config};
+use tiny_http::{Method, Response, Server};
+
+/// Run the Telomere HTTP compression service.
+#[derive(Parser)]
+struct Args {
+    /// Address to bind, e.g. 0.0.0.0:8080
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    addr: String,
+}
+
+/// Hard ceiling on a request body, independent of `config.memory_limit`, so
+/// a fleet operator who never set `memory_limit` still gets a bound instead
+/// of `usize::MAX`. The effective cap is the smaller of this and
+/// `config.memory_limit` — see the body-reading loop below.
+const MAX_BODY_BYTES: u64 = 64 * 1024 * 1024;
+
+fn main() {
+    let args = Args::parse();
+    let server = Server::http(&args.addr).unwrap_or_else(|e| {
+        eprintln!("failed to bind {}: {e}", args.addr);
+        std::process::exit(1);
+    });
+    eprintln!("telomere serve listening on {}", args.addr);
+
+    for mut request in server.incoming_requests() {
+        let url = request.url().to_string();
+        let method = request.method().clone();
+        let path = url.split('?').next().unwrap_or("").to_string();
+
+        // Reject unsupported routes/methods before touching the body at
+        // all, so a flood of junk requests can't force an unbounded read
+        // per request just to find out they'd be rejected anyway.
+        if !matches!(
+            (&method, path.as_str()),
+            (Method::Post, "/compress") | (Method::Post, "/decompress")
+        ) {
+            let _ = request.respond(
+                Response::from_string(format!(
+                    "unsupported {method} {path}, expected POST /compress or POST /decompress"
+                ))
+                .with_status_code(400),
+            );
+            continue;
+        }
+
+        let config = match config_from_query(&url) {
+            Ok(config) => config,
+            Err(e) => {
+                let _ = request.respond(Response::from_string(e).with_status_code(400));
+                continue;
+            }
+        };
+
+        let body_cap = MAX_BODY_BYTES.min(config.memory_limit as u64);
+        if let Some(len) = request.body_length() {
+            if len as u64 > body_cap {
+                let _ = request.respond(
+                    Response::from_string(format!(
+                        "request body of {len} bytes exceeds the {body_cap}-byte limit"
+                    ))
+                    .with_status_code(413),
+                );
+                continue;
+            }
+        }
+
+        // `take` caps the read itself too, since a client can omit or lie
+        // about Content-Length; one extra byte of slack lets a body that
+        // lands exactly on the cap through without tripping the oversized
+        // check below.
+        let mut body = Vec::new();
+        if let Err(e) = request
+            .as_reader()
+            .take(body_cap.saturating_add(1))
+            .read_to_end(&mut body)
+        {
+            let _ = request.respond(
+                Response::from_string(format!("failed to read request body: {e}"))
+                    .with_status_code(400),
+            );
+            continue;
+        }
+        if body.len() as u64 > body_cap {
+            let _ = request.respond(
+                Response::from_string(format!("request body exceeds the {body_cap}-byte limit"))
+                    .with_status_code(413),
+            );
+            continue;
+        }
+
+        let result = match (&method, path.as_str()) {
+            (Method::Post, "/compress") => compress_with_config(&body, &config)
+                .map_err(|e| format!("compression failed: {e}")),
+            (Method::Post, "/decompress") => {
+                decompress_with_limit(&body, &config, config.memory_limit)
+                    .map_err(|e| format!("decompression failed: {e}"))
+            }
+            _ => unreachable!("unsupported routes were rejected above"),
+        };
+
+        let response = match result {
+            Ok(bytes) => Response::from_data(bytes),
+            Err(e) => Response::from_string(e).with_status_code(400),
+        };
+        let _ = request.respond(response);
+    }
+}
+
+/// Build a [`Config`] from query-string parameters, defaulting anything
+/// unspecified to [`Config::default`].
+fn config_from_query(url: &str) -> Result<Config, String> {
+    let mut config = Config::default();
+    let Some(query) = url.split_once('?').map(|(_, q)| q) else {
+        return Ok(config);
+    };
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("malformed query parameter: {pair}"))?;
+        let parse_usize = |value: &str| {
+            value
+                .parse::<usize>()
+                .map_err(|e| format!("invalid value for {key}: {e}"))
+        };
+        match key {
+            "block_size" => config.block_size = parse_usize(value)?,
+            "max_seed_len" => config.max_seed_len = parse_usize(value)?,
+            "max_arity" => {
+                config.max_arity = value
+                    .parse::<u8>()
+                    .map_err(|e| format!("invalid value for max_arity: {e}"))?
+            }
+            "hash_bits" => config.hash_bits = parse_usize(value)?,
+            "memory_limit" => config.memory_limit = parse_usize(value)?,
+            other => return Err(format!("unknown query parameter: {other}")),
+        }
+    }
+    config.validate().map_err(|e| e.to_string())?;
+    Ok(config)
+}
@@ -1,22 +1,33 @@
 //!
 //! A [`BlockStore`] stores all block data in a contiguous arena and manages
 //! metadata via compact `BlockRef` structures. This replaces the legacy
-//! allocator-heavy `BlockTable`.
-
-use hashbrown::HashMap;
+//! allocator-heavy `BlockTable`. Consumers that only need a window into a
+//! subset of blocks (e.g. [`crate::gpu::GpuSeedMatcher`]'s tiles) should hold
+//! [`BlockId`] handles plus a `&BlockStore` borrow rather than cloning bytes
+//! out of the arena a second time.
+
+use crate::hasher::SeedExpander;
+use crate::header::v1_record_bit_len;
+use crate::seed::find_seed_match;
+use crate::seed_index::index_to_seed;
+use bumpalo::collections::Vec as BumpVec;
+use bumpalo::Bump;
+use hashbrown::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// Handle to a block stored in the `BlockStore`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BlockId(pub u32);
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BranchStatus {
     Active,
     Pruned,
     Collapsed,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockRef {
     /// Offset into the data arena.
     pub offset: u32,
@@ -39,6 +50,7 @@ pub struct BlockRef {
 }
 
 /// A cache-friendly store for block data and metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockStore {
     /// Contiguous arena for block data.
     data_arena: Vec<u8>,
@@ -92,6 +104,18 @@ impl BlockStore {
         id
     }
 
+    /// Drop a block from the group index. The slot in `blocks`/`data_arena`
+    /// is left in place (handles stay valid); only the group index is
+    /// updated, in O(group size) rather than a scan over every group.
+    pub fn remove_block(&mut self, id: BlockId) {
+        let b = self.get_block(id);
+        let bit_len = b.bit_len as usize;
+
+        if let Some(group) = self.groups.get_mut(&bit_len) {
+            group.retain(|&g| g != id);
+        }
+    }
+
     pub fn get_data(&self, id: BlockId) -> &[u8] {
         let b = &self.blocks[id.0 as usize];
         &self.data_arena[b.offset as usize..(b.offset as usize + b.byte_len as usize)]
@@ -135,6 +159,22 @@ impl BlockStore {
     pub fn clear_empty(&mut self) {
         self.groups.retain(|_, v| !v.is_empty());
     }
+
+    /// Snapshot the data arena, groups, and branch state to `path` via
+    /// bincode, so long runs can checkpoint the search lattice for offline
+    /// analysis or for the resume feature.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), crate::TelomereError> {
+        let bytes = bincode::serialize(self)
+            .map_err(|e| crate::TelomereError::Header(format!("block store snapshot: {e}")))?;
+        std::fs::write(path, bytes).map_err(crate::TelomereError::Io)
+    }
+
+    /// Load a snapshot previously written by [`BlockStore::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, crate::TelomereError> {
+        let bytes = std::fs::read(path).map_err(crate::TelomereError::Io)?;
+        bincode::deserialize(&bytes)
+            .map_err(|e| crate::TelomereError::Header(format!("block store snapshot: {e}")))
+    }
 }
 
 /// Split raw input into fixed-sized blocks and populate a store.
@@ -159,56 +199,424 @@ pub fn split_into_blocks(input: &[u8], block_size_bits: usize) -> BlockStore {
     store
 }
 
-/// Simulate a compression pass (legacy compat).
-/// Note: This function previously mutated the table significantly.
-/// We'll adapt it to work with BlockStore.
-#[allow(dead_code)]
-pub fn simulate_pass(store: &mut BlockStore, seed_table: &HashMap<String, usize>) -> usize {
+/// A found seed match, cheap enough to cache by content digest: the seed
+/// index plus its already-priced wire cost. Named `BlockSeedMatch` (rather
+/// than the shorter `SeedMatch`) because [`crate::block_indexer::SeedMatch`]
+/// already owns that name for an unrelated type.
+pub type BlockSeedMatch = (u64, usize);
+
+/// Cache of [`find_seed_match`] outcomes keyed by block content digest
+/// (`None` meaning "searched, no match"). Blocks are content-addressed by
+/// [`BlockRef::digest`], so a span that merges or otherwise changes bytes
+/// gets a different digest and therefore a fresh cache miss rather than a
+/// stale hit — no separate invalidation step is needed.
+///
+/// A single cache is shared across every pass of one [`simulate_passes`]
+/// run. Callers that drive several separate top-level `simulate_passes*`
+/// calls over time (e.g. re-running at a larger `max_seed_len` after a first
+/// pass budget runs out) can keep amortizing it across those calls too by
+/// building one with [`SeedMatchCache::new`] themselves and passing it to
+/// [`simulate_passes_with_cache`] instead of letting each call start cold.
+pub type SeedMatchCache = HashMap<[u8; 32], Option<BlockSeedMatch>>;
+
+/// Hit/miss counters for a [`SeedMatchCache`] over one [`simulate_pass`] or
+/// [`simulate_passes`] run, so callers can tell whether the cache is earning
+/// its memory before tuning its sizing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl CacheStats {
+    /// Fraction of lookups satisfied by an existing entry, `0.0` if the
+    /// cache has not been queried yet.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Per-seed-length attempt/hit/gain counters accumulated across one or more
+/// [`simulate_pass_over`] calls, indexed `0..max_seed_len` for lengths
+/// `1..=max_seed_len`.
+///
+/// `attempts[len - 1]` counts blocks that actually reached that length's
+/// bucket (i.e. did not already match at a shorter length); `hits[len - 1]`
+/// and `bits_saved[len - 1]` count how many of those matched there and how
+/// many bits that saved in total. [`simulate_passes_with_cache`] uses this to
+/// decide whether the next pass should keep probing a length at all, instead
+/// of re-running a bucket that has already shown it does not pay for itself.
+#[derive(Debug, Clone)]
+pub struct SeedLengthStats {
+    pub attempts: Vec<usize>,
+    pub hits: Vec<usize>,
+    pub bits_saved: Vec<i64>,
+}
+
+impl SeedLengthStats {
+    pub fn new(max_seed_len: usize) -> Self {
+        SeedLengthStats {
+            attempts: vec![0; max_seed_len],
+            hits: vec![0; max_seed_len],
+            bits_saved: vec![0; max_seed_len],
+        }
+    }
+
+    /// Record one block's outcome: `matched_len` is the seed length it
+    /// matched at, or `None` on a miss. `searched_up_to` is the
+    /// `max_seed_len` actually used for this block's search, which may be
+    /// smaller than `self.attempts.len()` once an earlier pass has already
+    /// narrowed it.
+    fn record_block(
+        &mut self,
+        matched_len: Option<usize>,
+        searched_up_to: usize,
+        original_bits: usize,
+        total_bits: usize,
+    ) {
+        let attempted_up_to = matched_len
+            .unwrap_or(searched_up_to)
+            .min(self.attempts.len());
+        for len in 1..=attempted_up_to {
+            self.attempts[len - 1] += 1;
+        }
+        if let Some(len) = matched_len {
+            if len <= self.hits.len() {
+                self.hits[len - 1] += 1;
+                self.bits_saved[len - 1] += original_bits as i64 - total_bits as i64;
+            }
+        }
+    }
+
+    /// Number of candidate seeds a miss at length `len` enumerates in full.
+    fn bucket_size(len: usize) -> u128 {
+        1u128 << (8 * len)
+    }
+
+    /// The largest seed length still worth probing, given what has been
+    /// recorded so far. Only ever narrows `current_max`, never widens it —
+    /// a length that looked unproductive this run is not un-discovered by a
+    /// later pass drawing the same conclusion again.
+    ///
+    /// A length pays for itself if the bits it has saved across every hit so
+    /// far is at least the raw seed-enumeration work spent reaching it
+    /// (`attempts * 2^(8*len)`, treating one probe as roughly one bit of
+    /// budget — a coarse but conservative stand-in for real probe cost, since
+    /// this layer has no wall-clock or hardware-cost model to draw on). A
+    /// length with no recorded attempts is left untouched rather than cut,
+    /// since "never reached" carries no evidence either way. The cut walks
+    /// from `current_max` downward so a single unproductive top length does
+    /// not hide a productive one just below it.
+    pub fn recommended_max_seed_len(&self, current_max: usize) -> usize {
+        let mut cutoff = current_max.min(self.attempts.len());
+        while cutoff > 1 {
+            let idx = cutoff - 1;
+            let attempts = self.attempts[idx];
+            if attempts == 0 {
+                break;
+            }
+            let gain_bits = self.bits_saved[idx].max(0) as u128;
+            let cost_bits = Self::bucket_size(cutoff).saturating_mul(attempts as u128);
+            if gain_bits >= cost_bits {
+                break;
+            }
+            cutoff -= 1;
+        }
+        cutoff.max(1)
+    }
+}
+
+/// Simulate a compression pass over `store`, restricted to `only` if given.
+///
+/// Unlike the old digest-table lookup this runs the real seed search
+/// ([`find_seed_match`]) against each block's bytes and prices the result
+/// with the real wire cost ([`v1_record_bit_len`]), so the resulting table is
+/// representative of what the actual codec would produce. A block is only
+/// rewritten if the found seed's encoded cost is strictly smaller than the
+/// block's current bit length; otherwise it stays in its original group
+/// untouched.
+///
+/// `only`, when `Some`, restricts the scan to that set of block IDs instead
+/// of every block currently in `store` — see [`simulate_passes`]. `cache`
+/// short-circuits the search entirely for a block whose digest was already
+/// resolved earlier in this run (e.g. a duplicate span, or the same block
+/// revisited on a later pass). Returns the number of new matches plus the
+/// IDs that were scanned but still did not match, i.e. the blocks still
+/// "dirty" and worth reconsidering on a later pass.
+///
+/// `expander` is injected (rather than hardcoded to `Blake3Expander`) so
+/// callers — and tests — can swap in a fake matcher without touching the
+/// real seed-search path.
+///
+/// `bump` backs the per-pass scratch vectors (the group scan copy, the
+/// unmatched/matched working lists, and each uncached block's search buffer)
+/// with a bump allocator instead of the global allocator. These are all
+/// allocated and dropped within this single call, so [`simulate_passes`]
+/// resets the same `Bump` once per pass rather than letting every pass pay
+/// its own round of heap churn.
+#[allow(clippy::too_many_arguments)]
+fn simulate_pass_over(
+    store: &mut BlockStore,
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+    only: Option<&HashSet<BlockId>>,
+    cache: &mut SeedMatchCache,
+    cache_stats: &mut CacheStats,
+    seed_stats: &mut SeedLengthStats,
+    bump: &Bump,
+) -> Result<(usize, HashSet<BlockId>), crate::TelomereError> {
     let mut lengths: Vec<usize> = store.groups.keys().copied().collect();
     lengths.sort_unstable_by(|a, b| b.cmp(a));
 
     let mut matches = 0usize;
+    let mut still_dirty: HashSet<BlockId> = HashSet::new();
 
     for len in lengths {
-        // We need to iterate and potentially move blocks to a new group (len=16).
+        // We need to iterate and potentially move blocks to a new group.
         // Since we can't easily move while iterating the HashMap, we extract indices.
-        let group_ids = store.groups.get(&len).cloned().unwrap_or_default(); // Scan copy
+        let mut group_ids = BumpVec::new_in(bump);
+        group_ids.extend(store.groups.get(&len).into_iter().flatten().copied());
         if group_ids.is_empty() {
             continue;
         }
 
-        let mut next_group_indices = Vec::new();
-        let mut matched_indices = Vec::new();
+        let mut next_group_indices: BumpVec<BlockId> = BumpVec::new_in(bump);
+        let mut matched_indices: BumpVec<(BlockId, u64, u16, usize)> = BumpVec::new_in(bump);
 
         for &id in &group_ids {
-            // let _data = store.get_data(id).to_vec(); // Unused
-            // Use digest from metadata
+            if only.is_some_and(|dirty| !dirty.contains(&id)) {
+                next_group_indices.push(id);
+                continue;
+            }
+
             let digest = store.get_block(id).digest;
-            let hex = hex::encode(digest);
+            let original_bits = store.get_block(id).bit_len as usize;
 
-            if let Some(&seed_idx) = seed_table.get(&hex) {
-                matched_indices.push((id, seed_idx));
-                matches += 1;
+            let found = if let Some(cached) = cache.get(&digest) {
+                cache_stats.hits += 1;
+                *cached
             } else {
-                next_group_indices.push(id);
+                cache_stats.misses += 1;
+                let mut data = BumpVec::new_in(bump);
+                data.extend_from_slice(store.get_data(id));
+                let computed = match find_seed_match(&data, max_seed_len, expander)? {
+                    Some(seed_idx) => {
+                        let total_bits = v1_record_bit_len(1, seed_idx as u64)?;
+                        Some((seed_idx as u64, total_bits))
+                    }
+                    None => None,
+                };
+                cache.insert(digest, computed);
+                computed
+            };
+
+            let matched_len = found.and_then(|(seed_idx, _)| {
+                index_to_seed(seed_idx as usize, max_seed_len)
+                    .map(|seed| seed.len())
+                    .ok()
+            });
+            let total_bits_for_stats = found.map(|(_, bits)| bits).unwrap_or(original_bits);
+            seed_stats.record_block(
+                matched_len,
+                max_seed_len,
+                original_bits,
+                total_bits_for_stats,
+            );
+
+            if let Some((seed_idx, total_bits)) = found {
+                if total_bits < original_bits {
+                    matched_indices.push((id, seed_idx, total_bits as u16, total_bits));
+                    matches += 1;
+                    continue;
+                }
             }
+            still_dirty.insert(id);
+            next_group_indices.push(id);
         }
 
         // Apply changes
         if !matched_indices.is_empty() {
-            for (id, seed_idx) in matched_indices {
+            for &(id, seed_idx, new_bit_len, new_group) in &matched_indices {
                 let block = store.get_block_mut(id);
-                block.seed_index = Some(seed_idx as u64);
+                block.seed_index = Some(seed_idx);
                 block.arity = Some(1);
-                block.bit_len = 16;
+                block.bit_len = new_bit_len;
 
-                store.groups.entry(16).or_default().push(id);
+                store.groups.entry(new_group).or_default().push(id);
             }
-            // Update the original group to only contain unmatched
-            store.groups.insert(len, next_group_indices);
+            // Update the original group to only contain unmatched blocks. The
+            // bump-allocated scratch list is copied out into a real `Vec`
+            // here since this one, unlike the others, outlives the pass.
+            store
+                .groups
+                .insert(len, next_group_indices.iter().copied().collect());
         }
     }
-    matches
+    Ok((matches, still_dirty))
+}
+
+/// Simulate a compression pass over every block in `store`.
+///
+/// See [`simulate_pass_over`]; this is the unrestricted single-pass entry
+/// point. [`simulate_passes`] is the multi-pass driver that avoids rescanning
+/// blocks a pass has already resolved.
+///
+/// Not currently called from `compress.rs`'s real multi-pass pipeline, which
+/// drives matching through [`crate::superposition::SuperpositionManager`]
+/// instead of a [`BlockStore`] dirty-set loop — the two track candidate state
+/// in incompatible ways, so bridging them is a real architectural change, not
+/// a wiring fix. This family (and [`SeedMatchCache`]) is exercised directly by
+/// its own tests below as a standalone model of the dirty-set/caching
+/// strategy; promoting it to the production entry point is unscheduled work,
+/// not an oversight.
+#[allow(dead_code)]
+pub fn simulate_pass(
+    store: &mut BlockStore,
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+) -> Result<usize, crate::TelomereError> {
+    let (matches, _) = simulate_pass_with_cache_stats(store, max_seed_len, expander)?;
+    Ok(matches)
+}
+
+/// Like [`simulate_pass`], but also returns the [`SeedMatchCache`] hit/miss
+/// counters for this pass, so `max_bytes`-style cache sizing can be tuned
+/// from real hit-rate data instead of guesswork.
+#[allow(dead_code)]
+pub fn simulate_pass_with_cache_stats(
+    store: &mut BlockStore,
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+) -> Result<(usize, CacheStats), crate::TelomereError> {
+    let mut cache = SeedMatchCache::new();
+    let mut cache_stats = CacheStats::default();
+    let mut seed_stats = SeedLengthStats::new(max_seed_len);
+    let bump = Bump::new();
+    let (matches, _) = simulate_pass_over(
+        store,
+        max_seed_len,
+        expander,
+        None,
+        &mut cache,
+        &mut cache_stats,
+        &mut seed_stats,
+        &bump,
+    )?;
+    Ok((matches, cache_stats))
+}
+
+/// Run [`simulate_pass`]-style passes up to `max_passes` times, restricting
+/// every pass after the first to the blocks that are still unresolved
+/// (no `seed_index` yet) instead of rescanning the whole store.
+///
+/// A block's underlying bytes never change between passes in this model, so
+/// once a pass has scanned it and found no match, rescanning it again with
+/// the same search parameters is wasted work — only a genuinely "dirty"
+/// remainder (blocks that haven't matched yet, which may still match if a
+/// later pass raises `max_seed_len`) can possibly yield something new.
+/// Stops early once a pass makes no new matches, since the remaining dirty
+/// set would be identical on the next pass.
+///
+/// A single [`SeedMatchCache`] is shared across every pass in this run, so a
+/// digest resolved (matched or not) on an earlier pass is never re-searched
+/// on a later one even outside the dirty-set restriction — e.g. two distinct
+/// blocks that happen to share content.
+#[allow(dead_code)]
+pub fn simulate_passes(
+    store: &mut BlockStore,
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+    max_passes: usize,
+) -> Result<usize, crate::TelomereError> {
+    let (matches, _) = simulate_passes_with_cache_stats(store, max_seed_len, expander, max_passes)?;
+    Ok(matches)
+}
+
+/// Like [`simulate_passes`], but also returns the [`SeedMatchCache`]
+/// hit/miss counters accumulated across every pass in this run.
+///
+/// Starts from a fresh, private [`SeedMatchCache`] each call. Use
+/// [`simulate_passes_with_cache`] directly if the cache should persist
+/// beyond this one call.
+#[allow(dead_code)]
+pub fn simulate_passes_with_cache_stats(
+    store: &mut BlockStore,
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+    max_passes: usize,
+) -> Result<(usize, CacheStats), crate::TelomereError> {
+    let mut cache = SeedMatchCache::new();
+    let mut cache_stats = CacheStats::default();
+    let mut seed_stats = SeedLengthStats::new(max_seed_len);
+    let total_matches = simulate_passes_with_cache(
+        store,
+        max_seed_len,
+        expander,
+        max_passes,
+        &mut cache,
+        &mut cache_stats,
+        &mut seed_stats,
+    )?;
+    Ok((total_matches, cache_stats))
+}
+
+/// Like [`simulate_passes_with_cache_stats`], but takes the [`SeedMatchCache`]
+/// and [`CacheStats`] as caller-owned `&mut` state instead of always starting
+/// from empty.
+///
+/// This lets a caller that drives several separate top-level calls — e.g.
+/// retrying at a larger `max_seed_len` after an earlier budget-limited run —
+/// carry resolved digests forward instead of re-searching blocks whose bytes
+/// have not changed since the previous call. Within a single call the cache
+/// is already shared across every pass regardless of which function is used;
+/// this is only about amortizing it *across* calls.
+///
+/// Also tracks `seed_stats` across every pass in this run and, once a pass
+/// reports its own [`SeedLengthStats::recommended_max_seed_len`], narrows the
+/// `max_seed_len` used by the *next* pass to that value instead of repeating
+/// a length bucket that has already shown it does not pay for itself. The
+/// narrowing only ever shrinks, is decided from a pass's complete results —
+/// never from in-progress parallel state — and is recorded in `seed_stats`
+/// for the caller to inspect, so re-running the same input reaches the same
+/// cutoff every time.
+pub fn simulate_passes_with_cache(
+    store: &mut BlockStore,
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+    max_passes: usize,
+    cache: &mut SeedMatchCache,
+    cache_stats: &mut CacheStats,
+    seed_stats: &mut SeedLengthStats,
+) -> Result<usize, crate::TelomereError> {
+    let mut total_matches = 0usize;
+    let mut dirty: Option<HashSet<BlockId>> = None;
+    let mut bump = Bump::new();
+    let mut effective_max_seed_len = max_seed_len;
+    for _ in 0..max_passes {
+        bump.reset();
+        let (matches, still_dirty) = simulate_pass_over(
+            store,
+            effective_max_seed_len,
+            expander,
+            dirty.as_ref(),
+            cache,
+            cache_stats,
+            seed_stats,
+            &bump,
+        )?;
+        total_matches += matches;
+        if matches == 0 {
+            break;
+        }
+        dirty = Some(still_dirty);
+        effective_max_seed_len = seed_stats.recommended_max_seed_len(effective_max_seed_len);
+    }
+    Ok(total_matches)
 }
 
 /// Print summary
@@ -222,9 +630,12 @@ pub fn print_table_summary(store: &BlockStore) {
 
     for id in all_ids {
         let b = store.get_block(id);
-        println!(
+        tracing::info!(
             "{}{}: {} bits ({:?})",
-            b.global_index, b.branch_label, b.bit_len, b.status
+            b.global_index,
+            b.branch_label,
+            b.bit_len,
+            b.status
         );
     }
 }
@@ -234,9 +645,178 @@ pub fn group_by_bit_length(_blocks: Vec<BlockRef>) -> BlockStore {
     BlockStore::new()
 }
 
+impl BlockStore {
+    /// Render the current blocks and their branch labels as Graphviz DOT,
+    /// so spec authors can visually debug why a particular parse was chosen
+    /// for a test vector.
+    ///
+    /// Blocks are grouped into one node per `(global_index, branch_label)`
+    /// pair, annotated with bit length and status, and chained in
+    /// `global_index` order within each branch.
+    pub fn to_dot(&self) -> String {
+        let mut ids: Vec<BlockId> = (0..self.blocks.len() as u32).map(BlockId).collect();
+        ids.sort_by_key(|id| {
+            let b = self.get_block(*id);
+            (b.branch_label, b.global_index)
+        });
+
+        let mut out = String::new();
+        out.push_str("digraph BlockStore {\n");
+        out.push_str("  rankdir=LR;\n");
+
+        let mut prev: HashMap<char, BlockId> = HashMap::new();
+        for id in ids {
+            let b = self.get_block(id);
+            out.push_str(&format!(
+                "  \"{}{}\" [label=\"{} bits\\n{:?}\"];\n",
+                b.global_index, b.branch_label, b.bit_len, b.status
+            ));
+            if let Some(&prev_id) = prev.get(&b.branch_label) {
+                let p = self.get_block(prev_id);
+                out.push_str(&format!(
+                    "  \"{}{}\" -> \"{}{}\";\n",
+                    p.global_index, p.branch_label, b.global_index, b.branch_label
+                ));
+            }
+            prev.insert(b.branch_label, id);
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::BlockStore;
+    use crate::hasher::{Blake3Expander, SeedExpander};
+    use crate::BranchStatus;
+
+    #[test]
+    fn simulate_pass_finds_real_seed_match_and_prices_it() {
+        let expander = Blake3Expander;
+        let mut data = [0u8; 8];
+        expander.expand_into(&[0], &mut data);
+
+        let mut store = BlockStore::new();
+        let id = store.add_block(&data, 0, data.len() * 8);
+
+        let matches = super::simulate_pass(&mut store, 1, &expander).unwrap();
+
+        assert_eq!(matches, 1);
+        assert_eq!(store.get_block(id).seed_index, Some(0));
+        assert_eq!(store.get_block(id).arity, Some(1));
+        assert!((store.get_block(id).bit_len as usize) < data.len() * 8);
+    }
+
+    #[test]
+    fn simulate_pass_leaves_unmatched_blocks_untouched() {
+        let expander = Blake3Expander;
+        // max_seed_len of 0 guarantees find_seed_match returns None, so the
+        // block must be left exactly as it was.
+        let mut store = BlockStore::new();
+        let id = store.add_block(&[0u8], 0, 8);
+
+        let matches = super::simulate_pass(&mut store, 0, &expander).unwrap();
+
+        assert_eq!(matches, 0);
+        assert_eq!(store.get_block(id).seed_index, None);
+        assert_eq!(store.get_block(id).bit_len, 8);
+    }
+
+    #[test]
+    fn simulate_passes_stops_once_a_pass_finds_nothing_new() {
+        let expander = Blake3Expander;
+        let mut store = BlockStore::new();
+        // max_seed_len of 0 means no block can ever match, so the very first
+        // pass already finds nothing and later passes must not run at all.
+        store.add_block(&[0u8], 0, 8);
+
+        let matches = super::simulate_passes(&mut store, 0, &expander, 10).unwrap();
+        assert_eq!(matches, 0);
+    }
+
+    #[test]
+    fn duplicate_content_blocks_share_one_seed_search() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct CountingExpander {
+            inner: Blake3Expander,
+            searches: AtomicUsize,
+        }
+
+        impl SeedExpander for CountingExpander {
+            fn expand_into(&self, seed: &[u8], out: &mut [u8]) {
+                self.searches.fetch_add(1, Ordering::Relaxed);
+                self.inner.expand_into(seed, out);
+            }
+            fn digest(&self, data: &[u8]) -> [u8; 32] {
+                self.inner.digest(data)
+            }
+            fn prefix_matches(&self, seed: &[u8], target: &[u8], bits: usize) -> bool {
+                self.inner.prefix_matches(seed, target, bits)
+            }
+        }
+
+        let expander = CountingExpander {
+            inner: Blake3Expander,
+            searches: AtomicUsize::new(0),
+        };
+        // A fixed, arbitrary 8-byte target that no 1-byte seed's BLAKE3
+        // expansion happens to match: the search exhausts every candidate
+        // seed deterministically (no early exit), so the call count per
+        // uncached search is exactly 256 — a precise, non-flaky signal that
+        // a second identical block skipped the search entirely.
+        let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut store = BlockStore::new();
+        store.add_block(&data, 0, data.len() * 8);
+        store.add_block(&data, 1, data.len() * 8);
+
+        let matches = super::simulate_pass(&mut store, 1, &expander).unwrap();
+        assert_eq!(matches, 0);
+        assert_eq!(
+            expander.searches.load(Ordering::Relaxed),
+            256,
+            "second block's digest should hit the cache instead of re-searching all 256 seeds"
+        );
+    }
+
+    #[test]
+    fn cache_stats_count_hits_and_misses_across_duplicate_blocks() {
+        let expander = Blake3Expander;
+        let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+        let mut store = BlockStore::new();
+        store.add_block(&data, 0, data.len() * 8);
+        store.add_block(&data, 1, data.len() * 8);
+
+        let (matches, cache_stats) =
+            super::simulate_pass_with_cache_stats(&mut store, 1, &expander).unwrap();
+
+        assert_eq!(matches, 0);
+        assert_eq!(cache_stats.misses, 1);
+        assert_eq!(cache_stats.hits, 1);
+        assert!((cache_stats.hit_rate() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn simulate_passes_matches_already_resolved_blocks_only_once() {
+        let expander = Blake3Expander;
+        let mut data = [0u8; 8];
+        expander.expand_into(&[0], &mut data);
+
+        let mut store = BlockStore::new();
+        let id = store.add_block(&data, 0, data.len() * 8);
+
+        // A single pass would already resolve the block; running several more
+        // passes must not "re-match" it a second time, since subsequent
+        // passes are restricted to the still-dirty (unresolved) remainder.
+        let matches = super::simulate_passes(&mut store, 1, &expander, 5).unwrap();
+
+        assert_eq!(matches, 1);
+        assert_eq!(store.get_block(id).seed_index, Some(0));
+    }
 
     #[test]
     fn add_block_records_blake3_digest() {
@@ -247,4 +827,77 @@ mod tests {
 
         assert_eq!(store.get_block(id).digest, expected);
     }
+
+    #[test]
+    fn save_and_load_round_trips_blocks_and_status() {
+        let mut store = BlockStore::new();
+        let id = store.add_block(b"round trip me", 3, 24);
+        store.get_block_mut(id).status = BranchStatus::Pruned;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blockstore.bin");
+        store.save(&path).unwrap();
+
+        let loaded = BlockStore::load(&path).unwrap();
+        assert_eq!(loaded.get_data(id), store.get_data(id));
+        assert_eq!(loaded.get_block(id).status, BranchStatus::Pruned);
+    }
+
+    #[test]
+    fn remove_block_drops_it_from_the_group_index() {
+        let mut store = BlockStore::new();
+        let a = store.add_block(b"one", 5, 24);
+        store.remove_block(a);
+        assert!(store.get_group(24).map_or(true, |g| !g.contains(&a)));
+    }
+
+    #[test]
+    fn to_dot_includes_each_block_node() {
+        let mut store = BlockStore::new();
+        store.add_block(b"one", 0, 24);
+        store.add_block(b"two", 1, 24);
+        let dot = store.to_dot();
+        assert!(dot.starts_with("digraph BlockStore {"));
+        assert!(dot.contains("\"0A\""));
+        assert!(dot.contains("\"1A\""));
+        assert!(dot.contains("\"0A\" -> \"1A\""));
+    }
+
+    #[test]
+    fn seed_length_stats_cuts_a_length_with_no_hits() {
+        use super::SeedLengthStats;
+
+        let mut stats = SeedLengthStats::new(2);
+        // Length 1 is never cut (the cutoff does not go below 1); length 2
+        // never found anything despite 10 blocks reaching its 65536-seed bucket.
+        stats.record_block(Some(1), 2, 1000, 0);
+        for _ in 0..10 {
+            stats.record_block(None, 2, 16, 16);
+        }
+
+        assert_eq!(stats.recommended_max_seed_len(2), 1);
+    }
+
+    #[test]
+    fn seed_length_stats_keeps_a_length_that_pays_for_itself() {
+        use super::SeedLengthStats;
+
+        let mut stats = SeedLengthStats::new(2);
+        stats.record_block(Some(1), 2, 100, 8);
+        stats.record_block(Some(2), 2, 10_000_000, 16);
+
+        assert_eq!(stats.recommended_max_seed_len(2), 2);
+    }
+
+    #[test]
+    fn seed_length_stats_leaves_an_unreached_length_untouched() {
+        use super::SeedLengthStats;
+
+        // Nothing ever reached length 2 (every block matched at length 1),
+        // so there is no evidence to cut it on.
+        let mut stats = SeedLengthStats::new(2);
+        stats.record_block(Some(1), 2, 100, 8);
+
+        assert_eq!(stats.recommended_max_seed_len(2), 2);
+    }
 }
@@ -3,6 +3,8 @@
 //! span as removed.  This module defines the [`MutableBlock`] type used
 //! during these transformations and utilities for applying a bundle.
 
+use hashbrown::HashSet;
+
 /// Status of a mutable block during bundling operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockStatus {
@@ -42,8 +44,11 @@ pub fn apply_bundle(
     }
 
     let pos = bundle_indices[0];
+    // Build the removal set once so marking bundled blocks is a single O(n)
+    // sweep instead of an O(n * bundle_indices.len()) scan over the table.
+    let removed: HashSet<usize> = bundle_indices.iter().copied().collect();
     for b in table.iter_mut() {
-        if bundle_indices.contains(&b.position) {
+        if removed.contains(&b.position) {
             b.status = BlockStatus::Removed;
         }
     }
@@ -58,3 +63,122 @@ pub fn apply_bundle(
         status: BlockStatus::Active,
     });
 }
+
+/// One pending bundle change, as passed to [`apply_block_changes`].
+pub struct BlockChange {
+    pub bundle_indices: Vec<usize>,
+    pub seed_index: usize,
+    pub arity: usize,
+    pub new_bit_length: usize,
+}
+
+/// Apply many bundle changes to `table` in a single O(n) sweep.
+///
+/// Calling [`apply_bundle`] once per change costs O(n * changes.len()),
+/// since each call re-scans the whole table to mark removed positions. This
+/// builds one removal set covering every change up front, marks all bundled
+/// positions in a single pass over `table`, then appends the new compressed
+/// blocks — same end state as applying each change in order, at O(n +
+/// changes.len()) instead.
+pub fn apply_block_changes(table: &mut Vec<MutableBlock>, changes: &[BlockChange]) {
+    if changes.is_empty() {
+        return;
+    }
+
+    let mut removed: HashSet<usize> = HashSet::new();
+    for change in changes {
+        removed.extend(change.bundle_indices.iter().copied());
+    }
+
+    for b in table.iter_mut() {
+        if removed.contains(&b.position) {
+            b.status = BlockStatus::Removed;
+        }
+    }
+
+    for change in changes {
+        if let Some(&pos) = change.bundle_indices.first() {
+            table.push(MutableBlock {
+                origin_index: table[pos].origin_index,
+                position: pos,
+                bit_length: change.new_bit_length,
+                data: vec![],
+                arity: Some(change.arity),
+                seed_index: Some(change.seed_index),
+                status: BlockStatus::Active,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(position: usize) -> MutableBlock {
+        MutableBlock {
+            origin_index: position,
+            position,
+            bit_length: 8,
+            data: vec![0u8],
+            arity: None,
+            seed_index: None,
+            status: BlockStatus::Active,
+        }
+    }
+
+    #[test]
+    fn batched_changes_match_sequential_apply_bundle() {
+        let mut sequential: Vec<MutableBlock> = (0..6).map(block).collect();
+        apply_bundle(&mut sequential, &[0, 1], 10, 2, 4);
+        apply_bundle(&mut sequential, &[2, 3], 20, 2, 4);
+
+        let mut batched: Vec<MutableBlock> = (0..6).map(block).collect();
+        apply_block_changes(
+            &mut batched,
+            &[
+                BlockChange {
+                    bundle_indices: vec![0, 1],
+                    seed_index: 10,
+                    arity: 2,
+                    new_bit_length: 4,
+                },
+                BlockChange {
+                    bundle_indices: vec![2, 3],
+                    seed_index: 20,
+                    arity: 2,
+                    new_bit_length: 4,
+                },
+            ],
+        );
+
+        let statuses = |t: &[MutableBlock]| -> Vec<BlockStatus> {
+            t.iter().map(|b| b.status.clone()).collect()
+        };
+        assert_eq!(statuses(&sequential), statuses(&batched));
+        assert_eq!(sequential.len(), batched.len());
+    }
+
+    #[test]
+    fn batched_changes_scale_to_thousands_of_bundles() {
+        let n = 4000;
+        let mut table: Vec<MutableBlock> = (0..n).map(block).collect();
+        let changes: Vec<BlockChange> = (0..n / 2)
+            .map(|i| BlockChange {
+                bundle_indices: vec![i * 2],
+                seed_index: i,
+                arity: 1,
+                new_bit_length: 4,
+            })
+            .collect();
+
+        apply_block_changes(&mut table, &changes);
+
+        let removed = table
+            .iter()
+            .filter(|b| b.status == BlockStatus::Removed)
+            .count();
+        assert_eq!(removed, n / 2);
+        assert_eq!(table.len(), n + n / 2);
+    }
+}
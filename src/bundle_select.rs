@@ -24,9 +24,51 @@ pub struct AcceptedBundle {
     pub superposed: bool,
 }
 
+/// Why [`select_bundles`] turned down a [`BundleRecord`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The record's blocks are already owned by more than one accepted
+    /// bundle, so there is no single owner to superpose it over.
+    AmbiguousOverlap,
+    /// The record overlaps exactly one accepted bundle, but its blocks are
+    /// not entirely contained within that bundle's blocks.
+    NotSubset,
+    /// The record's blocks are all contained within its owner's blocks, but its bit cost
+    /// exceeds the owner's by more than the superposition delta.
+    BitDeltaTooLarge,
+}
+
+/// A [`BundleRecord`] that [`select_bundles`] did not accept, with the reason.
+#[derive(Debug, Clone)]
+pub struct RejectedRecord {
+    pub record: BundleRecord,
+    pub reason: RejectionReason,
+}
+
+/// Outcome of a [`select_bundles`] run: the accepted bundles plus every
+/// rejected record and why, so callers can tune selection without adding
+/// `println!`s to the library.
+#[derive(Debug, Clone, Default)]
+pub struct SelectionReport {
+    pub accepted: Vec<AcceptedBundle>,
+    pub rejected: Vec<RejectedRecord>,
+}
+
+/// Bit cost delta within which a record may still be accepted as a
+/// superposition over its owner.
+const SUPERPOSITION_DELTA_BITS: usize = 8;
+
 /// Greedily select bundles with conflict and superposition handling.
-pub fn select_bundles(records: Vec<BundleRecord>) -> Vec<AcceptedBundle> {
+///
+/// Takes an `impl Iterator` rather than a `Vec` so the CPU/GPU matching
+/// pipelines can stream records in as they're produced instead of buffering
+/// every match before selection can start. The state carried across records
+/// is `accepted` and the `ownership` index, both bounded by the accepted
+/// bundles, plus the `rejected` list, which grows by one entry for every
+/// record that is not accepted.
+pub fn select_bundles(records: impl IntoIterator<Item = BundleRecord>) -> SelectionReport {
     let mut accepted: Vec<AcceptedBundle> = Vec::new();
+    let mut rejected: Vec<RejectedRecord> = Vec::new();
     let mut ownership: HashMap<usize, usize> = HashMap::new(); // block -> index in accepted
 
     for rec in records {
@@ -53,7 +95,10 @@ pub fn select_bundles(records: Vec<BundleRecord>) -> Vec<AcceptedBundle> {
         }
 
         if owners.len() > 1 {
-            // Ambiguous overlap.
+            rejected.push(RejectedRecord {
+                record: rec,
+                reason: RejectionReason::AmbiguousOverlap,
+            });
             continue;
         }
 
@@ -65,19 +110,26 @@ pub fn select_bundles(records: Vec<BundleRecord>) -> Vec<AcceptedBundle> {
         let owner_set: HashSet<usize> = owner.block_indices.iter().copied().collect();
 
         if !rec.block_indices.iter().all(|b| owner_set.contains(b)) {
-            // Not a subset of the owner bundle.
+            rejected.push(RejectedRecord {
+                record: rec,
+                reason: RejectionReason::NotSubset,
+            });
             continue;
         }
 
-        if rec.original_bits > owner.original_bits + 8 {
-            // Too big to superpose.
+        if rec.original_bits > owner.original_bits + SUPERPOSITION_DELTA_BITS {
+            rejected.push(RejectedRecord {
+                record: rec,
+                reason: RejectionReason::BitDeltaTooLarge,
+            });
             continue;
         }
 
         // Accept as superposition without claiming blocks.
-        println!(
-            "[debug] accepting superposition: candidate seed {} over owner seed {}",
-            rec.seed_index, owner.seed_index
+        tracing::debug!(
+            candidate_seed = rec.seed_index,
+            owner_seed = owner.seed_index,
+            "accepting superposition"
         );
         accepted.push(AcceptedBundle {
             seed_index: rec.seed_index,
@@ -88,5 +140,82 @@ pub fn select_bundles(records: Vec<BundleRecord>) -> Vec<AcceptedBundle> {
         });
     }
 
-    accepted
+    SelectionReport { accepted, rejected }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(seed_index: usize, bundle_length: usize, block_indices: &[usize], original_bits: usize) -> BundleRecord {
+        BundleRecord {
+            seed_index,
+            bundle_length,
+            block_indices: block_indices.to_vec(),
+            original_bits,
+        }
+    }
+
+    #[test]
+    fn ambiguous_overlap_is_reported() {
+        let records = vec![
+            record(1, 1, &[0], 32),
+            record(2, 1, &[1], 32),
+            record(3, 2, &[0, 1], 20),
+        ];
+        let report = select_bundles(records);
+        assert_eq!(report.accepted.len(), 2);
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].reason, RejectionReason::AmbiguousOverlap);
+        assert_eq!(report.rejected[0].record.seed_index, 3);
+    }
+
+    #[test]
+    fn non_subset_is_reported() {
+        let records = vec![
+            record(1, 2, &[0, 1], 40),
+            record(2, 2, &[1, 2], 10),
+        ];
+        let report = select_bundles(records);
+        assert_eq!(report.accepted.len(), 1);
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].reason, RejectionReason::NotSubset);
+    }
+
+    #[test]
+    fn bit_delta_too_large_is_reported() {
+        let records = vec![
+            record(1, 2, &[0, 1], 40),
+            record(2, 2, &[0, 1], 60),
+        ];
+        let report = select_bundles(records);
+        assert_eq!(report.accepted.len(), 1);
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(
+            report.rejected[0].reason,
+            RejectionReason::BitDeltaTooLarge
+        );
+    }
+
+    #[test]
+    fn superposition_within_delta_is_accepted() {
+        let records = vec![
+            record(1, 2, &[0, 1], 40),
+            record(2, 2, &[0, 1], 45),
+        ];
+        let report = select_bundles(records);
+        assert_eq!(report.accepted.len(), 2);
+        assert!(report.rejected.is_empty());
+        assert!(report.accepted[1].superposed);
+    }
+
+    #[test]
+    fn accepts_a_non_vec_iterator_of_records() {
+        // Exercises the streaming entry point with something other than a
+        // `Vec`, standing in for a pipeline that yields records lazily.
+        let records = (0..3).map(|i| record(i, 1, &[i], 32));
+        let report = select_bundles(records);
+        assert_eq!(report.accepted.len(), 3);
+        assert!(report.rejected.is_empty());
+    }
 }
@@ -27,6 +27,8 @@ pub fn bundle_one_layer(
     spans: &[(usize, Candidate)],
     candidates: &HashMap<(usize, usize), Candidate>,
 ) -> Vec<(usize, Candidate)> {
+    #[cfg(feature = "trace-spans")]
+    let _span = tracing::info_span!("bundle_one_layer", spans = spans.len()).entered();
     // Pre-compute the starting block index for each span.
     let mut start_block = Vec::with_capacity(spans.len());
     let mut block = 0usize;
@@ -88,6 +90,7 @@ pub fn bundle_one_layer(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::CandidateOrigin;
     use quickcheck::quickcheck;
     use std::collections::HashMap;
 
@@ -101,6 +104,8 @@ mod tests {
                     seed_index: 0,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
             (
@@ -109,6 +114,8 @@ mod tests {
                     seed_index: 1,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
             (
@@ -117,6 +124,8 @@ mod tests {
                     seed_index: 2,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
         ];
@@ -128,6 +137,8 @@ mod tests {
                 seed_index: 10,
                 arity: 2,
                 bit_len: 30,
+                from_bundle: false,
+                origin: CandidateOrigin::default(),
             },
         );
 
@@ -148,6 +159,8 @@ mod tests {
                     seed_index: 0,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
             (
@@ -156,6 +169,8 @@ mod tests {
                     seed_index: 1,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
             (
@@ -164,6 +179,8 @@ mod tests {
                     seed_index: 2,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
         ];
@@ -174,6 +191,8 @@ mod tests {
                 seed_index: 10,
                 arity: 2,
                 bit_len: 30,
+                from_bundle: false,
+                origin: CandidateOrigin::default(),
             },
         );
 
@@ -191,6 +210,8 @@ mod tests {
                     seed_index: 0,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
             (
@@ -199,6 +220,8 @@ mod tests {
                     seed_index: 1,
                     arity: 1,
                     bit_len: 16,
+                    from_bundle: false,
+                    origin: CandidateOrigin::default(),
                 },
             ),
         ];
@@ -210,6 +233,8 @@ mod tests {
                 seed_index: 10,
                 arity: 3,
                 bit_len: 40,
+                from_bundle: false,
+                origin: CandidateOrigin::default(),
             },
         );
 
@@ -222,11 +247,11 @@ mod tests {
             let blocks = (n % 5) + 2;
             let mut spans = Vec::new();
             for i in 0..blocks {
-                spans.push((i as usize, Candidate { seed_index: i as u64, arity: 1, bit_len: 16 }));
+                spans.push((i as usize, Candidate { seed_index: i as u64, arity: 1, bit_len: 16, from_bundle: false, origin: CandidateOrigin::default() }));
             }
             let mut cand_map = HashMap::new();
             if blocks >= 2 {
-                cand_map.insert((0, 2), Candidate { seed_index: 99, arity: 2, bit_len: 30 });
+                cand_map.insert((0, 2), Candidate { seed_index: 99, arity: 2, bit_len: 30, from_bundle: false, origin: CandidateOrigin::default() });
             }
             let once = bundle_one_layer(&spans, &cand_map);
             let twice = bundle_one_layer(&once, &cand_map);
@@ -1,12 +1,4 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Candidate {
-    /// Total encoded length in bits for this representation.
-    pub bits_length: usize,
-    /// Seed index used for deterministic tie breaking.
-    pub seed: usize,
-    /// Whether this candidate originates from a bundle spanning multiple blocks.
-    pub from_bundle: bool,
-}
+use crate::types::Candidate;
 
 #[derive(Debug, Clone)]
 pub struct Block {
@@ -14,116 +6,171 @@ pub struct Block {
     pub candidates: Vec<Candidate>,
 }
 
+/// How ties between equally-short candidates are broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TieBreak {
+    /// Prefer the lower seed index (the long-standing default).
+    SeedAscending,
+    /// Prefer the higher seed index.
+    SeedDescending,
+}
+
+/// Shared knobs for branch pruning, so the block-table path
+/// ([`prune_candidates`]) and the superposition path
+/// (`SuperpositionManager::prune_end_of_pass`) can be kept consistent from
+/// one place instead of each baking in its own "8 bits, keep 3" policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PrunePolicy {
+    /// Candidates more than this many bits longer than the best are dropped.
+    pub delta_bits: usize,
+    /// At most this many surviving candidates are kept per block/index.
+    pub keep_count: usize,
+    /// How to order equally-short candidates before truncating to
+    /// `keep_count`.
+    pub tie_break: TieBreak,
+}
+
+impl Default for PrunePolicy {
+    fn default() -> Self {
+        PrunePolicy {
+            delta_bits: 8,
+            keep_count: 3,
+            tie_break: TieBreak::SeedAscending,
+        }
+    }
+}
+
+/// Round `bit_len` up to the emitted cost once the writer flushes to the
+/// next byte boundary, given the bit cursor where this candidate would start.
+///
+/// Mirrors the `(8 - (bits % 8)) % 8` pad computed at actual emit time (see
+/// `tlmr.rs`/`tlmr_v2.rs`), so two candidates of different raw `bit_len`
+/// can land on the same real output size when they round up to the same
+/// byte.
+pub fn aligned_bits_length(start_bit: usize, bit_len: usize) -> usize {
+    let end_bit = start_bit + bit_len;
+    let pad = (8 - (end_bit % 8)) % 8;
+    bit_len + pad
+}
+
 /// Prune the candidates for each block after a compression pass.
 ///
-/// - Candidates are sorted by `bits_length` (shortest first; tie broken by
-///   `seed` for determinism).
-/// - All candidates whose length is more than 8 bits longer than the best are
-///   removed.
+/// `start_bit` is the bit cursor in the output stream at which the first
+/// block begins; each block is assumed to flush-pad to a byte boundary once
+/// its winning candidate is emitted, so the cursor advances by that
+/// candidate's aligned cost before the next block is considered.
+///
+/// - Candidates are sorted by their byte-aligned emitted cost (shortest
+///   first; tie broken by `seed_index` for determinism). Raw `bit_len` alone
+///   can understate a candidate's true cost: a nominally shorter candidate
+///   that lands mid-byte pads out to the same size as a longer one that
+///   lands on a byte boundary.
+/// - All candidates whose aligned cost is more than 8 bits longer than the
+///   best are removed.
 /// - If any candidate comes from a successful bundle, all non-bundled
 ///   candidates are discarded, leaving only the bundle representation(s).
-pub fn prune_candidates(blocks: &mut [Block]) {
+///
+/// Uses [`PrunePolicy::default`]; see [`prune_candidates_with_policy`] to
+/// override the delta/keep-count/tie-break knobs.
+pub fn prune_candidates(blocks: &mut [Block], start_bit: usize) {
+    prune_candidates_with_policy(blocks, start_bit, &PrunePolicy::default())
+}
+
+/// Same as [`prune_candidates`], but with the delta/keep-count/tie-break
+/// policy made explicit instead of baked in, so this path and
+/// `SuperpositionManager::prune_end_of_pass` can share one [`PrunePolicy`].
+pub fn prune_candidates_with_policy(blocks: &mut [Block], start_bit: usize, policy: &PrunePolicy) {
+    let mut cursor = start_bit;
     for block in blocks.iter_mut() {
         if block.candidates.is_empty() {
             continue;
         }
 
-        // Deterministic ordering.
+        // Deterministic ordering by real emitted cost.
         block.candidates.sort_by(|a, b| {
-            a.bits_length
-                .cmp(&b.bits_length)
-                .then_with(|| a.seed.cmp(&b.seed))
+            let ord = aligned_bits_length(cursor, a.bit_len).cmp(&aligned_bits_length(cursor, b.bit_len));
+            match policy.tie_break {
+                TieBreak::SeedAscending => ord.then_with(|| a.seed_index.cmp(&b.seed_index)),
+                TieBreak::SeedDescending => ord.then_with(|| b.seed_index.cmp(&a.seed_index)),
+            }
         });
 
-        // Length delta prune.
-        let best = block.candidates[0].bits_length;
-        block.candidates.retain(|c| c.bits_length <= best + 8);
+        // Length delta prune, measured on aligned cost.
+        let best = aligned_bits_length(cursor, block.candidates[0].bit_len);
+        block
+            .candidates
+            .retain(|c| aligned_bits_length(cursor, c.bit_len) <= best + policy.delta_bits);
 
         // Bundling prune.
         let has_bundle = block.candidates.iter().any(|c| c.from_bundle);
         if has_bundle {
             block.candidates.retain(|c| c.from_bundle);
         }
+
+        if block.candidates.len() > policy.keep_count {
+            block.candidates.truncate(policy.keep_count);
+        }
+
+        cursor += aligned_bits_length(cursor, block.candidates[0].bit_len);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::CandidateOrigin;
+
+    fn candidate(bit_len: usize, seed_index: u64, from_bundle: bool) -> Candidate {
+        Candidate {
+            seed_index,
+            arity: 1,
+            bit_len,
+            from_bundle,
+            origin: CandidateOrigin::default(),
+        }
+    }
 
     #[test]
     fn prunes_candidates_by_bits_length() {
         let mut blocks = [Block {
             candidates: vec![
-                Candidate {
-                    bits_length: 24,
-                    seed: 1,
-                    from_bundle: false,
-                },
-                Candidate {
-                    bits_length: 27,
-                    seed: 2,
-                    from_bundle: false,
-                },
-                Candidate {
-                    bits_length: 35,
-                    seed: 3,
-                    from_bundle: false,
-                },
+                candidate(24, 1, false),
+                candidate(27, 2, false),
+                candidate(35, 3, false),
             ],
         }];
-        prune_candidates(&mut blocks);
+        prune_candidates(&mut blocks, 0);
         let cands = &blocks[0].candidates;
         assert_eq!(cands.len(), 2);
-        assert_eq!(cands[0].bits_length, 24);
-        assert_eq!(cands[1].bits_length, 27);
+        assert_eq!(cands[0].bit_len, 24);
+        assert_eq!(cands[1].bit_len, 27);
     }
 
     #[test]
     fn multiple_candidates_within_delta_survive() {
         let mut blocks = [Block {
-            candidates: vec![
-                Candidate {
-                    bits_length: 20,
-                    seed: 2,
-                    from_bundle: false,
-                },
-                Candidate {
-                    bits_length: 21,
-                    seed: 1,
-                    from_bundle: false,
-                },
-            ],
+            candidates: vec![candidate(20, 2, false), candidate(21, 1, false)],
         }];
-        prune_candidates(&mut blocks);
+        prune_candidates(&mut blocks, 0);
         let cands = &blocks[0].candidates;
         assert_eq!(cands.len(), 2);
-        assert_eq!(cands[0].bits_length, 20);
-        assert_eq!(cands[1].bits_length, 21);
+        // Both land on the same 24-bit aligned cost from cursor 0, so the
+        // seed tie-break (ascending) picks seed_index 1 first, not the
+        // shorter raw bit_len.
+        assert_eq!(cands[0].bit_len, 21);
+        assert_eq!(cands[1].bit_len, 20);
     }
 
     #[test]
     fn bundle_wipes_out_nonbundles() {
         let mut blocks = [Block {
             candidates: vec![
-                Candidate {
-                    bits_length: 24,
-                    seed: 1,
-                    from_bundle: false,
-                },
-                Candidate {
-                    bits_length: 25,
-                    seed: 2,
-                    from_bundle: true,
-                },
-                Candidate {
-                    bits_length: 30,
-                    seed: 3,
-                    from_bundle: false,
-                },
+                candidate(24, 1, false),
+                candidate(25, 2, true),
+                candidate(30, 3, false),
             ],
         }];
-        prune_candidates(&mut blocks);
+        prune_candidates(&mut blocks, 0);
         let cands = &blocks[0].candidates;
         assert_eq!(cands.len(), 1);
         assert!(cands[0].from_bundle);
@@ -133,27 +180,58 @@ mod tests {
     fn pruning_is_deterministic() {
         let template = Block {
             candidates: vec![
-                Candidate {
-                    bits_length: 20,
-                    seed: 2,
-                    from_bundle: false,
-                },
-                Candidate {
-                    bits_length: 20,
-                    seed: 1,
-                    from_bundle: false,
-                },
-                Candidate {
-                    bits_length: 21,
-                    seed: 3,
-                    from_bundle: false,
-                },
+                candidate(20, 2, false),
+                candidate(20, 1, false),
+                candidate(21, 3, false),
             ],
         };
         let mut blocks1 = [template.clone()];
         let mut blocks2 = [template];
-        prune_candidates(&mut blocks1);
-        prune_candidates(&mut blocks2);
+        prune_candidates(&mut blocks1, 0);
+        prune_candidates(&mut blocks2, 0);
         assert_eq!(blocks1[0].candidates, blocks2[0].candidates);
     }
+
+    #[test]
+    fn padding_ties_override_raw_length_order() {
+        // Starting 2 bits into a byte: a raw-5-bit candidate and a raw-6-bit
+        // candidate both flush-pad out to the same 6-bit emitted cost, so the
+        // nominally shorter one must not automatically win.
+        let mut blocks = [Block {
+            candidates: vec![candidate(5, 5, false), candidate(6, 1, false)],
+        }];
+        prune_candidates(&mut blocks, 2);
+        let cands = &blocks[0].candidates;
+        assert_eq!(cands.len(), 2);
+        // Tied on aligned cost, so the seed tie-break picks the winner, not
+        // raw bit_len.
+        assert_eq!(cands[0].seed_index, 1);
+    }
+
+    #[test]
+    fn policy_overrides_delta_and_keep_count() {
+        let mut blocks = [Block {
+            candidates: vec![
+                candidate(16, 1, false),
+                candidate(17, 2, false),
+                candidate(18, 3, false),
+            ],
+        }];
+        let policy = PrunePolicy {
+            delta_bits: 2,
+            keep_count: 1,
+            tie_break: TieBreak::SeedAscending,
+        };
+        prune_candidates_with_policy(&mut blocks, 0, &policy);
+        let cands = &blocks[0].candidates;
+        assert_eq!(cands.len(), 1);
+        assert_eq!(cands[0].bit_len, 16);
+    }
+
+    #[test]
+    fn aligned_bits_length_rounds_up_to_byte_boundary() {
+        assert_eq!(aligned_bits_length(0, 8), 8);
+        assert_eq!(aligned_bits_length(2, 6), 6);
+        assert_eq!(aligned_bits_length(2, 8), 14);
+    }
 }
@@ -0,0 +1,64 @@
+//! Mid-run checkpoints for the indexed/streaming v2 engines.
+//!
+//! A multi-hour compression run can be interrupted. `--checkpoint PATH`
+//! snapshots the pass loop's state after every completed pass so a later
+//! `--resume PATH` invocation can continue from the next pass instead of
+//! restarting from pass 1. Snapshots are bincode, the same on-disk
+//! convention [`crate::block::BlockStore::save`] uses for its own snapshots.
+
+use crate::indexed::IndexedTelemetry;
+use crate::streaming::StreamingTelemetry;
+use crate::tlmr_v2::TlmrV2LayerDescriptor;
+use crate::TelomereError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Resumable state for [`crate::compress_indexed_v2_with_checkpoint_and_telemetry`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedCheckpoint {
+    /// Index (0-based) of the next pass to run.
+    pub next_pass: usize,
+    /// Output of the last completed pass; input to the next one.
+    pub current: Vec<u8>,
+    pub layers_inner_to_outer: Vec<TlmrV2LayerDescriptor>,
+    pub telemetry: IndexedTelemetry,
+}
+
+impl IndexedCheckpoint {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TelomereError> {
+        let bytes = bincode::serialize(self)
+            .map_err(|e| TelomereError::Header(format!("indexed checkpoint: {e}")))?;
+        std::fs::write(path, bytes).map_err(TelomereError::Io)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TelomereError> {
+        let bytes = std::fs::read(path).map_err(TelomereError::Io)?;
+        bincode::deserialize(&bytes)
+            .map_err(|e| TelomereError::Header(format!("indexed checkpoint: {e}")))
+    }
+}
+
+/// Resumable state for [`crate::compress_streaming_v2_with_checkpoint_and_telemetry`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamingCheckpoint {
+    /// Index (0-based) of the next pass to run.
+    pub next_pass: usize,
+    /// Output of the last completed pass; input to the next one.
+    pub current: Vec<u8>,
+    pub layers_inner_to_outer: Vec<TlmrV2LayerDescriptor>,
+    pub telemetry: StreamingTelemetry,
+}
+
+impl StreamingCheckpoint {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TelomereError> {
+        let bytes = bincode::serialize(self)
+            .map_err(|e| TelomereError::Header(format!("streaming checkpoint: {e}")))?;
+        std::fs::write(path, bytes).map_err(TelomereError::Io)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TelomereError> {
+        let bytes = std::fs::read(path).map_err(TelomereError::Io)?;
+        bincode::deserialize(&bytes)
+            .map_err(|e| TelomereError::Header(format!("streaming checkpoint: {e}")))
+    }
+}
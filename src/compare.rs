@@ -0,0 +1,161 @@
+//! Structural diff between two `.tlmr` files.
+//!
+//! Backs `telomere compare`: rather than just reporting that two outputs
+//! differ, this decodes both region lists (v1 records or v2 layer
+//! descriptors) and walks them in lockstep to localize the first divergence,
+//! which is the fast path for chasing nondeterminism between build flavors.
+
+use crate::tlmr::{decode_tlmr_header_with_len, inspect_v1_records, RecordInfo};
+use crate::tlmr_v2::{decode_v2_header_and_descriptors, TlmrV2LayerDescriptor};
+use crate::TelomereError;
+use crate::{TLMR_FORMAT_VERSION, TLMR_MAGIC, TLMR_V2_FORMAT_VERSION};
+use serde::Serialize;
+
+/// First point where two files' v1 record lists disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct V1RecordDivergence {
+    pub index: usize,
+    pub a: RecordInfo,
+    pub b: RecordInfo,
+}
+
+/// First point where two files' v2 layer stacks disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct V2LayerDivergence {
+    pub index: usize,
+    pub a: TlmrV2LayerDescriptor,
+    pub b: TlmrV2LayerDescriptor,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "format", rename_all = "snake_case")]
+pub enum CompareReport {
+    V1 {
+        identical: bool,
+        a_bytes: usize,
+        b_bytes: usize,
+        size_delta_bytes: i64,
+        a_original_len: u64,
+        b_original_len: u64,
+        a_record_count: usize,
+        b_record_count: usize,
+        first_divergence: Option<V1RecordDivergence>,
+    },
+    V2 {
+        identical: bool,
+        a_bytes: usize,
+        b_bytes: usize,
+        size_delta_bytes: i64,
+        a_original_len: u64,
+        b_original_len: u64,
+        a_layer_count: usize,
+        b_layer_count: usize,
+        first_divergence: Option<V2LayerDivergence>,
+    },
+}
+
+impl CompareReport {
+    pub fn identical(&self) -> bool {
+        match self {
+            CompareReport::V1 { identical, .. } | CompareReport::V2 { identical, .. } => {
+                *identical
+            }
+        }
+    }
+}
+
+fn is_v1(data: &[u8]) -> bool {
+    data.len() >= 5 && data[0..4] == TLMR_MAGIC && data[4] == TLMR_FORMAT_VERSION
+}
+
+fn is_v2(data: &[u8]) -> bool {
+    data.len() >= 5 && data[0..4] == TLMR_MAGIC && data[4] == TLMR_V2_FORMAT_VERSION
+}
+
+/// Decode `a` and `b` as `.tlmr` files of the same format and diff their
+/// region lists. Returns an error if either file is unrecognized or the two
+/// files are different formats (v1 vs v2) — there is no meaningful region
+/// alignment across formats to diff.
+pub fn compare_tlmr_files(a: &[u8], b: &[u8]) -> Result<CompareReport, TelomereError> {
+    if is_v1(a) && is_v1(b) {
+        return compare_v1(a, b);
+    }
+    if is_v2(a) && is_v2(b) {
+        return compare_v2(a, b);
+    }
+    if is_v1(a) || is_v2(a) || is_v1(b) || is_v2(b) {
+        return Err(TelomereError::Header(
+            "cannot compare .tlmr files of different formats (v1 vs v2)".into(),
+        ));
+    }
+    Err(TelomereError::Header(
+        "unrecognized file: missing TLMR magic or unsupported format version".into(),
+    ))
+}
+
+fn compare_v1(a: &[u8], b: &[u8]) -> Result<CompareReport, TelomereError> {
+    let (a_header, a_payload_start) = decode_tlmr_header_with_len(a)?;
+    let (b_header, b_payload_start) = decode_tlmr_header_with_len(b)?;
+    let a_records = inspect_v1_records(&a_header, &a[a_payload_start..])?;
+    let b_records = inspect_v1_records(&b_header, &b[b_payload_start..])?;
+
+    let first_divergence = a_records
+        .iter()
+        .zip(b_records.iter())
+        .enumerate()
+        .find(|(_, (ra, rb))| ra != rb)
+        .map(|(index, (ra, rb))| V1RecordDivergence {
+            index,
+            a: ra.clone(),
+            b: rb.clone(),
+        });
+
+    let identical = first_divergence.is_none()
+        && a_records.len() == b_records.len()
+        && a_header.original_len == b_header.original_len;
+
+    Ok(CompareReport::V1 {
+        identical,
+        a_bytes: a.len(),
+        b_bytes: b.len(),
+        size_delta_bytes: b.len() as i64 - a.len() as i64,
+        a_original_len: a_header.original_len,
+        b_original_len: b_header.original_len,
+        a_record_count: a_records.len(),
+        b_record_count: b_records.len(),
+        first_divergence,
+    })
+}
+
+fn compare_v2(a: &[u8], b: &[u8]) -> Result<CompareReport, TelomereError> {
+    let (a_header, a_layers, _) = decode_v2_header_and_descriptors(a)?;
+    let (b_header, b_layers, _) = decode_v2_header_and_descriptors(b)?;
+
+    let first_divergence = a_layers
+        .iter()
+        .zip(b_layers.iter())
+        .enumerate()
+        .find(|(_, (la, lb))| la != lb)
+        .map(|(index, (la, lb))| V2LayerDivergence {
+            index,
+            a: la.clone(),
+            b: lb.clone(),
+        });
+
+    let identical = first_divergence.is_none()
+        && a_layers.len() == b_layers.len()
+        && a_header.original_len == b_header.original_len
+        && a_header.output_hash == b_header.output_hash;
+
+    Ok(CompareReport::V2 {
+        identical,
+        a_bytes: a.len(),
+        b_bytes: b.len(),
+        size_delta_bytes: b.len() as i64 - a.len() as i64,
+        a_original_len: a_header.original_len,
+        b_original_len: b_header.original_len,
+        a_layer_count: a_layers.len(),
+        b_layer_count: b_layers.len(),
+        first_divergence,
+    })
+}
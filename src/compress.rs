@@ -6,17 +6,38 @@ use crate::bundler::bundle_one_layer;
 use crate::compress_stats::{CompressionStats, PassStats, RunSummary};
 use crate::config::Config;
 use crate::header::{encode_v1_record_into_writer, v1_record_bit_len, Header};
-use crate::seed::find_seed_match;
+use crate::seed::{find_seed_match_with_scan_count, find_seed_match_with_scan_count_and_cache};
+use crate::sha_cache::ShaCache;
 use crate::superposition::SuperpositionManager;
 use crate::tlmr::{
     encode_tlmr_header, truncated_hash_bits, TlmrHeader, LOTUS_PRESET_VERSION, TLMR_FORMAT_VERSION,
 };
 use crate::TelomereError;
+#[cfg(feature = "native-io")]
 use indicatif::{ProgressBar, ProgressStyle};
 use lotus::BitWriter as LotusBitWriter;
 use std::collections::HashMap;
 use std::time::Instant;
 
+/// Stand-in for [`indicatif::ProgressBar`] without the `native-io` feature,
+/// so `--progress` degrades to a no-op instead of disappearing from the
+/// function signature.
+#[cfg(not(feature = "native-io"))]
+struct ProgressBar;
+
+#[cfg(not(feature = "native-io"))]
+impl ProgressBar {
+    fn inc(&self, _delta: u64) {}
+    fn finish_and_clear(&self) {}
+}
+
+/// Per-shard capacity of the [`ShaCache`] shared across a whole
+/// [`compress_multi_pass_with_config_and_fingerprint`] call. Sized generously
+/// relative to `ShaCache`'s own `DEFAULT_SHARD_COUNT` so a run over a
+/// multi-megabyte input doesn't start evicting short (and therefore
+/// frequently retried) seeds mid-run.
+const SEED_EXPANSION_CACHE_CAPACITY_PER_SHARD: usize = 4096;
+
 fn lotus_err(e: lotus::LotusError) -> TelomereError {
     TelomereError::Header(format!("lotus codec error: {e}"))
 }
@@ -56,6 +77,123 @@ pub fn compress_multi_pass_with_config(
     config: &Config,
     max_passes: usize,
     show_status: bool,
+) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
+    compress_multi_pass_with_config_and_stats(data, config, max_passes, show_status, None)
+}
+
+/// Like [`compress_multi_pass_with_config`], but ticks `stats` once per block
+/// considered and logs the winning candidate of each pass, so `--stats-csv`
+/// can snapshot progress through a long brute-force run.
+pub fn compress_multi_pass_with_config_and_stats(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    show_status: bool,
+    stats: Option<&mut CompressionStats>,
+) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
+    compress_multi_pass_with_config_and_gpu(data, config, max_passes, show_status, stats, None)
+}
+
+/// Like [`compress_multi_pass_with_config_and_stats`], but when `gpu` is set
+/// also runs each pass's blocks through [`crate::gpu::GpuSeedMatcher`] in
+/// tiles of `gpu.tile_blocks`, contributing single-byte-seed candidates
+/// tagged [`crate::types::Engine::Gpu`] alongside the CPU search below.
+pub fn compress_multi_pass_with_config_and_gpu(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    show_status: bool,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
+    compress_multi_pass_with_config_and_limits(data, config, max_passes, show_status, stats, gpu, None)
+}
+
+/// Like [`compress_multi_pass_with_config_and_gpu`], but when `limits` is set
+/// also enforces [`crate::seed_logger::ResourceLimits`] across the three
+/// places this pass loop grows unbounded state: every accepted seed match is
+/// appended to `seed_log.bin` via a [`crate::seed_logger::SeedLogAppender`]
+/// (disk-checked), the superposition cache is put into bounded-memory mode
+/// via [`SuperpositionManager::enable_disk_spill`] sized off
+/// `limits.max_memory_bytes`, and resident memory is sampled every 256
+/// blocks against the same bound. This is the only caller that actually
+/// drives enforcement; every other compress entry point passes
+/// `limits: None` and runs unbounded, as before.
+pub fn compress_multi_pass_with_config_and_limits(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    show_status: bool,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
+    compress_multi_pass_with_config_and_profile(
+        data, config, max_passes, show_status, stats, gpu, limits, None,
+    )
+}
+
+/// Like [`compress_multi_pass_with_config_and_limits`], but when `profile` is
+/// set records a [`crate::profile::PhaseTimings`] per pass covering block
+/// splitting, seed search, superposition pruning, bit-stream emit, and
+/// output hashing, so `telomere profile` can report where a pass actually
+/// spends its time.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_multi_pass_with_config_and_profile(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    show_status: bool,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+    profile: Option<&mut Vec<crate::profile::PhaseTimings>>,
+) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
+    compress_multi_pass_with_config_and_decision_log(
+        data, config, max_passes, show_status, stats, gpu, limits, profile, None,
+    )
+}
+
+/// Like [`compress_multi_pass_with_config_and_profile`], but when
+/// `decision_log` is set appends a [`crate::decision_log::DecisionRecord`]
+/// for every block range the bundler emits, recording its chosen candidate
+/// plus whatever alternatives superposition pruning left behind for the
+/// same start index — a structured replacement for reading compressor
+/// decisions out of `tracing::debug!` output.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_multi_pass_with_config_and_decision_log(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    show_status: bool,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+    profile: Option<&mut Vec<crate::profile::PhaseTimings>>,
+    decision_log: Option<&mut crate::decision_log::DecisionLogger>,
+) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
+    compress_multi_pass_with_config_and_fingerprint(
+        data, config, max_passes, show_status, stats, gpu, limits, profile, decision_log, None,
+    )
+}
+
+/// Like [`compress_multi_pass_with_config_and_decision_log`], but when
+/// `fingerprint` is set folds every finalized block range's `(block_index,
+/// seed_index, bit_cost)` into a [`crate::fingerprint::RunFingerprint`], so
+/// a reproducibility audit can compare the final digest across two runs
+/// that claim the same settings instead of diffing full output.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_multi_pass_with_config_and_fingerprint(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    show_status: bool,
+    mut stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+    mut profile: Option<&mut Vec<crate::profile::PhaseTimings>>,
+    mut decision_log: Option<&mut crate::decision_log::DecisionLogger>,
+    mut fingerprint: Option<&mut crate::fingerprint::RunFingerprint>,
 ) -> Result<(Vec<u8>, Vec<usize>), TelomereError> {
     config.validate()?;
     if max_passes == 0 {
@@ -76,14 +214,31 @@ pub fn compress_multi_pass_with_config(
     let expander = config.get_expander();
 
     // Memory monitoring
+    #[cfg(feature = "native-io")]
     use sysinfo::{System, SystemExt};
+    #[cfg(feature = "native-io")]
     let mut sys = if config.memory_limit != usize::MAX {
         Some(System::new())
     } else {
         None
     };
 
+    // Buffered across the whole call (not re-opened per pass or per match) so
+    // a run logging many matches pays a handful of resource-limit checks and
+    // flushes instead of one open + one limit check per match — see
+    // `SeedLogAppender`'s doc comment.
+    let mut seed_log = limits
+        .map(|l| crate::seed_logger::SeedLogAppender::open(std::path::Path::new("seed_log.bin"), Some(*l)))
+        .transpose()?;
+
+    // Shared across every pass and block in this call so the same candidate
+    // seed (overwhelmingly likely near the short end of the enumeration,
+    // since the search always starts there) is only ever expanded once —
+    // see `ShaCache`'s doc comment.
+    let seed_expansion_cache = ShaCache::new(SEED_EXPANSION_CACHE_CAPACITY_PER_SHARD);
+
     while passes < pass_limit {
+        #[cfg(feature = "native-io")]
         if let Some(s) = &mut sys {
             s.refresh_memory();
             let used = s.used_memory(); // sysinfo 0.29: used_memory() returns bytes
@@ -96,7 +251,18 @@ pub fn compress_multi_pass_with_config(
         }
 
         passes += 1;
+        if let Some(s) = stats.as_deref_mut() {
+            s.sample_memory();
+        }
+        #[cfg(feature = "trace-spans")]
+        let _pass_span = tracing::info_span!("compress_pass", pass = passes, block_size = config.block_size).entered();
+        let mut timings = crate::profile::PhaseTimings {
+            pass: passes,
+            ..Default::default()
+        };
+
         // Split the current stream into fixed sized blocks.
+        let block_split_t0 = Instant::now();
         let mut blocks: Vec<&[u8]> = Vec::new();
         let mut offset = 0usize;
         let block_size = config.block_size;
@@ -105,13 +271,18 @@ pub fn compress_multi_pass_with_config(
             blocks.push(&current[offset..end]);
             offset += block_size;
         }
+        timings.block_split_ms = block_split_t0.elapsed().as_millis() as u64;
 
         let blocks_total = blocks.len();
+        // Track progress by byte offset rather than block count so indicatif's
+        // built-in rate/ETA placeholders report actual throughput instead of
+        // an opaque blocks/sec figure.
+        #[cfg(feature = "native-io")]
         let maybe_pb = if show_status && blocks_total > 0 {
-            let pb = ProgressBar::new(blocks_total as u64);
+            let pb = ProgressBar::new(current.len() as u64);
             pb.set_style(
                 ProgressStyle::with_template(
-                    "{bar:50.cyan/blue} {percent:>3}%  {pos}/{len} blocks",
+                    "{bar:50.cyan/blue} {percent:>3}%  {bytes}/{total_bytes}  {bytes_per_sec}  eta {eta}",
                 )
                 .unwrap(),
             );
@@ -119,11 +290,48 @@ pub fn compress_multi_pass_with_config(
         } else {
             None
         };
+        #[cfg(not(feature = "native-io"))]
+        let maybe_pb: Option<ProgressBar> = None;
 
         let mut mgr = SuperpositionManager::new(blocks.len());
+        if let Some(limits) = limits {
+            // Rough sizing: a superposed Candidate plus its bookkeeping runs
+            // a few hundred bytes; 512 is a conservative per-entry estimate
+            // so the cache doesn't balloon past `max_memory_bytes` before
+            // spilling kicks in.
+            let capacity = ((limits.max_memory_bytes / 512) as usize).max(1);
+            let spill_dir = std::env::temp_dir().join("telomere-spill");
+            std::fs::create_dir_all(&spill_dir).map_err(TelomereError::Io)?;
+            mgr.enable_disk_spill(capacity, spill_dir);
+        }
 
         // Insert all candidates for each block index.
+        let seed_search_t0 = Instant::now();
+        #[cfg(feature = "trace-spans")]
+        let _seed_search_span =
+            tracing::info_span!("seed_search", pass = passes, blocks = blocks.len()).entered();
         for (idx, _slice) in blocks.iter().enumerate() {
+            if crate::interrupt::is_interrupted() {
+                return Err(TelomereError::Interrupted);
+            }
+            if let Some(s) = stats.as_deref_mut() {
+                s.tick_block();
+            }
+            #[cfg(feature = "metrics")]
+            crate::metrics::global().record_block();
+            if let Some(limits) = limits {
+                if idx % 256 == 0 {
+                    let mut cache_sys = System::new();
+                    cache_sys.refresh_memory();
+                    let used = cache_sys.used_memory();
+                    if used > limits.max_memory_bytes {
+                        return Err(TelomereError::Internal(format!(
+                            "cache growth exceeded --max-memory-bytes: {used} > {}",
+                            limits.max_memory_bytes
+                        )));
+                    }
+                }
+            }
             // Literal candidate always exists.
             let lit_bits = v1_literal_candidate_bit_len(_slice.len())?;
             let _ = mgr.insert_superposed(
@@ -132,6 +340,12 @@ pub fn compress_multi_pass_with_config(
                     seed_index: usize::MAX as u64,
                     arity: 1,
                     bit_len: lit_bits,
+                    from_bundle: false,
+                    origin: crate::types::CandidateOrigin {
+                        pass: passes as u32,
+                        engine: crate::types::Engine::Cpu,
+                        method: crate::types::MatchMethod::Literal,
+                    },
                 },
             );
 
@@ -145,9 +359,18 @@ pub fn compress_multi_pass_with_config(
                     break;
                 }
                 let span = &current[span_start..span_end];
-                if let Some(seed_idx) =
-                    find_seed_match(span, config.max_seed_len, expander.as_ref())?
-                {
+                #[cfg(feature = "metrics")]
+                crate::metrics::global().record_seed_probe();
+                let (seed_found, scanned) = find_seed_match_with_scan_count_and_cache(
+                    span,
+                    config.max_seed_len,
+                    expander.as_ref(),
+                    Some(&seed_expansion_cache),
+                )?;
+                if let Some(s) = stats.as_deref_mut() {
+                    s.log_seeds_scanned(crate::types::Engine::Cpu, scanned);
+                }
+                if let Some(seed_idx) = seed_found {
                     let total_bits = v1_record_bit_len(arity, seed_idx as u64)?;
 
                     // Bit-accurate profit check: compare the record's wire
@@ -155,12 +378,22 @@ pub fn compress_multi_pass_with_config(
                     // packing the actual on-wire cost is `total_bits`, not
                     // `ceil(total_bits / 8)`, so the comparison is bit-vs-bit.
                     if total_bits < span.len() * 8 {
+                        if let Some(appender) = seed_log.as_mut() {
+                            let hash = *blake3::hash(span).as_bytes();
+                            appender.log(seed_idx as u64, hash)?;
+                        }
                         let _ = mgr.insert_superposed(
                             idx,
                             crate::types::Candidate {
                                 seed_index: seed_idx as u64,
                                 arity: arity as u8,
                                 bit_len: total_bits,
+                                from_bundle: arity > 1,
+                                origin: crate::types::CandidateOrigin {
+                                    pass: passes as u32,
+                                    engine: crate::types::Engine::Cpu,
+                                    method: crate::types::MatchMethod::BruteForce,
+                                },
                             },
                         );
                     }
@@ -168,17 +401,63 @@ pub fn compress_multi_pass_with_config(
             }
 
             if let Some(pb) = &maybe_pb {
-                if (idx & 0xF) == 0 {
-                    pb.inc(16);
+                pb.inc(_slice.len() as u64);
+            }
+        }
+        timings.seed_search_ms = seed_search_t0.elapsed().as_millis() as u64;
+
+        if let Some(gpu) = gpu {
+            tracing::info!(
+                "GPU pass: device={} tile_blocks={} blocks={}",
+                gpu.device,
+                gpu.tile_blocks,
+                blocks.len()
+            );
+            let mut gpu_store = crate::block::BlockStore::new();
+            let mut gpu_ids = Vec::with_capacity(blocks.len());
+            for (idx, slice) in blocks.iter().enumerate() {
+                gpu_ids.push(gpu_store.add_block(slice, idx, slice.len() * 8));
+            }
+            let mut matcher = crate::gpu::GpuSeedMatcher::new();
+            for tile in gpu_ids.chunks(gpu.tile_blocks.max(1)) {
+                matcher.load_tile(&gpu_store, tile);
+                if let Some(s) = stats.as_deref_mut() {
+                    s.log_seeds_scanned(crate::types::Engine::Gpu, 256);
+                }
+                for m in matcher.seed_match(0, 256, expander.as_ref())? {
+                    let Some(&idx) = m.block_indices.first() else {
+                        continue;
+                    };
+                    let total_bits = v1_record_bit_len(1, m.seed_index as u64)?;
+                    if total_bits < blocks[idx].len() * 8 {
+                        let _ = mgr.insert_superposed(
+                            idx,
+                            crate::types::Candidate {
+                                seed_index: m.seed_index as u64,
+                                arity: 1,
+                                bit_len: total_bits,
+                                from_bundle: false,
+                                origin: crate::types::CandidateOrigin {
+                                    pass: passes as u32,
+                                    engine: crate::types::Engine::Gpu,
+                                    method: crate::types::MatchMethod::BruteForce,
+                                },
+                            },
+                        );
+                    }
                 }
             }
         }
 
+        let pruning_t0 = Instant::now();
+        #[cfg(feature = "trace-spans")]
+        let _pruning_span = tracing::info_span!("superposition_pruning", pass = passes).entered();
         if config.enable_superposition {
             // No pruning before bundling to maximize options
         } else {
             mgr.prune_end_of_pass();
         }
+        timings.pruning_ms = pruning_t0.elapsed().as_millis() as u64;
 
         if let Some(pb) = &maybe_pb {
             pb.finish_and_clear();
@@ -215,6 +494,12 @@ pub fn compress_multi_pass_with_config(
                     seed_index: usize::MAX as u64,
                     arity: 1,
                     bit_len: v1_literal_candidate_bit_len(blocks[i].len())?,
+                    from_bundle: false,
+                    origin: crate::types::CandidateOrigin {
+                        pass: passes as u32,
+                        engine: crate::types::Engine::Cpu,
+                        method: crate::types::MatchMethod::Literal,
+                    },
                 });
 
             base_spans.push((i, best_arity_1));
@@ -233,6 +518,65 @@ pub fn compress_multi_pass_with_config(
         // 3. Run Bundler
         let final_spans = bundle_one_layer(&base_spans, &bundle_cands);
 
+        if let Some(logger) = decision_log.as_deref_mut() {
+            for (idx, cand) in &final_spans {
+                let alternatives = block_cand_map
+                    .get(idx)
+                    .map(|cands| {
+                        cands
+                            .iter()
+                            .filter(|c| {
+                                c.seed_index != cand.seed_index || c.arity != cand.arity
+                            })
+                            .map(crate::decision_log::AlternativeRecord::from)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let record = crate::decision_log::DecisionRecord {
+                    pass: passes as u32,
+                    block_start: *idx,
+                    arity: cand.arity,
+                    is_literal: cand.seed_index == usize::MAX as u64,
+                    bit_cost: cand.bit_len,
+                    alternatives,
+                };
+                logger.log(&record).map_err(TelomereError::Io)?;
+            }
+        }
+
+        if let Some(s) = stats.as_deref_mut() {
+            for (idx, cand) in &final_spans {
+                let is_literal = cand.seed_index == usize::MAX as u64;
+                if let Some(slice) = blocks.get(*idx) {
+                    s.maybe_log(slice, slice, !is_literal);
+                }
+                let seed_len = (cand.origin.method != crate::types::MatchMethod::Literal)
+                    .then_some(config.max_seed_len);
+                let original_bits: usize = blocks
+                    .iter()
+                    .skip(*idx)
+                    .take(cand.arity as usize)
+                    .map(|b| b.len() * 8)
+                    .sum();
+                let bit_savings = original_bits as i64 - cand.bit_len as i64;
+                s.log_match_with_origin(&cand.origin, cand.arity as usize, seed_len, bit_savings);
+            }
+        }
+        if let Some(fp) = fingerprint.as_deref_mut() {
+            for (idx, cand) in &final_spans {
+                fp.log_emit_decision(*idx, cand.seed_index, cand.bit_len);
+            }
+        }
+        #[cfg(feature = "metrics")]
+        for (_, cand) in &final_spans {
+            if cand.seed_index != usize::MAX as u64 {
+                crate::metrics::global().record_match(cand.arity as usize);
+                if cand.origin.engine == crate::types::Engine::Gpu {
+                    crate::metrics::global().record_gpu_match();
+                }
+            }
+        }
+
         // Build the next compressed stream from the bundled candidates.
         let last_block = if current.is_empty() {
             block_size
@@ -244,6 +588,9 @@ pub fn compress_multi_pass_with_config(
         // end of the payload (to byte-align the file's final byte) plus the
         // 0..7 alignment pad inside each literal record so its raw bytes can
         // be memcpy'd directly.
+        let emit_t0 = Instant::now();
+        #[cfg(feature = "trace-spans")]
+        let _emit_span = tracing::info_span!("emit", pass = passes).entered();
         let mut layer_writer = LotusBitWriter::new();
 
         for (_idx, cand) in final_spans {
@@ -273,6 +620,11 @@ pub fn compress_multi_pass_with_config(
 
         let payload_bit_len = layer_writer.bits_written() as u64;
         let payload = layer_writer.into_bytes();
+        timings.emit_ms = emit_t0.elapsed().as_millis() as u64;
+
+        let hashing_t0 = Instant::now();
+        let output_hash = truncated_hash_bits(&current, expander.as_ref(), config.hash_bits);
+        timings.hashing_ms = hashing_t0.elapsed().as_millis() as u64;
 
         let header = encode_tlmr_header(&TlmrHeader {
             version: TLMR_FORMAT_VERSION,
@@ -286,11 +638,20 @@ pub fn compress_multi_pass_with_config(
             layer_count: 1,
             original_len: current.len() as u64,
             payload_bit_len,
-            output_hash: truncated_hash_bits(&current, expander.as_ref(), config.hash_bits),
+            output_hash,
         });
         let mut next = header;
         next.extend(payload);
 
+        #[cfg(feature = "phase-stats")]
+        if let Some(s) = stats.as_deref_mut() {
+            s.log_phase_timings(&timings);
+        }
+
+        if let Some(profile) = profile.as_deref_mut() {
+            profile.push(timings);
+        }
+
         let saved = current.len().saturating_sub(next.len());
         if saved > 0 {
             gains.push(saved);
@@ -302,6 +663,13 @@ pub fn compress_multi_pass_with_config(
         current = next;
     }
 
+    if let Some(logger) = decision_log.as_deref_mut() {
+        logger.flush().map_err(TelomereError::Io)?;
+    }
+    if let Some(log) = seed_log.as_mut() {
+        log.flush()?;
+    }
+
     Ok((current, gains))
 }
 
@@ -313,6 +681,103 @@ pub fn compress_with_run_summary(
     data: &[u8],
     config: &Config,
     max_passes: usize,
+) -> Result<(Vec<u8>, RunSummary), TelomereError> {
+    compress_with_run_summary_and_stats(data, config, max_passes, None)
+}
+
+/// Like [`compress_with_run_summary`], but ticks `stats` through the
+/// underlying pass loop so `--stats-csv`/`--stats-interval` can observe a v1
+/// brute-force run in progress.
+pub fn compress_with_run_summary_and_stats(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    stats: Option<&mut CompressionStats>,
+) -> Result<(Vec<u8>, RunSummary), TelomereError> {
+    compress_with_run_summary_and_gpu(data, config, max_passes, stats, None)
+}
+
+/// Like [`compress_with_run_summary_and_stats`], but forwards `gpu` to
+/// [`compress_multi_pass_with_config_and_gpu`] so `--gpu` can drive the
+/// matcher through the same entry point the CLI's brute/v1 path uses.
+pub fn compress_with_run_summary_and_gpu(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+) -> Result<(Vec<u8>, RunSummary), TelomereError> {
+    compress_with_run_summary_and_limits(data, config, max_passes, stats, gpu, None)
+}
+
+/// Like [`compress_with_run_summary_and_gpu`], but forwards `limits` to
+/// [`compress_multi_pass_with_config_and_limits`] so `--max-disk-bytes`/
+/// `--max-memory-bytes` can enforce resource limits through the same entry
+/// point the CLI's brute/v1 path uses.
+pub fn compress_with_run_summary_and_limits(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+) -> Result<(Vec<u8>, RunSummary), TelomereError> {
+    compress_with_run_summary_and_profile(data, config, max_passes, stats, gpu, limits, None)
+}
+
+/// Like [`compress_with_run_summary_and_limits`], but forwards `profile` to
+/// [`compress_multi_pass_with_config_and_profile`] so `telomere profile` can
+/// report phase timings through the same entry point the CLI's brute/v1
+/// path uses.
+pub fn compress_with_run_summary_and_profile(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+    profile: Option<&mut Vec<crate::profile::PhaseTimings>>,
+) -> Result<(Vec<u8>, RunSummary), TelomereError> {
+    compress_with_run_summary_and_decision_log(data, config, max_passes, stats, gpu, limits, profile, None)
+}
+
+/// Like [`compress_with_run_summary_and_profile`], but forwards
+/// `decision_log` to [`compress_multi_pass_with_config_and_decision_log`] so
+/// the same per-block decision trail is available through the `RunSummary`
+/// entry point, not just the raw pass loop.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_with_run_summary_and_decision_log(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+    profile: Option<&mut Vec<crate::profile::PhaseTimings>>,
+    decision_log: Option<&mut crate::decision_log::DecisionLogger>,
+) -> Result<(Vec<u8>, RunSummary), TelomereError> {
+    compress_with_run_summary_and_fingerprint(
+        data, config, max_passes, stats, gpu, limits, profile, decision_log, None,
+    )
+}
+
+/// Like [`compress_with_run_summary_and_decision_log`], but when
+/// `fingerprint` is set runs through
+/// [`compress_multi_pass_with_config_and_fingerprint`] and attaches the
+/// finished [`crate::fingerprint::RunFingerprint`]'s digest to
+/// [`RunSummary::run_fingerprint`], so a reproducibility audit can read it
+/// straight off the printed/JSON summary.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_with_run_summary_and_fingerprint(
+    data: &[u8],
+    config: &Config,
+    max_passes: usize,
+    stats: Option<&mut CompressionStats>,
+    gpu: Option<&crate::gpu::GpuTileConfig>,
+    limits: Option<&crate::seed_logger::ResourceLimits>,
+    profile: Option<&mut Vec<crate::profile::PhaseTimings>>,
+    decision_log: Option<&mut crate::decision_log::DecisionLogger>,
+    mut fingerprint: Option<&mut crate::fingerprint::RunFingerprint>,
 ) -> Result<(Vec<u8>, RunSummary), TelomereError> {
     if max_passes == 0 {
         return Err(TelomereError::Config(
@@ -321,9 +786,23 @@ pub fn compress_with_run_summary(
     }
     let original_bytes = data.len();
     let t0 = Instant::now();
-    let (out, _) = compress_multi_pass_with_config(data, config, max_passes, false)?;
+    let (out, _) = compress_multi_pass_with_config_and_fingerprint(
+        data,
+        config,
+        max_passes,
+        false,
+        stats,
+        gpu,
+        limits,
+        profile,
+        decision_log,
+        fingerprint.as_deref_mut(),
+    )?;
+    #[cfg(feature = "metrics")]
+    crate::metrics::global().add_bytes(original_bytes as u64, out.len() as u64);
     let pass_stats = vec![PassStats::new(1, original_bytes, out.len(), t0.elapsed())];
-    let summary = RunSummary::new(original_bytes, pass_stats);
+    let mut summary = RunSummary::new(original_bytes, pass_stats);
+    summary.run_fingerprint = fingerprint.map(|fp| fp.finalize_hex());
     Ok((out, summary))
 }
 
@@ -343,13 +822,27 @@ pub fn compress_block_with_config(
     let expander = config.get_expander();
 
     let slice = &input[..block_size];
-    if let Some(seed_idx) = find_seed_match(slice, config.max_seed_len, expander.as_ref())? {
+    let (seed_found, scanned) =
+        find_seed_match_with_scan_count(slice, config.max_seed_len, expander.as_ref())?;
+    if let Some(s) = stats.as_deref_mut() {
+        s.log_seeds_scanned(crate::types::Engine::Cpu, scanned);
+    }
+    if let Some(seed_idx) = seed_found {
         let total_bits = v1_record_bit_len(1, seed_idx as u64)?;
 
         if total_bits < block_size * 8 {
             if let Some(s) = stats.as_deref_mut() {
                 s.maybe_log(slice, slice, false);
-                s.log_match(false, 1);
+                s.log_match_with_origin(
+                    &crate::types::CandidateOrigin {
+                        pass: 1,
+                        engine: crate::types::Engine::Cpu,
+                        method: crate::types::MatchMethod::BruteForce,
+                    },
+                    1,
+                    Some(config.max_seed_len),
+                    block_size as i64 * 8 - total_bits as i64,
+                );
             }
             return Ok(Some((Header::Arity(1), block_size)));
         }
@@ -357,7 +850,16 @@ pub fn compress_block_with_config(
 
     if let Some(s) = stats.as_deref_mut() {
         s.maybe_log(slice, slice, false);
-        s.log_match(false, 1);
+        s.log_match_with_origin(
+            &crate::types::CandidateOrigin {
+                pass: 1,
+                engine: crate::types::Engine::Cpu,
+                method: crate::types::MatchMethod::Literal,
+            },
+            1,
+            None,
+            0,
+        );
     }
     Ok(Some((Header::Literal, block_size)))
 }
@@ -374,11 +876,11 @@ pub fn compress(data: &[u8], block_size: usize) -> Result<Vec<u8>, TelomereError
 
     let mut in_len = data.len();
     if gains.is_empty() {
-        println!("Compression pass 1: {} bytes → {} bytes", in_len, out.len());
+        tracing::info!("Compression pass 1: {} bytes → {} bytes", in_len, out.len());
     }
     for (idx, saved) in gains.iter().enumerate() {
         let out_len = in_len.saturating_sub(*saved);
-        println!(
+        tracing::info!(
             "Compression pass {}: {} bytes → {} bytes",
             idx + 1,
             in_len,
@@ -448,4 +950,37 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn run_fingerprint_is_stable_across_repeated_compressions() {
+        let config = Config {
+            block_size: 4,
+            max_seed_len: 1,
+            ..Config::default()
+        };
+        let data = b"abcdabcdabcdabcd".to_vec();
+
+        let mut fp_a = crate::fingerprint::RunFingerprint::new();
+        let (_, summary_a) = compress_with_run_summary_and_fingerprint(
+            &data,
+            &config,
+            1,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&mut fp_a),
+        )
+        .unwrap();
+
+        let mut fp_b = crate::fingerprint::RunFingerprint::new();
+        let (_, summary_b) = compress_with_run_summary_and_fingerprint(
+            &data, &config, 1, None, None, None, None, None, Some(&mut fp_b),
+        )
+        .unwrap();
+
+        assert!(summary_a.run_fingerprint.is_some());
+        assert_eq!(summary_a.run_fingerprint, summary_b.run_fingerprint);
+    }
 }
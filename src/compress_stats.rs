@@ -1,16 +1,53 @@
 //! Compression statistics: per-pass deltas, JSON export, CSV logging.
 
+use crate::term::{paint, Color};
+#[cfg(feature = "native-io")]
 use csv::Writer;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Write;
 use std::time::{Duration, Instant};
 
+/// One [`CompressionStats::tick_block`] row, as written to the JSONL sink
+/// (see [`CompressionStats::with_jsonl`]). Mirrors the `--stats-csv` column
+/// layout but keeps the histograms as structured maps instead of the CSV's
+/// `"k:v k:v"` cell format, since JSONL consumers don't need to re-parse it.
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct StatsRow {
+    seconds: f32,
+    total_blocks: usize,
+    compressed_blocks: usize,
+    arity_histogram: BTreeMap<usize, usize>,
+    literal_run_histogram: BTreeMap<usize, usize>,
+}
+
+/// Default number of `--stats-csv` rows buffered between flushes (see
+/// [`CompressionStats::with_csv_flush_interval`]). Flushing a `csv::Writer`
+/// forces a syscall, so flushing on nearly every block (the old behavior)
+/// measurably slows compression when stats are enabled.
+const DEFAULT_CSV_FLUSH_INTERVAL: u64 = 256;
+
+/// Format a delta percentage for display, green when the pass shrank the
+/// data and red when it grew it, so a scrolling multi-pass log reads at a
+/// glance.
+fn colorize_delta_pct(delta_pct: f64) -> String {
+    let text = format!("{delta_pct:+.2}%");
+    if delta_pct < 0.0 {
+        paint(&text, Color::Green)
+    } else if delta_pct > 0.0 {
+        paint(&text, Color::Red)
+    } else {
+        text
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Per-pass delta statistics
 // ---------------------------------------------------------------------------
 
 /// Statistics for a single compression pass.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
 pub struct PassStats {
     pub pass: usize,
     pub bytes_in: usize,
@@ -18,6 +55,7 @@ pub struct PassStats {
     pub delta_bytes: i64,
     pub delta_pct: f64,
     pub duration_ms: u64,
+    pub throughput_bytes_per_sec: f64,
 }
 
 impl PassStats {
@@ -28,13 +66,20 @@ impl PassStats {
         } else {
             delta_bytes as f64 / bytes_in as f64 * 100.0
         };
+        let duration_ms = duration.as_millis() as u64;
+        let throughput_bytes_per_sec = if duration_ms == 0 {
+            0.0
+        } else {
+            bytes_in as f64 / (duration_ms as f64 / 1000.0)
+        };
         Self {
             pass,
             bytes_in,
             bytes_out,
             delta_bytes,
             delta_pct,
-            duration_ms: duration.as_millis() as u64,
+            duration_ms,
+            throughput_bytes_per_sec,
         }
     }
 
@@ -44,7 +89,7 @@ impl PassStats {
 }
 
 /// Summary of a full multi-pass compression run.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
 pub struct RunSummary {
     pub passes: Vec<PassStats>,
     pub original_bytes: usize,
@@ -52,6 +97,11 @@ pub struct RunSummary {
     pub total_delta_bytes: i64,
     pub total_delta_pct: f64,
     pub total_duration_ms: u64,
+    pub throughput_bytes_per_sec: f64,
+    /// Digest over every emit decision of the run, set when the caller ran
+    /// through [`crate::compress_with_run_summary_and_fingerprint`]. `None`
+    /// for callers that didn't opt in, since computing it isn't free.
+    pub run_fingerprint: Option<String>,
 }
 
 impl RunSummary {
@@ -64,6 +114,11 @@ impl RunSummary {
             total_delta as f64 / original_bytes as f64 * 100.0
         };
         let total_ms = passes.iter().map(|p| p.duration_ms).sum();
+        let throughput_bytes_per_sec = if total_ms == 0 {
+            0.0
+        } else {
+            original_bytes as f64 / (total_ms as f64 / 1000.0)
+        };
         Self {
             passes,
             original_bytes,
@@ -71,6 +126,8 @@ impl RunSummary {
             total_delta_bytes: total_delta,
             total_delta_pct: total_pct,
             total_duration_ms: total_ms,
+            throughput_bytes_per_sec,
+            run_fingerprint: None,
         }
     }
 
@@ -79,16 +136,28 @@ impl RunSummary {
     }
 
     pub fn print_summary(&self) {
-        eprintln!(
-            "Compression: {} → {} bytes ({:+.2}%) in {}ms",
-            self.original_bytes, self.final_bytes, self.total_delta_pct, self.total_duration_ms
+        tracing::info!(
+            "Compression: {} → {} bytes ({}) in {}ms ({:.1} KB/s)",
+            self.original_bytes,
+            self.final_bytes,
+            colorize_delta_pct(self.total_delta_pct),
+            self.total_duration_ms,
+            self.throughput_bytes_per_sec / 1024.0,
         );
         for p in &self.passes {
-            eprintln!(
-                "  pass {}: {} → {} ({:+.2}%) {}ms",
-                p.pass, p.bytes_in, p.bytes_out, p.delta_pct, p.duration_ms
+            tracing::info!(
+                "  pass {}: {} → {} ({}) {}ms ({:.1} KB/s)",
+                p.pass,
+                p.bytes_in,
+                p.bytes_out,
+                colorize_delta_pct(p.delta_pct),
+                p.duration_ms,
+                p.throughput_bytes_per_sec / 1024.0,
             );
         }
+        if let Some(fingerprint) = &self.run_fingerprint {
+            tracing::info!("  fingerprint: {fingerprint}");
+        }
     }
 }
 
@@ -96,14 +165,71 @@ impl RunSummary {
 // Block-level stats (existing, kept for compatibility)
 // ---------------------------------------------------------------------------
 
+/// Render a `BTreeMap<usize, usize>` histogram as `key:count` pairs for a
+/// single log line or CSV cell, e.g. `"1:40 2:12 4:3"`.
+fn format_histogram(hist: &BTreeMap<usize, usize>) -> String {
+    hist.iter()
+        .map(|(k, v)| format!("{k}:{v}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 pub struct CompressionStats {
     start_time: Instant,
     pub total_blocks: usize,
     pub compressed_blocks: usize,
-    pub greedy_matches: usize,
-    pub fallback_matches: usize,
-    csv: Option<Writer<File>>,
+    /// Matches attributed to a seed-table lookup via [`CandidateOrigin`].
+    pub table_hits: usize,
+    /// Matches attributed to brute-force seed enumeration via [`CandidateOrigin`].
+    pub brute_force_matches: usize,
+    /// Matches attributed to the CPU engine via [`CandidateOrigin`], so a
+    /// hybrid run's summary can show the CPU/GPU split rather than just the
+    /// GPU count on its own.
+    pub cpu_matches: usize,
+    /// Matches attributed to the GPU engine via [`CandidateOrigin`].
+    pub gpu_matches: usize,
+    /// Seeds tried by the CPU engine while searching for a match, summed
+    /// across every call to [`Self::log_seeds_scanned`].
+    pub cpu_seeds_scanned: usize,
+    /// Seeds tried by the GPU engine while searching for a match.
+    pub gpu_seeds_scanned: usize,
+    /// Bits saved (original span size minus encoded size) by CPU matches,
+    /// so `--gpu` runs can judge whether the GPU pass is pulling its weight.
+    pub cpu_bits_saved: u64,
+    /// Bits saved by GPU matches.
+    pub gpu_bits_saved: u64,
+    /// Count of non-literal matches by block arity (1 = single block, 2 =
+    /// two-block bundle, ...).
+    pub arity_histogram: BTreeMap<usize, usize>,
+    /// Count of non-literal matches by the seed length (bytes) the run's
+    /// `--seed-depth` searched at.
+    pub seed_length_histogram: BTreeMap<usize, usize>,
+    /// Count of consecutive-literal-block run lengths, flushed whenever a
+    /// run ends (a non-literal match is logged, or [`Self::report`] runs).
+    pub literal_run_histogram: BTreeMap<usize, usize>,
+    current_literal_run: usize,
+    /// Highest resident memory seen across calls to [`Self::sample_memory`].
+    pub peak_memory_bytes: u64,
+    memory_sample_sum_bytes: u64,
+    memory_sample_count: u64,
+    #[cfg(feature = "native-io")]
+    csv: Option<Writer<Box<dyn Write>>>,
+    /// Appends one [`StatsRow`] per [`Self::tick_block`] call as JSON Lines,
+    /// for embedders that want structured rows instead of CSV cells (see
+    /// [`Self::with_jsonl`]/[`Self::with_jsonl_writer`]).
+    jsonl: Option<Box<dyn Write>>,
     interval: u64,
+    /// Rows written to `csv`/`jsonl` since the last flush. [`Self::tick_block`]
+    /// only flushes once this reaches `csv_flush_interval`, since flushing on
+    /// nearly every block measurably slows compression; [`Self::finish`]
+    /// flushes whatever is left so the sink is never missing its tail.
+    rows_since_flush: u64,
+    csv_flush_interval: u64,
+    /// Wall time accumulated per pipeline phase across every pass, via
+    /// [`Self::log_phase_timings`]. Only populated when the `phase-stats`
+    /// feature is enabled.
+    #[cfg(feature = "phase-stats")]
+    pub phase_timings: crate::profile::PhaseTimingTotals,
 }
 
 impl Default for CompressionStats {
@@ -118,33 +244,78 @@ impl CompressionStats {
             start_time: Instant::now(),
             total_blocks: 0,
             compressed_blocks: 0,
-            greedy_matches: 0,
-            fallback_matches: 0,
+            table_hits: 0,
+            brute_force_matches: 0,
+            cpu_matches: 0,
+            gpu_matches: 0,
+            cpu_seeds_scanned: 0,
+            gpu_seeds_scanned: 0,
+            cpu_bits_saved: 0,
+            gpu_bits_saved: 0,
+            arity_histogram: BTreeMap::new(),
+            seed_length_histogram: BTreeMap::new(),
+            literal_run_histogram: BTreeMap::new(),
+            current_literal_run: 0,
+            peak_memory_bytes: 0,
+            memory_sample_sum_bytes: 0,
+            memory_sample_count: 0,
+            #[cfg(feature = "native-io")]
             csv: None,
+            jsonl: None,
             interval: 0,
+            rows_since_flush: 0,
+            csv_flush_interval: DEFAULT_CSV_FLUSH_INTERVAL,
+            #[cfg(feature = "phase-stats")]
+            phase_timings: crate::profile::PhaseTimingTotals::default(),
         }
     }
 
+    /// Fold one pass's [`crate::profile::PhaseTimings`] into
+    /// [`Self::phase_timings`]. A no-op unless the `phase-stats` feature is
+    /// enabled.
+    #[cfg(feature = "phase-stats")]
+    pub fn log_phase_timings(&mut self, timings: &crate::profile::PhaseTimings) {
+        self.phase_timings.add(timings);
+    }
+
+    #[cfg(feature = "native-io")]
     pub fn with_csv(path: &str) -> Result<Self, crate::TelomereError> {
         let file = File::create(path).map_err(crate::TelomereError::from)?;
-        let mut wtr = Writer::from_writer(file);
+        Self::with_csv_writer(file)
+    }
+
+    /// Like [`Self::with_csv`], but accepts any [`Write`] instead of a file
+    /// path, so tests and embedders can capture `--stats-csv`-style rows in
+    /// memory (e.g. `Vec<u8>`) or forward them over a socket.
+    #[cfg(feature = "native-io")]
+    pub fn with_csv_writer(writer: impl Write + 'static) -> Result<Self, crate::TelomereError> {
+        let mut wtr = Writer::from_writer(Box::new(writer) as Box<dyn Write>);
         wtr.write_record(&[
             "seconds",
             "total_blocks",
             "compressed_blocks",
-            "greedy",
-            "fallback",
+            "arity_histogram",
+            "literal_run_histogram",
         ])
         .map_err(|e| crate::TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
-        Ok(Self {
-            start_time: Instant::now(),
-            total_blocks: 0,
-            compressed_blocks: 0,
-            greedy_matches: 0,
-            fallback_matches: 0,
-            csv: Some(wtr),
-            interval: 0,
-        })
+        let mut stats = Self::new();
+        stats.csv = Some(wtr);
+        Ok(stats)
+    }
+
+    /// Append one [`StatsRow`] of JSON Lines per [`Self::tick_block`] call to
+    /// `path`, alongside (or instead of) the CSV sink.
+    pub fn with_jsonl(mut self, path: &str) -> Result<Self, crate::TelomereError> {
+        let file = File::create(path).map_err(crate::TelomereError::from)?;
+        self.jsonl = Some(Box::new(file));
+        Ok(self)
+    }
+
+    /// Like [`Self::with_jsonl`], but accepts any [`Write`] instead of a
+    /// file path.
+    pub fn with_jsonl_writer(mut self, writer: impl Write + 'static) -> Self {
+        self.jsonl = Some(Box::new(writer));
+        self
     }
 
     pub fn with_interval(mut self, interval: u64) -> Self {
@@ -152,18 +323,124 @@ impl CompressionStats {
         self
     }
 
-    pub fn log_match(&mut self, is_greedy: bool, blocks_compressed: usize) {
+    /// Flush the `--stats-csv`/JSONL writer every `interval` rows instead of
+    /// the default [`DEFAULT_CSV_FLUSH_INTERVAL`]. `0` flushes every row
+    /// (the old, slower-but-most-durable behavior).
+    pub fn with_csv_flush_interval(mut self, interval: u64) -> Self {
+        self.csv_flush_interval = interval;
+        self
+    }
+
+    /// Sample current resident memory and fold it into
+    /// [`Self::peak_memory_bytes`]/[`Self::average_memory_bytes`]. Meant to
+    /// be called once per pass boundary, not per block — `sysinfo::System`
+    /// refreshes aren't cheap enough for the per-block hot loop. A no-op
+    /// without the `native-io` feature, since there's no portable way to
+    /// read resident memory there.
+    pub fn sample_memory(&mut self) {
+        #[cfg(feature = "native-io")]
+        {
+            use sysinfo::{ProcessExt, System, SystemExt};
+            let Ok(pid) = sysinfo::get_current_pid() else {
+                return;
+            };
+            let mut sys = System::new();
+            sys.refresh_process(pid);
+            let Some(used_bytes) = sys.process(pid).map(|p| p.memory()) else {
+                return;
+            };
+            self.peak_memory_bytes = self.peak_memory_bytes.max(used_bytes);
+            self.memory_sample_sum_bytes += used_bytes;
+            self.memory_sample_count += 1;
+        }
+    }
+
+    pub fn average_memory_bytes(&self) -> u64 {
+        self.memory_sample_sum_bytes
+            .checked_div(self.memory_sample_count)
+            .unwrap_or(0)
+    }
+
+    /// End the current literal run (if any), recording its length in
+    /// [`Self::literal_run_histogram`].
+    fn flush_literal_run(&mut self) {
+        if self.current_literal_run > 0 {
+            *self
+                .literal_run_histogram
+                .entry(self.current_literal_run)
+                .or_insert(0) += 1;
+            self.current_literal_run = 0;
+        }
+    }
+
+    /// Attribute a match to the [`crate::types::CandidateOrigin`] that
+    /// produced it, so table-hit, brute-force and GPU matches can be told
+    /// apart when tuning, and feed the arity/seed-length/literal-run
+    /// histograms `report()` and CSV output draw from.
+    ///
+    /// `seed_len` is the seed byte length the search ran at (`--seed-depth`);
+    /// pass `None` for literal matches, which have no seed. `bit_savings` is
+    /// the original span size minus its encoded size in bits (0 for literal
+    /// matches, which save nothing), folded into [`Self::cpu_bits_saved`] or
+    /// [`Self::gpu_bits_saved`] by `origin.engine`.
+    pub fn log_match_with_origin(
+        &mut self,
+        origin: &crate::types::CandidateOrigin,
+        blocks_compressed: usize,
+        seed_len: Option<usize>,
+        bit_savings: i64,
+    ) {
+        use crate::types::{Engine, MatchMethod};
+
         self.compressed_blocks += blocks_compressed;
-        if is_greedy {
-            self.greedy_matches += 1;
+        match origin.method {
+            MatchMethod::TableHit => self.table_hits += 1,
+            MatchMethod::BruteForce => self.brute_force_matches += 1,
+            MatchMethod::Literal => {}
+        }
+        if origin.method != MatchMethod::Literal {
+            match origin.engine {
+                Engine::Cpu => {
+                    self.cpu_matches += 1;
+                    self.cpu_bits_saved = self.cpu_bits_saved.saturating_add_signed(bit_savings);
+                }
+                Engine::Gpu => {
+                    self.gpu_matches += 1;
+                    self.gpu_bits_saved = self.gpu_bits_saved.saturating_add_signed(bit_savings);
+                }
+            }
+        }
+
+        if origin.method == MatchMethod::Literal {
+            self.current_literal_run += blocks_compressed;
         } else {
-            self.fallback_matches += 1;
+            self.flush_literal_run();
+            *self
+                .arity_histogram
+                .entry(blocks_compressed)
+                .or_insert(0) += 1;
+            if let Some(seed_len) = seed_len {
+                *self
+                    .seed_length_histogram
+                    .entry(seed_len)
+                    .or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Record that `count` seeds were tried by `engine` during a search,
+    /// whether or not it found a match, so a hybrid run can report each
+    /// backend's seeds-per-match ratio alongside its match count.
+    pub fn log_seeds_scanned(&mut self, engine: crate::types::Engine, count: usize) {
+        match engine {
+            crate::types::Engine::Cpu => self.cpu_seeds_scanned += count,
+            crate::types::Engine::Gpu => self.gpu_seeds_scanned += count,
         }
     }
 
     pub fn maybe_log(&self, span: &[u8], seed: &[u8], is_greedy: bool) {
         if self.interval > 0 && (self.total_blocks as u64) % self.interval == 0 {
-            println!(
+            tracing::debug!(
                 "[{:>6}] span: {:02X?}  seed: {:02X?}  method: {}",
                 self.total_blocks,
                 &span[..3.min(span.len())],
@@ -175,35 +452,431 @@ impl CompressionStats {
 
     pub fn tick_block(&mut self) {
         self.total_blocks += 1;
+        #[cfg(feature = "native-io")]
+        let has_csv = self.csv.is_some();
+        #[cfg(not(feature = "native-io"))]
+        let has_csv = false;
+        if !has_csv && self.jsonl.is_none() {
+            return;
+        }
+        let elapsed = self.start_time.elapsed().as_secs_f32();
+        #[cfg(feature = "native-io")]
         if let Some(wtr) = self.csv.as_mut() {
-            let elapsed = self.start_time.elapsed().as_secs_f32();
             let _ = wtr.write_record(&[
                 format!("{:.3}", elapsed),
                 self.total_blocks.to_string(),
                 self.compressed_blocks.to_string(),
-                self.greedy_matches.to_string(),
-                self.fallback_matches.to_string(),
+                format_histogram(&self.arity_histogram),
+                format_histogram(&self.literal_run_histogram),
             ]);
-            let _ = wtr.flush();
+        }
+        if let Some(wtr) = self.jsonl.as_mut() {
+            let row = StatsRow {
+                seconds: elapsed,
+                total_blocks: self.total_blocks,
+                compressed_blocks: self.compressed_blocks,
+                arity_histogram: self.arity_histogram.clone(),
+                literal_run_histogram: self.literal_run_histogram.clone(),
+            };
+            if let Ok(line) = serde_json::to_string(&row) {
+                let _ = writeln!(wtr, "{line}");
+            }
+        }
+        self.rows_since_flush += 1;
+        if self.csv_flush_interval == 0 || self.rows_since_flush >= self.csv_flush_interval {
+            let _ = self.flush_sinks();
         }
     }
 
-    pub fn report(&self) {
+    /// Flush both sinks and reset the buffered-row counter. Shared by the
+    /// interval flush in [`Self::tick_block`] and the final flush in
+    /// [`Self::finish`].
+    fn flush_sinks(&mut self) -> Result<(), crate::TelomereError> {
+        #[cfg(feature = "native-io")]
+        if let Some(wtr) = self.csv.as_mut() {
+            wtr.flush().map_err(crate::TelomereError::from)?;
+        }
+        if let Some(wtr) = self.jsonl.as_mut() {
+            wtr.flush().map_err(crate::TelomereError::from)?;
+        }
+        self.rows_since_flush = 0;
+        Ok(())
+    }
+
+    /// Flush any `--stats-csv`/JSONL rows buffered since the last interval
+    /// flush. Callers that create a [`CompressionStats`] with
+    /// [`Self::with_csv`] or [`Self::with_jsonl`] must call this once the
+    /// run is done, since buffering rows between flushes (see
+    /// [`Self::with_csv_flush_interval`]) means the last partial batch would
+    /// otherwise never reach disk.
+    pub fn finish(&mut self) -> Result<(), crate::TelomereError> {
+        self.flush_sinks()
+    }
+
+    pub fn report(&mut self) {
+        self.flush_literal_run();
         let elapsed = self.start_time.elapsed().as_secs_f32();
         let ratio = self.compressed_blocks as f32 / self.total_blocks.max(1) as f32;
-        println!(
-            "Compression: {:.2}s | blocks={} compressed={} ({:.1}%) greedy={} fallback={}",
+        tracing::info!(
+            "Compression: {:.2}s | blocks={} compressed={} ({:.1}%) table_hits={} brute_force={} gpu={}",
             elapsed,
             self.total_blocks,
             self.compressed_blocks,
             ratio * 100.0,
-            self.greedy_matches,
-            self.fallback_matches,
+            self.table_hits,
+            self.brute_force_matches,
+            self.gpu_matches,
+        );
+        tracing::info!(
+            "  cpu: matches={} seeds_scanned={} bits_saved={} | gpu: matches={} seeds_scanned={} bits_saved={}",
+            self.cpu_matches,
+            self.cpu_seeds_scanned,
+            self.cpu_bits_saved,
+            self.gpu_matches,
+            self.gpu_seeds_scanned,
+            self.gpu_bits_saved,
+        );
+        tracing::info!("  arity histogram: {}", format_histogram(&self.arity_histogram));
+        tracing::info!(
+            "  seed length histogram: {}",
+            format_histogram(&self.seed_length_histogram)
+        );
+        tracing::info!(
+            "  literal run histogram: {}",
+            format_histogram(&self.literal_run_histogram)
+        );
+        tracing::info!(
+            "  memory: peak {} bytes, average {} bytes ({} samples)",
+            self.peak_memory_bytes,
+            self.average_memory_bytes(),
+            self.memory_sample_count,
+        );
+        #[cfg(feature = "phase-stats")]
+        tracing::info!(
+            "  phase timings: block_split={}ms seed_search={}ms pruning={}ms emit={}ms hashing={}ms",
+            self.phase_timings.block_split_ms,
+            self.phase_timings.seed_search_ms,
+            self.phase_timings.pruning_ms,
+            self.phase_timings.emit_ms,
+            self.phase_timings.hashing_ms,
         );
     }
+
+    /// Snapshot the current counters as a [`CompressionStatsReport`], for
+    /// callers that want the data without the `tracing`-formatted text
+    /// [`Self::report`] emits.
+    pub fn to_report(&mut self) -> CompressionStatsReport {
+        self.flush_literal_run();
+        let elapsed_secs = self.start_time.elapsed().as_secs_f32();
+        let ratio = self.compressed_blocks as f32 / self.total_blocks.max(1) as f32;
+        CompressionStatsReport {
+            elapsed_secs,
+            total_blocks: self.total_blocks,
+            compressed_blocks: self.compressed_blocks,
+            ratio,
+            table_hits: self.table_hits,
+            brute_force_matches: self.brute_force_matches,
+            cpu_matches: self.cpu_matches,
+            gpu_matches: self.gpu_matches,
+            cpu_seeds_scanned: self.cpu_seeds_scanned,
+            gpu_seeds_scanned: self.gpu_seeds_scanned,
+            cpu_bits_saved: self.cpu_bits_saved,
+            gpu_bits_saved: self.gpu_bits_saved,
+            arity_histogram: self.arity_histogram.clone(),
+            seed_length_histogram: self.seed_length_histogram.clone(),
+            literal_run_histogram: self.literal_run_histogram.clone(),
+            peak_memory_bytes: self.peak_memory_bytes,
+            average_memory_bytes: self.average_memory_bytes(),
+        }
+    }
+
+    pub fn to_json(&mut self) -> String {
+        self.to_report().to_json()
+    }
+}
+
+/// Lock-free counters for the subset of [`CompressionStats`] that a
+/// multi-threaded compression pass can update without serializing workers
+/// on a mutex: one shared (or one-per-worker) instance, incremented with
+/// relaxed atomics, then folded into a single [`CompressionStats`] via
+/// [`Self::merge_into`] once the pass finishes — the same fold-many-into-one
+/// shape [`StatsAggregator`] already uses to combine per-file stats.
+///
+/// Histograms aren't tracked here: bucketing them needs the same
+/// `BTreeMap::entry` dance `CompressionStats` already does, which doesn't
+/// have a lock-free equivalent worth the complexity for what's normally a
+/// handful of distinct keys. Merge histograms by having each worker keep
+/// its own `CompressionStats` for non-hot-path bookkeeping and combining
+/// them with [`StatsAggregator`] instead.
+#[derive(Default)]
+pub struct AtomicCompressionCounters {
+    pub total_blocks: std::sync::atomic::AtomicUsize,
+    pub compressed_blocks: std::sync::atomic::AtomicUsize,
+    pub table_hits: std::sync::atomic::AtomicUsize,
+    pub brute_force_matches: std::sync::atomic::AtomicUsize,
+    pub cpu_matches: std::sync::atomic::AtomicUsize,
+    pub gpu_matches: std::sync::atomic::AtomicUsize,
+    pub cpu_seeds_scanned: std::sync::atomic::AtomicUsize,
+    pub gpu_seeds_scanned: std::sync::atomic::AtomicUsize,
+    pub cpu_bits_saved: std::sync::atomic::AtomicU64,
+    pub gpu_bits_saved: std::sync::atomic::AtomicU64,
+}
+
+/// Fold a signed delta into an atomic `u64` the same way
+/// [`u64::saturating_add_signed`] folds it into a plain one, so bit-savings
+/// bookkeeping can't underflow-panic or wrap even under contention.
+fn atomic_saturating_add_signed(counter: &std::sync::atomic::AtomicU64, delta: i64) {
+    use std::sync::atomic::Ordering;
+    let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+        Some(v.saturating_add_signed(delta))
+    });
+}
+
+impl AtomicCompressionCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock-free counterpart to [`CompressionStats::tick_block`]'s counter
+    /// increment (CSV/JSONL row emission has no lock-free equivalent here;
+    /// keep those on a single thread's `CompressionStats`).
+    pub fn tick_block(&self) {
+        self.total_blocks
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Lock-free counterpart to
+    /// [`CompressionStats::log_match_with_origin`]'s counter updates.
+    /// `seed_len` is accepted for signature parity but unused: the seed
+    /// length histogram isn't tracked here (see the struct docs).
+    pub fn log_match_with_origin(
+        &self,
+        origin: &crate::types::CandidateOrigin,
+        blocks_compressed: usize,
+        _seed_len: Option<usize>,
+        bit_savings: i64,
+    ) {
+        use crate::types::{Engine, MatchMethod};
+        use std::sync::atomic::Ordering;
+
+        self.compressed_blocks
+            .fetch_add(blocks_compressed, Ordering::Relaxed);
+        match origin.method {
+            MatchMethod::TableHit => {
+                self.table_hits.fetch_add(1, Ordering::Relaxed);
+            }
+            MatchMethod::BruteForce => {
+                self.brute_force_matches.fetch_add(1, Ordering::Relaxed);
+            }
+            MatchMethod::Literal => {}
+        }
+        if origin.method != MatchMethod::Literal {
+            match origin.engine {
+                Engine::Cpu => {
+                    self.cpu_matches.fetch_add(1, Ordering::Relaxed);
+                    atomic_saturating_add_signed(&self.cpu_bits_saved, bit_savings);
+                }
+                Engine::Gpu => {
+                    self.gpu_matches.fetch_add(1, Ordering::Relaxed);
+                    atomic_saturating_add_signed(&self.gpu_bits_saved, bit_savings);
+                }
+            }
+        }
+    }
+
+    /// Lock-free counterpart to [`CompressionStats::log_seeds_scanned`].
+    pub fn log_seeds_scanned(&self, engine: crate::types::Engine, count: usize) {
+        use std::sync::atomic::Ordering;
+        match engine {
+            crate::types::Engine::Cpu => {
+                self.cpu_seeds_scanned.fetch_add(count, Ordering::Relaxed);
+            }
+            crate::types::Engine::Gpu => {
+                self.gpu_seeds_scanned.fetch_add(count, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Fold these counters into `stats`, e.g. once a multi-threaded pass
+    /// finishes and a single [`CompressionStats`] needs to own the
+    /// report/CSV/JSON output for the whole run.
+    pub fn merge_into(&self, stats: &mut CompressionStats) {
+        use std::sync::atomic::Ordering;
+        stats.total_blocks += self.total_blocks.load(Ordering::Relaxed);
+        stats.compressed_blocks += self.compressed_blocks.load(Ordering::Relaxed);
+        stats.table_hits += self.table_hits.load(Ordering::Relaxed);
+        stats.brute_force_matches += self.brute_force_matches.load(Ordering::Relaxed);
+        stats.cpu_matches += self.cpu_matches.load(Ordering::Relaxed);
+        stats.gpu_matches += self.gpu_matches.load(Ordering::Relaxed);
+        stats.cpu_seeds_scanned += self.cpu_seeds_scanned.load(Ordering::Relaxed);
+        stats.gpu_seeds_scanned += self.gpu_seeds_scanned.load(Ordering::Relaxed);
+        stats.cpu_bits_saved += self.cpu_bits_saved.load(Ordering::Relaxed);
+        stats.gpu_bits_saved += self.gpu_bits_saved.load(Ordering::Relaxed);
+    }
+}
+
+/// Stable-schema snapshot of [`CompressionStats`], serialized by
+/// [`CompressionStats::to_json`] and [`write_stats_json`]. Downstream
+/// dashboards should depend on this struct's fields, not `report()`'s
+/// `tracing`-formatted text.
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct CompressionStatsReport {
+    pub elapsed_secs: f32,
+    pub total_blocks: usize,
+    pub compressed_blocks: usize,
+    pub ratio: f32,
+    pub table_hits: usize,
+    pub brute_force_matches: usize,
+    pub cpu_matches: usize,
+    pub gpu_matches: usize,
+    pub cpu_seeds_scanned: usize,
+    pub gpu_seeds_scanned: usize,
+    pub cpu_bits_saved: u64,
+    pub gpu_bits_saved: u64,
+    pub arity_histogram: BTreeMap<usize, usize>,
+    pub seed_length_histogram: BTreeMap<usize, usize>,
+    pub literal_run_histogram: BTreeMap<usize, usize>,
+    pub peak_memory_bytes: u64,
+    pub average_memory_bytes: u64,
+}
+
+impl CompressionStatsReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e))
+    }
+}
+
+pub fn write_stats_json(stats: &mut CompressionStats, path: &str) -> Result<(), crate::TelomereError> {
+    std::fs::write(path, stats.to_json()).map_err(crate::TelomereError::from)
+}
+
+/// Merges per-file [`CompressionStats`] across a multi-file or `--recursive`
+/// batch run into one combined report, so compressing a directory of small
+/// files yields a single meaningful summary instead of each file's
+/// `--stats-csv` output clobbering the last.
+#[derive(Default)]
+pub struct StatsAggregator {
+    file_count: usize,
+    total_blocks: usize,
+    compressed_blocks: usize,
+    table_hits: usize,
+    brute_force_matches: usize,
+    cpu_matches: usize,
+    gpu_matches: usize,
+    cpu_seeds_scanned: usize,
+    gpu_seeds_scanned: usize,
+    cpu_bits_saved: u64,
+    gpu_bits_saved: u64,
+    arity_histogram: BTreeMap<usize, usize>,
+    seed_length_histogram: BTreeMap<usize, usize>,
+    literal_run_histogram: BTreeMap<usize, usize>,
+    peak_memory_bytes: u64,
+    average_memory_bytes_sum: u64,
+    elapsed_secs: f32,
+}
+
+impl StatsAggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one file's finished stats into the running aggregate.
+    pub fn add(&mut self, stats: &mut CompressionStats) {
+        let _ = stats.finish();
+        let report = stats.to_report();
+        self.file_count += 1;
+        self.total_blocks += report.total_blocks;
+        self.compressed_blocks += report.compressed_blocks;
+        self.table_hits += report.table_hits;
+        self.brute_force_matches += report.brute_force_matches;
+        self.cpu_matches += report.cpu_matches;
+        self.gpu_matches += report.gpu_matches;
+        self.cpu_seeds_scanned += report.cpu_seeds_scanned;
+        self.gpu_seeds_scanned += report.gpu_seeds_scanned;
+        self.cpu_bits_saved += report.cpu_bits_saved;
+        self.gpu_bits_saved += report.gpu_bits_saved;
+        merge_histogram_into(&mut self.arity_histogram, &report.arity_histogram);
+        merge_histogram_into(&mut self.seed_length_histogram, &report.seed_length_histogram);
+        merge_histogram_into(&mut self.literal_run_histogram, &report.literal_run_histogram);
+        self.peak_memory_bytes = self.peak_memory_bytes.max(report.peak_memory_bytes);
+        self.average_memory_bytes_sum += report.average_memory_bytes;
+        self.elapsed_secs += report.elapsed_secs;
+    }
+
+    /// Snapshot the merged counters as a [`CompressionStatsReport`], reusing
+    /// the single-file schema so downstream tooling doesn't need a second
+    /// shape to understand.
+    pub fn to_report(&self) -> CompressionStatsReport {
+        let ratio = self.compressed_blocks as f32 / self.total_blocks.max(1) as f32;
+        CompressionStatsReport {
+            elapsed_secs: self.elapsed_secs,
+            total_blocks: self.total_blocks,
+            compressed_blocks: self.compressed_blocks,
+            ratio,
+            table_hits: self.table_hits,
+            brute_force_matches: self.brute_force_matches,
+            cpu_matches: self.cpu_matches,
+            gpu_matches: self.gpu_matches,
+            cpu_seeds_scanned: self.cpu_seeds_scanned,
+            gpu_seeds_scanned: self.gpu_seeds_scanned,
+            cpu_bits_saved: self.cpu_bits_saved,
+            gpu_bits_saved: self.gpu_bits_saved,
+            arity_histogram: self.arity_histogram.clone(),
+            seed_length_histogram: self.seed_length_histogram.clone(),
+            literal_run_histogram: self.literal_run_histogram.clone(),
+            peak_memory_bytes: self.peak_memory_bytes,
+            average_memory_bytes: self.average_memory_bytes_sum / self.file_count.max(1) as u64,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        self.to_report().to_json()
+    }
+}
+
+fn merge_histogram_into(target: &mut BTreeMap<usize, usize>, source: &BTreeMap<usize, usize>) {
+    for (k, v) in source {
+        *target.entry(*k).or_insert(0) += v;
+    }
+}
+
+/// Write a [`StatsAggregator`]'s combined report as a one-row CSV, matching
+/// [`write_stats_csv`]'s column layout so existing tooling that reads a
+/// `--stats-csv` file doesn't need a second parser for batch runs.
+#[cfg(feature = "native-io")]
+pub fn write_aggregated_stats_csv(
+    agg: &StatsAggregator,
+    path: &str,
+) -> Result<(), crate::TelomereError> {
+    let report = agg.to_report();
+    let mut wtr = Writer::from_writer(File::create(path).map_err(crate::TelomereError::from)?);
+    wtr.write_record(&[
+        "time_s",
+        "total_blocks",
+        "compressed_blocks",
+        "ratio",
+        "arity_histogram",
+        "seed_length_histogram",
+        "literal_run_histogram",
+    ])
+    .map_err(|e| crate::TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    wtr.write_record(&[
+        format!("{:.2}", report.elapsed_secs),
+        report.total_blocks.to_string(),
+        report.compressed_blocks.to_string(),
+        format!("{:.2}", report.ratio * 100.0),
+        format_histogram(&report.arity_histogram),
+        format_histogram(&report.seed_length_histogram),
+        format_histogram(&report.literal_run_histogram),
+    ])
+    .map_err(|e| crate::TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+    wtr.flush().map_err(crate::TelomereError::from)?;
+    Ok(())
 }
 
-pub fn write_stats_csv(stats: &CompressionStats, path: &str) -> Result<(), crate::TelomereError> {
+#[cfg(feature = "native-io")]
+pub fn write_stats_csv(stats: &mut CompressionStats, path: &str) -> Result<(), crate::TelomereError> {
+    stats.flush_literal_run();
     let elapsed = stats.start_time.elapsed().as_secs_f32();
     let ratio = stats.compressed_blocks as f32 / stats.total_blocks.max(1) as f32;
     let mut wtr = Writer::from_writer(File::create(path).map_err(crate::TelomereError::from)?);
@@ -212,8 +885,9 @@ pub fn write_stats_csv(stats: &CompressionStats, path: &str) -> Result<(), crate
         "total_blocks",
         "compressed_blocks",
         "ratio",
-        "greedy",
-        "fallback",
+        "arity_histogram",
+        "seed_length_histogram",
+        "literal_run_histogram",
     ])
     .map_err(|e| crate::TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
     wtr.write_record(&[
@@ -221,10 +895,383 @@ pub fn write_stats_csv(stats: &CompressionStats, path: &str) -> Result<(), crate
         stats.total_blocks.to_string(),
         stats.compressed_blocks.to_string(),
         format!("{:.2}", ratio * 100.0),
-        stats.greedy_matches.to_string(),
-        stats.fallback_matches.to_string(),
+        format_histogram(&stats.arity_histogram),
+        format_histogram(&stats.seed_length_histogram),
+        format_histogram(&stats.literal_run_histogram),
     ])
     .map_err(|e| crate::TelomereError::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
     wtr.flush().map_err(crate::TelomereError::from)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CandidateOrigin, Engine, MatchMethod};
+
+    #[test]
+    fn stats_aggregator_merges_histograms_and_counters_across_files() {
+        let mut a = CompressionStats::new();
+        a.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::TableHit,
+            },
+            1,
+            Some(1),
+            4,
+        );
+        let mut b = CompressionStats::new();
+        b.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Gpu,
+                method: MatchMethod::BruteForce,
+            },
+            2,
+            Some(1),
+            6,
+        );
+
+        let mut agg = StatsAggregator::new();
+        agg.add(&mut a);
+        agg.add(&mut b);
+        let report = agg.to_report();
+
+        assert_eq!(report.table_hits, 1);
+        assert_eq!(report.brute_force_matches, 1);
+        assert_eq!(report.cpu_matches, 1);
+        assert_eq!(report.gpu_matches, 1);
+        assert_eq!(report.cpu_bits_saved, 4);
+        assert_eq!(report.gpu_bits_saved, 6);
+        assert_eq!(report.compressed_blocks, 3);
+        assert_eq!(report.seed_length_histogram.get(&1), Some(&2));
+    }
+
+    #[test]
+    fn pass_stats_and_run_summary_compute_throughput() {
+        let pass = PassStats::new(1, 1000, 800, Duration::from_millis(500));
+        assert!((pass.throughput_bytes_per_sec - 2000.0).abs() < 1e-6);
+
+        let summary = RunSummary::new(1000, vec![pass]);
+        assert!((summary.throughput_bytes_per_sec - 2000.0).abs() < 1e-6);
+        assert_eq!(summary.run_fingerprint, None);
+    }
+
+    #[test]
+    fn log_match_with_origin_attributes_by_method_and_engine() {
+        let mut stats = CompressionStats::new();
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::TableHit,
+            },
+            1,
+            Some(1),
+            3,
+        );
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Gpu,
+                method: MatchMethod::BruteForce,
+            },
+            2,
+            Some(2),
+            5,
+        );
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 2,
+                engine: Engine::Cpu,
+                method: MatchMethod::Literal,
+            },
+            1,
+            None,
+            0,
+        );
+
+        assert_eq!(stats.table_hits, 1);
+        assert_eq!(stats.brute_force_matches, 1);
+        assert_eq!(stats.cpu_matches, 1);
+        assert_eq!(stats.gpu_matches, 1);
+        assert_eq!(stats.cpu_bits_saved, 3);
+        assert_eq!(stats.gpu_bits_saved, 5);
+        assert_eq!(stats.compressed_blocks, 4);
+    }
+
+    #[test]
+    fn histograms_track_arity_seed_length_and_literal_runs() {
+        let mut stats = CompressionStats::new();
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::Literal,
+            },
+            1,
+            None,
+            0,
+        );
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::Literal,
+            },
+            1,
+            None,
+            0,
+        );
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::BruteForce,
+            },
+            2,
+            Some(2),
+            4,
+        );
+        stats.report();
+
+        assert_eq!(stats.literal_run_histogram.get(&2), Some(&1));
+        assert_eq!(stats.arity_histogram.get(&2), Some(&1));
+        assert_eq!(stats.seed_length_histogram.get(&2), Some(&1));
+    }
+
+    #[test]
+    fn to_json_round_trips_through_serde() {
+        let mut stats = CompressionStats::new();
+        stats.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::BruteForce,
+            },
+            2,
+            Some(2),
+            4,
+        );
+        let json = stats.to_json();
+        let report: CompressionStatsReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.brute_force_matches, 1);
+        assert_eq!(report.arity_histogram.get(&2), Some(&1));
+    }
+
+    #[test]
+    #[cfg(feature = "native-io")]
+    fn tick_block_buffers_rows_until_flush_interval_or_finish() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stats.csv");
+        let mut stats = CompressionStats::with_csv(path.to_str().unwrap())
+            .unwrap()
+            .with_csv_flush_interval(10);
+
+        for _ in 0..3 {
+            stats.tick_block();
+        }
+        // Below the flush interval: nothing (not even the header) has been
+        // flushed to disk yet.
+        let before_finish = std::fs::read_to_string(&path).unwrap();
+        assert!(before_finish.is_empty());
+
+        stats.finish().unwrap();
+        let after_finish = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(after_finish.lines().count(), 4); // header + 3 rows
+    }
+
+    /// A `Write` sink backed by a shared buffer, so a test can inspect what
+    /// was written after handing ownership of the writer to
+    /// [`CompressionStats::with_csv_writer`]/[`with_jsonl_writer`].
+    #[derive(Clone, Default)]
+    struct SharedBuf(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "native-io")]
+    fn with_csv_writer_captures_rows_in_memory() {
+        let buf = SharedBuf::default();
+        let mut stats = CompressionStats::with_csv_writer(buf.clone())
+            .unwrap()
+            .with_csv_flush_interval(1);
+        stats.tick_block();
+        stats.finish().unwrap();
+
+        let written = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        assert!(written.starts_with("seconds,total_blocks"));
+        assert_eq!(written.lines().count(), 2); // header + 1 row
+    }
+
+    #[test]
+    fn with_jsonl_writer_emits_one_json_object_per_tick() {
+        let buf = SharedBuf::default();
+        let mut stats = CompressionStats::new()
+            .with_jsonl_writer(buf.clone())
+            .with_csv_flush_interval(1);
+        stats.tick_block();
+        stats.tick_block();
+        stats.finish().unwrap();
+
+        let written = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = written.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let row: StatsRow = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(row.total_blocks, 2);
+    }
+
+    #[test]
+    fn log_seeds_scanned_accumulates_per_engine() {
+        let mut stats = CompressionStats::new();
+        stats.log_seeds_scanned(Engine::Cpu, 256);
+        stats.log_seeds_scanned(Engine::Cpu, 4);
+        stats.log_seeds_scanned(Engine::Gpu, 256);
+
+        assert_eq!(stats.cpu_seeds_scanned, 260);
+        assert_eq!(stats.gpu_seeds_scanned, 256);
+
+        let report = stats.to_report();
+        assert_eq!(report.cpu_seeds_scanned, 260);
+        assert_eq!(report.gpu_seeds_scanned, 256);
+    }
+
+    #[test]
+    #[cfg(feature = "phase-stats")]
+    fn log_phase_timings_accumulates_across_passes() {
+        let mut stats = CompressionStats::new();
+        stats.log_phase_timings(&crate::profile::PhaseTimings {
+            pass: 1,
+            block_split_ms: 1,
+            seed_search_ms: 10,
+            pruning_ms: 2,
+            emit_ms: 3,
+            hashing_ms: 4,
+        });
+        stats.log_phase_timings(&crate::profile::PhaseTimings {
+            pass: 2,
+            block_split_ms: 1,
+            seed_search_ms: 5,
+            pruning_ms: 1,
+            emit_ms: 1,
+            hashing_ms: 1,
+        });
+
+        assert_eq!(stats.phase_timings.block_split_ms, 2);
+        assert_eq!(stats.phase_timings.seed_search_ms, 15);
+        assert_eq!(stats.phase_timings.pruning_ms, 3);
+        assert_eq!(stats.phase_timings.emit_ms, 4);
+        assert_eq!(stats.phase_timings.hashing_ms, 5);
+    }
+
+    #[test]
+    fn sample_memory_tracks_peak_and_average() {
+        let mut stats = CompressionStats::new();
+        assert_eq!(stats.average_memory_bytes(), 0);
+
+        stats.sample_memory();
+        stats.sample_memory();
+        stats.sample_memory();
+
+        assert!(stats.peak_memory_bytes > 0);
+        assert!(stats.average_memory_bytes() > 0);
+        assert!(stats.average_memory_bytes() <= stats.peak_memory_bytes);
+
+        let report = stats.to_report();
+        assert_eq!(report.peak_memory_bytes, stats.peak_memory_bytes);
+        assert_eq!(report.average_memory_bytes, stats.average_memory_bytes());
+    }
+
+    #[test]
+    fn atomic_counters_merge_into_matches_sequential_stats() {
+        let atomic = AtomicCompressionCounters::new();
+        atomic.tick_block();
+        atomic.tick_block();
+        atomic.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Cpu,
+                method: MatchMethod::TableHit,
+            },
+            1,
+            Some(2),
+            14,
+        );
+        atomic.log_match_with_origin(
+            &CandidateOrigin {
+                pass: 1,
+                engine: Engine::Gpu,
+                method: MatchMethod::BruteForce,
+            },
+            1,
+            Some(3),
+            20,
+        );
+        atomic.log_seeds_scanned(Engine::Cpu, 256);
+        atomic.log_seeds_scanned(Engine::Gpu, 256);
+
+        let mut stats = CompressionStats::new();
+        atomic.merge_into(&mut stats);
+
+        assert_eq!(stats.total_blocks, 2);
+        assert_eq!(stats.compressed_blocks, 2);
+        assert_eq!(stats.table_hits, 1);
+        assert_eq!(stats.brute_force_matches, 1);
+        assert_eq!(stats.cpu_matches, 1);
+        assert_eq!(stats.gpu_matches, 1);
+        assert_eq!(stats.cpu_bits_saved, 14);
+        assert_eq!(stats.gpu_bits_saved, 20);
+        assert_eq!(stats.cpu_seeds_scanned, 256);
+        assert_eq!(stats.gpu_seeds_scanned, 256);
+    }
+
+    #[test]
+    fn atomic_counters_survive_concurrent_updates_from_many_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let counters = Arc::new(AtomicCompressionCounters::new());
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let counters = Arc::clone(&counters);
+            handles.push(thread::spawn(move || {
+                for _ in 0..100 {
+                    counters.tick_block();
+                    counters.log_match_with_origin(
+                        &CandidateOrigin {
+                            pass: 1,
+                            engine: Engine::Cpu,
+                            method: MatchMethod::TableHit,
+                        },
+                        1,
+                        Some(1),
+                        5,
+                    );
+                    counters.log_seeds_scanned(Engine::Cpu, 1);
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut stats = CompressionStats::new();
+        counters.merge_into(&mut stats);
+
+        assert_eq!(stats.total_blocks, 800);
+        assert_eq!(stats.compressed_blocks, 800);
+        assert_eq!(stats.table_hits, 800);
+        assert_eq!(stats.cpu_matches, 800);
+        assert_eq!(stats.cpu_bits_saved, 4000);
+        assert_eq!(stats.cpu_seeds_scanned, 800);
+    }
+}
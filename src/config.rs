@@ -5,6 +5,42 @@ use crate::TelomereError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// (De)serializes [`Config::seed_expansions`] as a hex-keyed JSON/TOML object
+/// (`{"2a": "deadbeef", ...}`) instead of raw integer keys and byte arrays,
+/// so hand-written config files and JSON APIs stay readable.
+mod seed_expansions_hex {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+
+    pub fn serialize<S>(map: &HashMap<usize, Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let hex_map: BTreeMap<String, String> = map
+            .iter()
+            .map(|(index, bytes)| (format!("{index:x}"), hex::encode(bytes)))
+            .collect();
+        hex_map.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<usize, Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex_map = BTreeMap::<String, String>::deserialize(deserializer)?;
+        hex_map
+            .into_iter()
+            .map(|(index, bytes)| {
+                let index = usize::from_str_radix(&index, 16)
+                    .map_err(|e| serde::de::Error::custom(format!("bad seed_expansions key: {e}")))?;
+                let bytes = hex::decode(&bytes)
+                    .map_err(|e| serde::de::Error::custom(format!("bad seed_expansions value: {e}")))?;
+                Ok((index, bytes))
+            })
+            .collect()
+    }
+}
+
 /// Enum representing the chosen hasher for seed expansion.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -32,7 +68,7 @@ impl HasherKind {
 }
 
 /// Runtime configuration parameters for the compressor and decompressor.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     /// Fixed block size in bytes.
     pub block_size: usize,
@@ -47,6 +83,7 @@ pub struct Config {
     /// The hasher to use for seed expansion.
     pub hasher: HasherKind,
     /// Pre-expanded seed bitstreams indexed by seed index.
+    #[serde(with = "seed_expansions_hex", default)]
     pub seed_expansions: HashMap<usize, Vec<u8>>,
     /// Whether to enable superposition (keeping multiple candidates per block).
     pub enable_superposition: bool,
@@ -70,34 +107,37 @@ impl Default for Config {
 }
 
 impl Config {
-    /// Validate runtime settings against the active `.tlmr` v1 format limits.
-    pub fn validate(&self) -> Result<(), TelomereError> {
+    /// Collect every way `self` violates the active `.tlmr` v1 format
+    /// limits, instead of stopping at the first. Empty means valid.
+    pub fn violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
         if !(1..=MAX_BLOCK_SIZE).contains(&self.block_size) {
-            return Err(TelomereError::Config(format!(
-                "block_size must be in 1..={MAX_BLOCK_SIZE}"
-            )));
+            violations.push(format!("block_size must be in 1..={MAX_BLOCK_SIZE}"));
         }
         if !(1..=MAX_SEED_LEN).contains(&self.max_seed_len) {
-            return Err(TelomereError::Config(format!(
-                "max_seed_len must be in 1..={MAX_SEED_LEN}"
-            )));
+            violations.push(format!("max_seed_len must be in 1..={MAX_SEED_LEN}"));
         }
         if !(1..=MAX_ARITY).contains(&self.max_arity) {
-            return Err(TelomereError::Config(format!(
-                "max_arity must be in 1..={MAX_ARITY}"
-            )));
+            violations.push(format!("max_arity must be in 1..={MAX_ARITY}"));
         }
         if !(1..=MAX_HASH_BITS).contains(&self.hash_bits) {
-            return Err(TelomereError::Config(format!(
-                "hash_bits must be in 1..={MAX_HASH_BITS}"
-            )));
+            violations.push(format!("hash_bits must be in 1..={MAX_HASH_BITS}"));
         }
         if self.memory_limit == 0 {
-            return Err(TelomereError::Config(
-                "memory_limit must be greater than zero".into(),
-            ));
+            violations.push("memory_limit must be greater than zero".into());
+        }
+        violations
+    }
+
+    /// Validate runtime settings against the active `.tlmr` v1 format
+    /// limits, reporting every violation (not just the first) in one error.
+    pub fn validate(&self) -> Result<(), TelomereError> {
+        let violations = self.violations();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(TelomereError::Config(violations.join("; ")))
         }
-        Ok(())
     }
 
     /// Returns a boxed seed expander based on the configuration.
@@ -105,3 +145,41 @@ impl Config {
         self.hasher.get_expander()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_reports_every_violation_at_once() {
+        let config = Config {
+            block_size: 0,
+            max_seed_len: 0,
+            ..Config::default()
+        };
+        let err = config.validate().unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("block_size"));
+        assert!(msg.contains("max_seed_len"));
+    }
+
+    #[test]
+    fn validate_accepts_default_config() {
+        assert!(Config::default().validate().is_ok());
+    }
+
+    #[test]
+    fn seed_expansions_round_trip_through_hex_json() {
+        let mut config = Config::default();
+        config.seed_expansions.insert(42, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains("\"2a\":\"deadbeef\""));
+
+        let round_tripped: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            round_tripped.seed_expansions.get(&42),
+            Some(&vec![0xDE, 0xAD, 0xBE, 0xEF])
+        );
+    }
+}
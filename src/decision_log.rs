@@ -0,0 +1,69 @@
+//! Per-block compressor decision log.
+//!
+//! Optional JSONL log of every emitted block range's chosen candidate, the
+//! alternatives superposition pruning left behind for the same start index,
+//! and their bit costs, so a tuning session can ask "why did it pick this"
+//! after the fact instead of scrolling `tracing::debug!` output.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::types::Candidate;
+
+/// An alternative candidate superposition pruning kept for a block start
+/// index, which the bundler did not ultimately select.
+#[derive(Debug, Clone, Serialize)]
+pub struct AlternativeRecord {
+    pub seed_index: u64,
+    pub arity: u8,
+    pub bit_len: usize,
+}
+
+impl From<&Candidate> for AlternativeRecord {
+    fn from(c: &Candidate) -> Self {
+        Self {
+            seed_index: c.seed_index,
+            arity: c.arity,
+            bit_len: c.bit_len,
+        }
+    }
+}
+
+/// One JSONL row: the candidate chosen for a block range, plus whatever
+/// alternatives survived pruning at the same starting block.
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionRecord {
+    pub pass: u32,
+    pub block_start: usize,
+    pub arity: u8,
+    pub is_literal: bool,
+    pub bit_cost: usize,
+    pub alternatives: Vec<AlternativeRecord>,
+}
+
+/// Appends [`DecisionRecord`]s as JSON Lines, one per emitted block range.
+/// Kept open for the duration of a compression run, mirroring
+/// [`crate::compress_stats::CompressionStats`]'s `--stats-csv` writer.
+pub struct DecisionLogger {
+    writer: BufWriter<File>,
+}
+
+impl DecisionLogger {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+        })
+    }
+
+    pub fn log(&mut self, record: &DecisionRecord) -> io::Result<()> {
+        let line = serde_json::to_string(record)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        writeln!(self.writer, "{line}")
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
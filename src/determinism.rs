@@ -0,0 +1,110 @@
+//! Consensus determinism auditing.
+//!
+//! Candidate selection must not depend on incidental iteration order (hash
+//! map traversal, thread scheduling, etc.) so that consensus-critical users
+//! can verify platform-independent behavior. This module provides a stable
+//! fingerprint over the final candidate selections plus a test hook that
+//! re-runs a build closure with reordered inputs to assert the fingerprint
+//! does not move.
+
+use crate::superposition::SuperpositionManager;
+
+/// Compute a stable BLAKE3 fingerprint of all canonical and superposed
+/// candidate selections in `mgr`. Entries are sorted before hashing so the
+/// result is independent of internal `HashMap` iteration order.
+pub fn decision_fingerprint(mgr: &SuperpositionManager) -> [u8; 32] {
+    let mut canonical = mgr.all_canonical();
+    canonical.sort_by_key(|(k, _)| *k);
+
+    let mut superposed = mgr.all_superposed();
+    superposed.sort_by_key(|(idx, _)| *idx);
+
+    let mut hasher = blake3::Hasher::new();
+    for ((start, blocks), cand) in canonical {
+        hasher.update(&(start as u64).to_le_bytes());
+        hasher.update(&(blocks as u64).to_le_bytes());
+        hasher.update(&(cand.bit_len as u64).to_le_bytes());
+        hasher.update(&cand.seed_index.to_le_bytes());
+        hasher.update(&[cand.arity]);
+    }
+    for (idx, mut list) in superposed {
+        list.sort_by_key(|(label, _)| *label);
+        hasher.update(&(idx as u64).to_le_bytes());
+        for (label, cand) in list {
+            hasher.update(&[label]);
+            hasher.update(&(cand.bit_len as u64).to_le_bytes());
+            hasher.update(&cand.seed_index.to_le_bytes());
+            hasher.update(&[cand.arity]);
+        }
+    }
+    hasher.finalize().into()
+}
+
+/// Test hook: run `build` once with `items` in their given order and once
+/// with the order reversed, then compare [`decision_fingerprint`]s. Returns
+/// `true` if both runs agree, catching consensus-breaking dependence on
+/// incidental iteration order.
+pub fn assert_order_independent<T: Clone>(
+    items: &[T],
+    build: impl Fn(&[T]) -> SuperpositionManager,
+) -> bool {
+    let forward = build(items);
+    let mut shuffled = items.to_vec();
+    shuffled.reverse();
+    let backward = build(&shuffled);
+    decision_fingerprint(&forward) == decision_fingerprint(&backward)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Candidate, CandidateOrigin};
+
+    fn candidate(bit_len: usize, seed_index: u64) -> Candidate {
+        Candidate {
+            seed_index,
+            arity: 1,
+            bit_len,
+            from_bundle: false,
+            origin: CandidateOrigin::default(),
+        }
+    }
+
+    #[test]
+    fn fingerprint_is_stable_across_insertion_order() {
+        let mut a = SuperpositionManager::new(4);
+        a.insert_superposed(0, candidate(10, 1)).unwrap();
+        a.insert_superposed(1, candidate(12, 2)).unwrap();
+
+        let mut b = SuperpositionManager::new(4);
+        b.insert_superposed(1, candidate(12, 2)).unwrap();
+        b.insert_superposed(0, candidate(10, 1)).unwrap();
+
+        assert_eq!(decision_fingerprint(&a), decision_fingerprint(&b));
+    }
+
+    #[test]
+    fn fingerprint_changes_with_selection() {
+        let mut a = SuperpositionManager::new(2);
+        a.insert_superposed(0, candidate(10, 1)).unwrap();
+
+        let mut b = SuperpositionManager::new(2);
+        b.insert_superposed(0, candidate(11, 1)).unwrap();
+
+        assert_ne!(decision_fingerprint(&a), decision_fingerprint(&b));
+    }
+
+    #[test]
+    fn assert_order_independent_detects_agreement() {
+        let items = vec![(0usize, 10usize, 1u64), (1, 12, 2)];
+        let build = |items: &[(usize, usize, u64)]| {
+            let mut mgr = SuperpositionManager::new(2);
+            for &(idx, bit_len, seed_index) in items {
+                mgr.insert_superposed(idx, candidate(bit_len, seed_index))
+                    .unwrap();
+            }
+            mgr
+        };
+        assert!(assert_order_independent(&items, build));
+    }
+}
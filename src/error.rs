@@ -1,11 +1,21 @@
 use thiserror::Error;
 
+/// `#[non_exhaustive]`: new variants (and new fields on existing struct
+/// variants) can be added without a breaking change. Most variants are
+/// still bare `String`s today — converting them to typed, matchable
+/// variants like [`TelomereError::HashMismatch`] is ongoing, one call site
+/// at a time, rather than a single rewrite.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum TelomereError {
     /// Malformed or invalid header/EVQL data.
     #[error("header error: {0}")]
     Header(String),
 
+    /// Decoded output's truncated hash doesn't match the header's.
+    #[error("output hash mismatch: expected {expected:#x}, got {actual:#x}")]
+    HashMismatch { expected: u64, actual: u64 },
+
     /// Seed search related failure.
     #[error("seed search error: {0}")]
     SeedSearch(String),
@@ -38,6 +48,11 @@ pub enum TelomereError {
     #[error("internal error: {0}")]
     Internal(String),
 
+    /// A compress loop stopped early because SIGINT was requested. Raised at
+    /// the next block boundary, never mid-write.
+    #[error("interrupted")]
+    Interrupted,
+
     /// (Legacy, avoid in new code) – fallback for decoding errors.
     #[error("decode error: {0}")]
     Decode(String),
@@ -0,0 +1,81 @@
+//! Deterministic digest over every emit decision in a compression run, for
+//! reproducibility audits.
+//!
+//! Unlike [`crate::determinism::decision_fingerprint`], which hashes a
+//! single pass's [`crate::superposition::SuperpositionManager`] state to
+//! test that candidate selection doesn't depend on iteration order, this
+//! accumulates across every pass of a real run, in actual emit order, so
+//! two runs that claim the same settings but differ here reveal
+//! nondeterminism immediately instead of requiring a full output diff.
+
+use blake3::Hasher;
+
+/// Running BLAKE3 digest over `(block_index, seed_index, bit_cost)` for
+/// every block range the compressor finalizes, across every pass of a run.
+#[derive(Default)]
+pub struct RunFingerprint {
+    hasher: Hasher,
+}
+
+impl RunFingerprint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one finalized emit decision: the block index a span starts
+    /// at, the seed index chosen for it (`u64::MAX` for a literal), and its
+    /// encoded bit cost.
+    pub fn log_emit_decision(&mut self, block_index: usize, seed_index: u64, bit_cost: usize) {
+        self.hasher.update(&(block_index as u64).to_le_bytes());
+        self.hasher.update(&seed_index.to_le_bytes());
+        self.hasher.update(&(bit_cost as u64).to_le_bytes());
+    }
+
+    /// Finalize the digest computed so far, as lowercase hex suitable for a
+    /// summary line or JSON report.
+    pub fn finalize_hex(&self) -> String {
+        hex::encode(self.hasher.finalize().as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_decisions_in_the_same_order_produce_the_same_fingerprint() {
+        let mut a = RunFingerprint::new();
+        a.log_emit_decision(0, 5, 40);
+        a.log_emit_decision(1, 9, 20);
+
+        let mut b = RunFingerprint::new();
+        b.log_emit_decision(0, 5, 40);
+        b.log_emit_decision(1, 9, 20);
+
+        assert_eq!(a.finalize_hex(), b.finalize_hex());
+    }
+
+    #[test]
+    fn a_different_bit_cost_changes_the_fingerprint() {
+        let mut a = RunFingerprint::new();
+        a.log_emit_decision(0, 5, 40);
+
+        let mut b = RunFingerprint::new();
+        b.log_emit_decision(0, 5, 41);
+
+        assert_ne!(a.finalize_hex(), b.finalize_hex());
+    }
+
+    #[test]
+    fn decision_order_changes_the_fingerprint() {
+        let mut a = RunFingerprint::new();
+        a.log_emit_decision(0, 5, 40);
+        a.log_emit_decision(1, 9, 20);
+
+        let mut b = RunFingerprint::new();
+        b.log_emit_decision(1, 9, 20);
+        b.log_emit_decision(0, 5, 40);
+
+        assert_ne!(a.finalize_hex(), b.finalize_hex());
+    }
+}
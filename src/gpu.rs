@@ -14,3 +14,13 @@ mod gpu_backend;
 mod gpu_backend;
 
 pub use gpu_backend::GpuSeedMatcher;
+
+/// Parameters for driving [`GpuSeedMatcher`] from the compress loop:
+/// `--gpu-device` is recorded for logging and future real-backend selection
+/// (neither backend currently discriminates by device), `--gpu-tile-blocks`
+/// sets how many blocks are loaded into the simulated tile at once.
+#[derive(Debug, Clone, Copy)]
+pub struct GpuTileConfig {
+    pub device: u32,
+    pub tile_blocks: usize,
+}
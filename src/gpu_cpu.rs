@@ -2,38 +2,31 @@ use crate::block::{BlockId, BlockStore};
 use crate::hasher::SeedExpander;
 use crate::{GpuMatchRecord, TelomereError};
 
-struct SimulatedBlock {
-    data: Vec<u8>,
-    global_index: usize,
-    bit_length: usize,
-}
-
 /// Simple CPU-based simulation of the GPU seed matcher.
+///
+/// The tile holds only [`BlockId`] handles into `store`'s data arena, not a
+/// second copy of each block's bytes — `store` already owns the one true copy
+/// (see [`BlockStore`]'s arena doc comment), so loading a tile no longer
+/// doubles memory for large inputs.
 #[derive(Default)]
-pub struct GpuSeedMatcher {
-    tile: Vec<SimulatedBlock>,
+pub struct GpuSeedMatcher<'a> {
+    store: Option<&'a BlockStore>,
+    tile: Vec<BlockId>,
 }
 
-impl GpuSeedMatcher {
+impl<'a> GpuSeedMatcher<'a> {
     /// Create a new matcher with an empty tile.
     pub fn new() -> Self {
-        Self { tile: Vec::new() }
+        Self {
+            store: None,
+            tile: Vec::new(),
+        }
     }
 
     /// Load a block tile into the simulated GPU memory.
-    pub fn load_tile(&mut self, store: &BlockStore, blocks: &[BlockId]) {
-        self.tile = blocks
-            .iter()
-            .map(|&id| {
-                let b_ref = store.get_block(id);
-                let data = store.get_data(id).to_vec();
-                SimulatedBlock {
-                    data,
-                    global_index: b_ref.global_index as usize,
-                    bit_length: b_ref.bit_len as usize,
-                }
-            })
-            .collect();
+    pub fn load_tile(&mut self, store: &'a BlockStore, blocks: &[BlockId]) {
+        self.store = Some(store);
+        self.tile = blocks.to_vec();
     }
 
     /// Hash seeds on the fly and return match records.
@@ -43,18 +36,24 @@ impl GpuSeedMatcher {
         end_seed: usize,
         expander: &dyn SeedExpander,
     ) -> Result<Vec<GpuMatchRecord>, TelomereError> {
+        let Some(store) = self.store else {
+            return Ok(Vec::new());
+        };
         let mut out = Vec::new();
         for seed in start_seed..end_seed {
             let seed_byte = seed as u8;
-            for block in &self.tile {
+            for &id in &self.tile {
+                let b_ref = store.get_block(id);
+                let data = store.get_data(id);
+                let bit_length = b_ref.bit_len as usize;
                 // Use expander to check for match.
                 // Assuming seed is just 1 byte as per original logic.
-                if expander.prefix_matches(&[seed_byte], &block.data, block.bit_length) {
+                if expander.prefix_matches(&[seed_byte], data, bit_length) {
                     out.push(GpuMatchRecord {
                         seed_index: seed,
                         bundle_length: 1,
-                        block_indices: vec![block.global_index],
-                        original_bits: block.bit_length,
+                        block_indices: vec![b_ref.global_index as usize],
+                        original_bits: bit_length,
                     });
                 }
             }
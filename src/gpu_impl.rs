@@ -9,34 +9,27 @@ use crate::block::{BlockId, BlockStore};
 use crate::hasher::SeedExpander;
 use crate::{GpuMatchRecord, TelomereError};
 
-struct ResearchBlock {
-    data: Vec<u8>,
-    global_index: usize,
-    bit_length: usize,
-}
-
+/// The tile holds only [`BlockId`] handles into `store`'s data arena, not a
+/// second copy of each block's bytes — `store` already owns the one true copy
+/// (see [`BlockStore`]'s arena doc comment), so loading a tile no longer
+/// doubles memory for large inputs.
 #[derive(Default)]
-pub struct GpuSeedMatcher {
-    tile: Vec<ResearchBlock>,
+pub struct GpuSeedMatcher<'a> {
+    store: Option<&'a BlockStore>,
+    tile: Vec<BlockId>,
 }
 
-impl GpuSeedMatcher {
+impl<'a> GpuSeedMatcher<'a> {
     pub fn new() -> Self {
-        Self { tile: Vec::new() }
+        Self {
+            store: None,
+            tile: Vec::new(),
+        }
     }
 
-    pub fn load_tile(&mut self, store: &BlockStore, blocks: &[BlockId]) {
-        self.tile = blocks
-            .iter()
-            .map(|&id| {
-                let b_ref = store.get_block(id);
-                ResearchBlock {
-                    data: store.get_data(id).to_vec(),
-                    global_index: b_ref.global_index as usize,
-                    bit_length: b_ref.bit_len as usize,
-                }
-            })
-            .collect();
+    pub fn load_tile(&mut self, store: &'a BlockStore, blocks: &[BlockId]) {
+        self.store = Some(store);
+        self.tile = blocks.to_vec();
     }
 
     pub fn seed_match(
@@ -45,16 +38,22 @@ impl GpuSeedMatcher {
         end_seed: usize,
         expander: &dyn SeedExpander,
     ) -> Result<Vec<GpuMatchRecord>, TelomereError> {
+        let Some(store) = self.store else {
+            return Ok(Vec::new());
+        };
         let mut out = Vec::new();
         for seed in start_seed..end_seed {
             let seed_byte = seed as u8;
-            for block in &self.tile {
-                if expander.prefix_matches(&[seed_byte], &block.data, block.bit_length) {
+            for &id in &self.tile {
+                let b_ref = store.get_block(id);
+                let data = store.get_data(id);
+                let bit_length = b_ref.bit_len as usize;
+                if expander.prefix_matches(&[seed_byte], data, bit_length) {
                     out.push(GpuMatchRecord {
                         seed_index: seed,
                         bundle_length: 1,
-                        block_indices: vec![block.global_index],
-                        original_bits: block.bit_length,
+                        block_indices: vec![b_ref.global_index as usize],
+                        original_bits: bit_length,
                     });
                 }
             }
@@ -23,7 +23,13 @@ pub fn lookup_seed(bytes: &[u8], prefix: [u8; 3]) -> Option<Vec<u8>> {
     // SAFETY: Entry is `Pod` and the length check above ensures the slice
     // length is a multiple of the item size.
     let entries: &[Entry] = bytemuck::cast_slice(bytes);
+    lookup_in_entries(entries, prefix)
+}
 
+/// Binary-searches a (sub-)slice of entries, already known to be sorted by
+/// prefix, for `prefix`. Shared by [`lookup_seed`] and, behind the
+/// `native-io` feature, [`MmapHashTable::lookup`].
+fn lookup_in_entries(entries: &[Entry], prefix: [u8; 3]) -> Option<Vec<u8>> {
     let mut left = 0usize;
     let mut right = entries.len();
 
@@ -62,6 +68,69 @@ pub fn lookup_seed(bytes: &[u8], prefix: [u8; 3]) -> Option<Vec<u8>> {
     None
 }
 
+/// Memory-mapped reader for the on-disk seed hash table (the ~135 MB table
+/// `telomere table build` can produce), so a long-running process doesn't
+/// have to read the whole file into a `Vec<u8>` just to call [`lookup_seed`]
+/// on it. A 256-bucket index over each entry's leading prefix byte, built
+/// once at [`MmapHashTable::open`] time, narrows [`MmapHashTable::lookup`]'s
+/// binary search to one page-local range instead of the whole file.
+///
+/// Requires the `native-io` feature, since `memmap2`'s mapping call isn't
+/// available on targets like `wasm32-unknown-unknown` — see
+/// [`crate::seed_expansion_index::MmapSeedExpansionIndex`] for the same
+/// tradeoff on the seed-expansion index.
+#[cfg(feature = "native-io")]
+pub struct MmapHashTable {
+    mmap: memmap2::Mmap,
+    /// `bucket_offsets[b]..bucket_offsets[b + 1]` is the range of entries
+    /// whose `prefix[0] == b`; `bucket_offsets[256]` is the entry count.
+    bucket_offsets: [usize; 257],
+}
+
+#[cfg(feature = "native-io")]
+impl MmapHashTable {
+    pub fn open(path: &std::path::Path) -> Result<Self, crate::TelomereError> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: the file isn't expected to be concurrently modified by
+        // another process while mapped; same assumption as
+        // `MmapSeedExpansionIndex::open_dir`.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        let entry_size = std::mem::size_of::<Entry>();
+        if mmap.len() % entry_size != 0 {
+            return Err(crate::TelomereError::Header(
+                "corrupt hash table file".into(),
+            ));
+        }
+
+        let mut bucket_offsets = [0usize; 257];
+        {
+            let entries: &[Entry] = bytemuck::cast_slice(&mmap);
+            for (bucket, offset) in bucket_offsets.iter_mut().enumerate().take(256) {
+                *offset = entries.partition_point(|e| (e.prefix[0] as usize) < bucket);
+            }
+            bucket_offsets[256] = entries.len();
+        }
+
+        Ok(Self {
+            mmap,
+            bucket_offsets,
+        })
+    }
+
+    fn entries(&self) -> &[Entry] {
+        bytemuck::cast_slice(&self.mmap)
+    }
+
+    /// Look up a seed by 3-byte hash prefix, searching only the bucket
+    /// `prefix[0]` falls in.
+    pub fn lookup(&self, prefix: [u8; 3]) -> Option<Vec<u8>> {
+        let bucket = prefix[0] as usize;
+        let start = self.bucket_offsets[bucket];
+        let end = self.bucket_offsets[bucket + 1];
+        lookup_in_entries(&self.entries()[start..end], prefix)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +186,43 @@ mod tests {
         let bytes: &[u8] = bytemuck::cast_slice(&entries);
         assert!(lookup_seed(bytes, [1, 2, 3]).is_none());
     }
+
+    #[cfg(feature = "native-io")]
+    #[test]
+    fn mmap_hash_table_matches_lookup_seed() {
+        let entries = [
+            Entry {
+                prefix: [0, 0, 1],
+                len: 3,
+                seed: [1, 2, 3, 0],
+            },
+            Entry {
+                prefix: [0, 1, 0],
+                len: 1,
+                seed: [4, 0, 0, 0],
+            },
+            Entry {
+                prefix: [2, 1, 1],
+                len: 4,
+                seed: [5, 6, 7, 8],
+            },
+        ];
+        let bytes: &[u8] = bytemuck::cast_slice(&entries);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hash_table.bin");
+        std::fs::write(&path, bytes).unwrap();
+
+        let table = MmapHashTable::open(&path).unwrap();
+        assert_eq!(table.lookup([0, 0, 1]).as_deref(), Some(&[1, 2, 3][..]));
+        assert_eq!(table.lookup([0, 1, 0]).as_deref(), Some(&[4][..]));
+        assert_eq!(
+            table.lookup([2, 1, 1]).as_deref(),
+            Some(&[5, 6, 7, 8][..])
+        );
+        assert!(table.lookup([9, 9, 9]).is_none());
+        // A prefix whose leading byte has no entries at all must land in an
+        // empty bucket range, not panic on an out-of-bounds slice.
+        assert!(table.lookup([1, 0, 0]).is_none());
+    }
 }
@@ -0,0 +1,482 @@
+//! Precomputed seed hash table: build, dump, and lookup.
+//!
+//! Backs the `telomere table build|dump|find` subcommands (also reachable as
+//! `telomere seeds ...`). Each entry maps
+//! the first three bytes of a seed's SHA-256 digest to the seed itself, so a
+//! span's digest prefix can be matched against known short seeds without a
+//! brute-force search. Entries are sorted by `hash_prefix` so lookups are a
+//! binary search. This module replaces the ad-hoc `hash_precompute`,
+//! `hash_dump`, and `hash_find` bins, which hardcoded `hash_table.bin` in the
+//! current directory.
+//!
+//! [`build_hash_table`] builds and sorts sequentially; [`build_hash_table_parallel`]
+//! does the same work with `rayon`; [`build_hash_table_external`] additionally
+//! bounds memory to one chunk at a time via an external sort (spill sorted
+//! chunks to disk, k-way merge) for the full `max_seed_len == 3` table.
+
+use crate::TelomereError;
+use bytemuck::{Pod, Zeroable};
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// 8-byte record stored in the hash table: the first three bytes of the
+/// seed's SHA-256 digest, the seed length, and the zero-padded seed bytes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, Zeroable, Pod)]
+pub struct HashEntry {
+    pub hash_prefix: [u8; 3],
+    pub seed_len: u8,
+    pub seed: [u8; 4],
+}
+
+/// Full, collision-proof ordering for [`HashEntry`]: `hash_prefix` first
+/// (what lookups binary-search on), then `seed_len`/`seed` as a tie-break so
+/// two seeds that happen to share a 3-byte digest prefix always land in the
+/// same relative order no matter which build path (sequential, parallel, or
+/// chunked-external-merge) produced them.
+fn cmp_entries(a: &HashEntry, b: &HashEntry) -> Ordering {
+    a.hash_prefix
+        .cmp(&b.hash_prefix)
+        .then_with(|| a.seed_len.cmp(&b.seed_len))
+        .then_with(|| a.seed.cmp(&b.seed))
+}
+
+fn validate_max_seed_len(max_seed_len: u8) -> Result<(), TelomereError> {
+    if !(1..=3).contains(&max_seed_len) {
+        return Err(TelomereError::Config(
+            "max_seed_len must be in 1..=3".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Builds the `HashEntry` for the `i`-th seed of length `len` bytes
+/// (big-endian, zero-padded into the 4-byte `seed` field).
+fn hash_entry_for(len: u8, i: u64) -> HashEntry {
+    let mut seed = [0u8; 4];
+    for b in 0..len {
+        seed[(len - 1 - b) as usize] = ((i >> (8 * b)) & 0xFF) as u8;
+    }
+    let digest = Sha256::digest(&seed[..len as usize]);
+    let mut hash_prefix = [0u8; 3];
+    hash_prefix.copy_from_slice(&digest[..3]);
+    HashEntry {
+        hash_prefix,
+        seed_len: len,
+        seed,
+    }
+}
+
+/// Generate a hash table covering every seed of length `1..=max_seed_len`
+/// bytes (inclusive, 1-3), sorted by `hash_prefix` ascending.
+pub fn build_hash_table(max_seed_len: u8) -> Result<Vec<HashEntry>, TelomereError> {
+    validate_max_seed_len(max_seed_len)?;
+
+    let total: usize = (1..=max_seed_len)
+        .map(|len| 1usize << (len as u32 * 8))
+        .sum();
+    let mut entries = Vec::new();
+    entries
+        .try_reserve_exact(total)
+        .map_err(|e| TelomereError::Internal(format!("unable to reserve memory: {e}")))?;
+
+    for len in 1..=max_seed_len {
+        let count: u64 = 1u64 << (len * 8);
+        for i in 0..count {
+            entries.push(hash_entry_for(len, i));
+        }
+    }
+
+    entries.sort_unstable_by(cmp_entries);
+    Ok(entries)
+}
+
+/// Like [`build_hash_table`], but hashes and sorts with `rayon` — for the
+/// `max_seed_len == 3` case (~16.8M entries), hashing dominates the build,
+/// and both the per-bucket hashing and the final sort parallelize cleanly.
+pub fn build_hash_table_parallel(max_seed_len: u8) -> Result<Vec<HashEntry>, TelomereError> {
+    validate_max_seed_len(max_seed_len)?;
+
+    let mut entries = Vec::new();
+    for len in 1..=max_seed_len {
+        let count: u64 = 1u64 << (len * 8);
+        let mut bucket: Vec<HashEntry> = (0..count)
+            .into_par_iter()
+            .map(|i| hash_entry_for(len, i))
+            .collect();
+        entries.append(&mut bucket);
+    }
+
+    entries.par_sort_unstable_by(cmp_entries);
+    Ok(entries)
+}
+
+/// Like [`build_hash_table_parallel`], but never holds more than
+/// `chunk_entries` entries in memory at once: each chunk is built and sorted
+/// in parallel, spilled to a run file under `run_dir`, and all runs are
+/// merged (streaming, one buffered entry per run) into `out_path` in the
+/// same sorted, raw-dump shape [`write_hash_table`] produces. Exists for the
+/// `max_seed_len == 3` case, where [`build_hash_table_parallel`]'s ~135 MB
+/// in-RAM `Vec` is the thing this is meant to avoid. Run files are removed
+/// as they're consumed; `run_dir` itself is left for the caller to manage.
+pub fn build_hash_table_external(
+    max_seed_len: u8,
+    chunk_entries: usize,
+    run_dir: &Path,
+    out_path: &Path,
+) -> Result<(), TelomereError> {
+    validate_max_seed_len(max_seed_len)?;
+    if chunk_entries == 0 {
+        return Err(TelomereError::Config(
+            "chunk_entries must be nonzero".into(),
+        ));
+    }
+
+    let mut run_paths: Vec<PathBuf> = Vec::new();
+    let mut chunk: Vec<HashEntry> = Vec::with_capacity(chunk_entries);
+
+    let mut flush = |chunk: &mut Vec<HashEntry>| -> Result<(), TelomereError> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        chunk.par_sort_unstable_by(cmp_entries);
+        let run_path = run_dir.join(format!("hash_table_run_{}.bin", run_paths.len()));
+        write_hash_table(chunk, &run_path)?;
+        run_paths.push(run_path);
+        chunk.clear();
+        Ok(())
+    };
+
+    for len in 1..=max_seed_len {
+        let count: u64 = 1u64 << (len * 8);
+        let mut offset = 0u64;
+        while offset < count {
+            let end = (offset + (chunk_entries - chunk.len()) as u64).min(count);
+            let mut generated: Vec<HashEntry> = (offset..end)
+                .into_par_iter()
+                .map(|i| hash_entry_for(len, i))
+                .collect();
+            chunk.append(&mut generated);
+            if chunk.len() >= chunk_entries {
+                flush(&mut chunk)?;
+            }
+            offset = end;
+        }
+    }
+    flush(&mut chunk)?;
+
+    let result = merge_sorted_runs(&run_paths, out_path);
+    for run_path in &run_paths {
+        let _ = fs::remove_file(run_path);
+    }
+    result
+}
+
+/// One run file's read cursor during a [`merge_sorted_runs`] k-way merge.
+struct RunCursor {
+    reader: BufReader<File>,
+}
+
+impl RunCursor {
+    fn next_entry(&mut self) -> Result<Option<HashEntry>, TelomereError> {
+        let mut buf = [0u8; std::mem::size_of::<HashEntry>()];
+        match self.reader.read_exact(&mut buf) {
+            Ok(()) => Ok(Some(*bytemuck::from_bytes::<HashEntry>(&buf[..]))),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(TelomereError::Io(e)),
+        }
+    }
+}
+
+/// Min-heap item for [`merge_sorted_runs`]: orders by [`cmp_entries`] (full
+/// entry order, not just `hash_prefix`) so `BinaryHeap` (a max-heap) pops the
+/// smallest entry first and ties between runs resolve the same way
+/// [`build_hash_table`]'s single sorted `Vec` would.
+struct HeapItem {
+    entry: HashEntry,
+    run_idx: usize,
+}
+
+impl PartialEq for HeapItem {
+    fn eq(&self, other: &Self) -> bool {
+        cmp_entries(&self.entry, &other.entry) == Ordering::Equal
+    }
+}
+
+impl Eq for HeapItem {}
+
+impl PartialOrd for HeapItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_entries(&other.entry, &self.entry)
+    }
+}
+
+/// Streams each (already sorted) run file in `run_paths` and merges them
+/// into `out_path`, sorted by `hash_prefix`, without reading any run fully
+/// into memory.
+fn merge_sorted_runs(run_paths: &[PathBuf], out_path: &Path) -> Result<(), TelomereError> {
+    let mut cursors: Vec<RunCursor> = run_paths
+        .iter()
+        .map(|path| {
+            Ok(RunCursor {
+                reader: BufReader::new(File::open(path)?),
+            })
+        })
+        .collect::<Result<_, std::io::Error>>()
+        .map_err(TelomereError::Io)?;
+
+    let mut heap = BinaryHeap::new();
+    for (run_idx, cursor) in cursors.iter_mut().enumerate() {
+        if let Some(entry) = cursor.next_entry()? {
+            heap.push(HeapItem { entry, run_idx });
+        }
+    }
+
+    let mut out = BufWriter::new(File::create(out_path).map_err(TelomereError::Io)?);
+    while let Some(HeapItem { entry, run_idx }) = heap.pop() {
+        out.write_all(bytemuck::bytes_of(&entry))
+            .map_err(TelomereError::Io)?;
+        if let Some(next) = cursors[run_idx].next_entry()? {
+            heap.push(HeapItem {
+                entry: next,
+                run_idx,
+            });
+        }
+    }
+    out.flush().map_err(TelomereError::Io)
+}
+
+/// Write entries to `path` as a raw `bytemuck`-cast byte dump.
+pub fn write_hash_table(entries: &[HashEntry], path: &Path) -> Result<(), TelomereError> {
+    let bytes: &[u8] = bytemuck::cast_slice(entries);
+    fs::write(path, bytes).map_err(TelomereError::Io)
+}
+
+/// Read and validate a hash table previously written by [`write_hash_table`].
+pub fn read_hash_table(path: &Path) -> Result<Vec<HashEntry>, TelomereError> {
+    let bytes = fs::read(path).map_err(TelomereError::Io)?;
+    if bytes.len() % std::mem::size_of::<HashEntry>() != 0 {
+        return Err(TelomereError::Header("corrupt hash table file".into()));
+    }
+    Ok(bytemuck::cast_slice(&bytes).to_vec())
+}
+
+/// Number of bits in `seed` (position of the most-significant 1 bit,
+/// big-endian, zero-based + 1; `0` for an all-zero seed).
+pub fn seed_bit_length(seed: &[u8]) -> u32 {
+    for (i, &b) in seed.iter().enumerate() {
+        if b != 0 {
+            return (seed.len() - i - 1) as u32 * 8 + (8 - b.leading_zeros());
+        }
+    }
+    0
+}
+
+/// Entries whose seed bit length falls within `min_bits..=max_bits`.
+pub fn dump_hash_table(entries: &[HashEntry], min_bits: u32, max_bits: u32) -> Vec<&HashEntry> {
+    entries
+        .iter()
+        .filter(|entry| {
+            let len = entry.seed_len as usize;
+            if entry.seed_len == 0 || len > entry.seed.len() {
+                return false;
+            }
+            let bit_len = seed_bit_length(&entry.seed[..len]);
+            bit_len >= min_bits && bit_len <= max_bits
+        })
+        .collect()
+}
+
+/// Entries whose `hash_prefix` equals the first three bytes of
+/// `SHA256(needle)`, sorted by seed length then seed value, alongside that
+/// digest prefix. `entries` must be sorted by `hash_prefix` ascending (as
+/// [`build_hash_table`] returns them).
+pub fn find_hash_table<'a>(entries: &'a [HashEntry], needle: &[u8]) -> ([u8; 3], Vec<&'a HashEntry>) {
+    let digest = Sha256::digest(needle);
+    let prefix = [digest[0], digest[1], digest[2]];
+
+    let start = entries.partition_point(|e| e.hash_prefix < prefix);
+    let mut matches: Vec<&HashEntry> = entries[start..]
+        .iter()
+        .take_while(|e| e.hash_prefix == prefix)
+        .collect();
+    matches.sort_by_key(|e| (e.seed_len, e.seed));
+    (prefix, matches)
+}
+
+// ---------------------------------------------------------------------------
+// Prefilter
+
+/// Number of distinct 3-byte `hash_prefix` values.
+const PREFIX_SPACE: usize = 1 << 24;
+
+fn prefix_index(prefix: [u8; 3]) -> usize {
+    ((prefix[0] as usize) << 16) | ((prefix[1] as usize) << 8) | prefix[2] as usize
+}
+
+/// Compact membership prefilter over a [`HashEntry`] table's `hash_prefix`
+/// values: one bit per possible 3-byte prefix (`2^24` bits, 2 MiB), so a
+/// block whose digest prefix isn't set can skip the real seed search
+/// entirely. A set bit doesn't guarantee a match (multiple seeds share a
+/// prefix and [`find_hash_table`] still has to confirm), but an unset bit
+/// rules one out for certain — false positives fall through to the real
+/// search, false negatives are impossible.
+///
+/// Built from a seed's own digest prefix, which is what [`find_hash_table`]
+/// needs for the `table find` CLI command — it does not test the actual
+/// seed-search match condition for an arbitrary [`crate::hasher::SeedExpander`],
+/// so it is intentionally not wired into `compress.rs`'s real search loop;
+/// see [`crate::seed::find_seed_match_with_prefilter`]'s doc comment.
+pub struct TruncHashTable {
+    bits: Vec<u64>,
+}
+
+impl TruncHashTable {
+    /// Builds a prefilter covering every `hash_prefix` present in `entries`.
+    pub fn build(entries: &[HashEntry]) -> Self {
+        let mut bits = vec![0u64; PREFIX_SPACE / 64];
+        for entry in entries {
+            let index = prefix_index(entry.hash_prefix);
+            bits[index / 64] |= 1 << (index % 64);
+        }
+        Self { bits }
+    }
+
+    /// Returns `false` if `needle`'s digest prefix is provably absent from
+    /// the table the prefilter was built from; `true` otherwise (which may
+    /// still be a false positive).
+    pub fn contains(&self, needle: &[u8]) -> bool {
+        let digest = Sha256::digest(needle);
+        let index = prefix_index([digest[0], digest[1], digest[2]]);
+        (self.bits[index / 64] >> (index % 64)) & 1 != 0
+    }
+
+    /// Writes the prefilter as a raw `bytemuck`-cast `u64` dump, mirroring
+    /// [`write_hash_table`]'s convention for the table itself.
+    pub fn save(&self, path: &Path) -> Result<(), TelomereError> {
+        let bytes: &[u8] = bytemuck::cast_slice(&self.bits);
+        fs::write(path, bytes).map_err(TelomereError::Io)
+    }
+
+    /// Reads a prefilter previously written by [`TruncHashTable::save`].
+    pub fn load(path: &Path) -> Result<Self, TelomereError> {
+        let bytes = fs::read(path).map_err(TelomereError::Io)?;
+        if bytes.len() != PREFIX_SPACE / 8 {
+            return Err(TelomereError::Header(
+                "corrupt truncated hash table prefilter".into(),
+            ));
+        }
+        let bits: &[u64] = bytemuck::cast_slice(&bytes);
+        Ok(Self {
+            bits: bits.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_bit_length_matches_examples() {
+        assert_eq!(seed_bit_length(&[0x00, 0x01]), 1);
+        assert_eq!(seed_bit_length(&[0x00, 0x80]), 8);
+        assert_eq!(seed_bit_length(&[0x01, 0x00]), 9);
+        assert_eq!(seed_bit_length(&[0x7F, 0x00]), 15);
+        assert_eq!(seed_bit_length(&[0x80, 0x00]), 16);
+    }
+
+    #[test]
+    fn build_rejects_out_of_range_max_seed_len() {
+        assert!(build_hash_table(0).is_err());
+        assert!(build_hash_table(4).is_err());
+    }
+
+    #[test]
+    fn parallel_build_matches_sequential_build() {
+        let sequential = build_hash_table(2).unwrap();
+        let parallel = build_hash_table_parallel(2).unwrap();
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn external_build_matches_sequential_build() {
+        let sequential = build_hash_table(2).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let out_path = dir.path().join("hash_table.bin");
+        build_hash_table_external(2, 37, dir.path(), &out_path).unwrap();
+        let external = read_hash_table(&out_path).unwrap();
+
+        assert_eq!(sequential, external);
+        // Run files are cleaned up after the merge.
+        let leftover_runs = fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|e| {
+                e.as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("hash_table_run_")
+            })
+            .count();
+        assert_eq!(leftover_runs, 0);
+    }
+
+    #[test]
+    fn external_build_rejects_zero_chunk_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let out_path = dir.path().join("hash_table.bin");
+        assert!(build_hash_table_external(1, 0, dir.path(), &out_path).is_err());
+    }
+
+    #[test]
+    fn find_locates_known_seed_and_reports_its_bit_length() {
+        let entries = build_hash_table(1).unwrap();
+        let (_, matches) = find_hash_table(&entries, &[0x2A]);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].seed_len, 1);
+        assert_eq!(matches[0].seed[0], 0x2A);
+    }
+
+    #[test]
+    fn trunc_hash_table_accepts_known_seeds_and_rejects_absent_ones() {
+        let entries = build_hash_table(1).unwrap();
+        let prefilter = TruncHashTable::build(&entries);
+        assert!(prefilter.contains(&[0x2A]));
+        // A needle whose digest prefix no 1-byte seed produces.
+        assert!(!prefilter.contains(b"definitely not a stored seed"));
+    }
+
+    #[test]
+    fn trunc_hash_table_round_trips_through_save_and_load() {
+        let entries = build_hash_table(1).unwrap();
+        let prefilter = TruncHashTable::build(&entries);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("prefilter.bin");
+        prefilter.save(&path).unwrap();
+        let loaded = TruncHashTable::load(&path).unwrap();
+        assert!(loaded.contains(&[0x2A]));
+        assert!(!loaded.contains(b"definitely not a stored seed"));
+    }
+
+    #[test]
+    fn dump_filters_by_bit_length_range() {
+        let entries = build_hash_table(1).unwrap();
+        let all = dump_hash_table(&entries, 1, 8);
+        assert_eq!(all.len(), 255); // every nonzero 1-byte seed
+        let narrow = dump_hash_table(&entries, 1, 1);
+        assert_eq!(narrow.len(), 1); // only seed 0x01 has bit length 1
+    }
+}
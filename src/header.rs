@@ -27,6 +27,12 @@
 //!
 //! Other Lotus integers keep the shared J3D2 preset unless their format section
 //! says otherwise.
+//!
+//! Encoding and decoding both route through `lotus`'s word-accumulator
+//! [`LotusBitWriter`]/[`LotusBitReader`] — no per-bit heap allocation in the
+//! hot path. [`encode_lotus_header`] (`Vec<bool>`) and [`pack_bits`] predate
+//! that and are kept only for callers built on the old shape; new code should
+//! use [`encode_lotus_header_bytes`] instead.
 
 use crate::TelomereError;
 use lotus::{
@@ -238,13 +244,26 @@ pub fn v1_record_bit_len(arity: usize, seed_index: u64) -> Result<usize, Telomer
     Ok(arity_bits + seed_bits)
 }
 
-/// Encode a complete Lotus header including the tiered seed index. Returns the
-/// bits in MSB order. This is a wrapper around the streaming form for callers
-/// that haven't migrated to `BitWriter` yet.
-pub fn encode_lotus_header(arity: usize, seed_index: u64) -> Result<Vec<bool>, TelomereError> {
+/// Encode a complete Lotus header including the tiered seed index directly
+/// into packed bytes (MSB-first, zero-padded in the final byte), via the
+/// same word-accumulator `LotusBitWriter` the streaming path uses. Prefer
+/// this over [`encode_lotus_header`] in new code — it skips the
+/// one-`bool`-per-bit intermediate entirely.
+pub fn encode_lotus_header_bytes(
+    arity: usize,
+    seed_index: u64,
+) -> Result<(Vec<u8>, usize), TelomereError> {
     let mut writer = LotusBitWriter::new();
     let bit_len = encode_v1_record_into_writer(arity, seed_index, &mut writer)?;
-    let bytes = writer.into_bytes();
+    Ok((writer.into_bytes(), bit_len))
+}
+
+/// (Legacy, avoid in new code) – encode a complete Lotus header as a `Vec<bool>`,
+/// one heap byte per bit. Kept for callers that built on this shape before
+/// [`encode_lotus_header_bytes`] existed; see that function for the
+/// word-accumulator equivalent.
+pub fn encode_lotus_header(arity: usize, seed_index: u64) -> Result<Vec<bool>, TelomereError> {
+    let (bytes, bit_len) = encode_lotus_header_bytes(arity, seed_index)?;
     let mut out = Vec::with_capacity(bit_len);
     for i in 0..bit_len {
         let byte = bytes[i / 8];
@@ -274,8 +293,8 @@ pub fn decode_header(data: &[u8]) -> Result<(Header, usize), TelomereError> {
 pub fn encode_header(header: &Header) -> Result<Vec<u8>, TelomereError> {
     match header {
         Header::Literal => {
-            let bits = encode_lotus_header(0xFF, 0)?;
-            Ok(pack_bits(&bits))
+            let (bytes, _bit_len) = encode_lotus_header_bytes(0xFF, 0)?;
+            Ok(bytes)
         }
         _ => Err(TelomereError::Header(
             "encode_header only supports Literal, use encode_lotus_header for Arity".into(),
@@ -353,6 +372,16 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn encode_lotus_header_bytes_matches_legacy_vec_bool_encoder() {
+        for arity in [1usize, 2, 3, 4, 5, 0xFF] {
+            let (bytes, bit_len) = encode_lotus_header_bytes(arity, 42).unwrap();
+            let bits = encode_lotus_header(arity, 42).unwrap();
+            assert_eq!(bit_len, bits.len());
+            assert_eq!(bytes, pack_bits(&bits));
+        }
+    }
+
     #[test]
     fn v1_record_bit_len_matches_encoder() {
         for arity in [1usize, 2, 3, 4, 5, 0xFF] {
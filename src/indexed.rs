@@ -5,11 +5,14 @@ use crate::tlmr_v2::{
     v2_seed_span_record_into_writer, validate_v2_search_config, validate_v2_span_step,
     TlmrV2LayerDescriptor,
 };
+use crate::checkpoint::IndexedCheckpoint;
+use crate::progress::{ProgressEvent, ProgressSink};
 use crate::TelomereError;
 use lotus::BitWriter;
 use rayon::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use std::time::Instant;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,7 +41,7 @@ impl IndexedCandidate {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SelectedSpanTelemetry {
     pub pass: usize,
     pub start: usize,
@@ -50,7 +53,41 @@ pub struct SelectedSpanTelemetry {
     pub savings: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Per-pass classification of a layer's selections, so `--json` consumers
+/// can see whether later passes are still finding material gains or have
+/// gone flat. Each pass re-encodes the *previous* pass's already-compressed
+/// output, so raw byte ranges aren't comparable pass-to-pass; this instead
+/// reports how that pass's own selections broke down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PassDiff {
+    pub pass: usize,
+    /// Spans covering exactly one base block: bytes compressed for the
+    /// first time at this layer.
+    pub newly_compressed_spans: usize,
+    /// Spans covering more than one base block (several blocks bundled into
+    /// a single seed match this layer).
+    pub re_bundled_spans: usize,
+    /// Bytes that stayed literal (uncompressed) through this layer.
+    pub unchanged_bytes: usize,
+}
+
+/// Build a [`PassDiff`] from the fields [`IndexedLayerTelemetry`] and
+/// [`crate::streaming::StreamingLayerTelemetry`] share.
+pub(crate) fn pass_diff(
+    pass: usize,
+    selected_count: usize,
+    bundle_count: usize,
+    literal_bytes: usize,
+) -> PassDiff {
+    PassDiff {
+        pass,
+        newly_compressed_spans: selected_count.saturating_sub(bundle_count),
+        re_bundled_spans: bundle_count,
+        unchanged_bytes: literal_bytes,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IndexedTierTelemetry {
     pub span_len: usize,
     pub unique_spans: usize,
@@ -63,7 +100,7 @@ pub struct IndexedTierTelemetry {
     pub estimated_target_table_bytes: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IndexedLayerTelemetry {
     pub pass: usize,
     pub bytes_in: usize,
@@ -78,7 +115,10 @@ pub struct IndexedLayerTelemetry {
     pub tiers: Vec<IndexedTierTelemetry>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// `Deserialize` is needed so [`crate::checkpoint::IndexedCheckpoint`] can
+/// reload the aggregate telemetry accumulated before a `--resume`d run
+/// continues past it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct IndexedTelemetry {
     pub candidate_count: usize,
     pub selected_count: usize,
@@ -88,6 +128,7 @@ pub struct IndexedTelemetry {
     pub selected_spans: Vec<SelectedSpanTelemetry>,
     pub tiers: Vec<IndexedTierTelemetry>,
     pub layers: Vec<IndexedLayerTelemetry>,
+    pub pass_diff: Vec<PassDiff>,
     pub final_payload_bytes: usize,
     pub container_bytes: usize,
     pub stop_reason: String,
@@ -104,6 +145,7 @@ impl IndexedTelemetry {
             selected_spans: Vec::new(),
             tiers: Vec::new(),
             layers: Vec::new(),
+            pass_diff: Vec::new(),
             final_payload_bytes: 0,
             container_bytes: 0,
             stop_reason: "not_started".into(),
@@ -187,9 +229,14 @@ pub fn compress_indexed_v2_with_telemetry<I: SeedLookup + ?Sized>(
         block_size,
         passes,
         hash_bits,
+        None,
     )
 }
 
+/// Like [`compress_indexed_v2_with_telemetry`] plus an explicit span step, and
+/// an optional wall-clock `deadline`: once a pass finishes at or past
+/// `deadline`, the loop stops and returns the best layer stack built so far
+/// instead of starting another pass.
 #[allow(clippy::too_many_arguments)]
 pub fn compress_indexed_v2_with_span_step_and_telemetry<I: SeedLookup + ?Sized>(
     data: &[u8],
@@ -201,6 +248,7 @@ pub fn compress_indexed_v2_with_span_step_and_telemetry<I: SeedLookup + ?Sized>(
     span_step: usize,
     passes: usize,
     hash_bits: usize,
+    deadline: Option<Instant>,
 ) -> Result<(Vec<u8>, IndexedTelemetry), TelomereError> {
     compress_indexed_v2_with_chunk_option_and_telemetry(
         data,
@@ -213,6 +261,10 @@ pub fn compress_indexed_v2_with_span_step_and_telemetry<I: SeedLookup + ?Sized>(
         passes,
         hash_bits,
         None,
+        deadline,
+        None,
+        None,
+        None,
     )
 }
 
@@ -228,6 +280,7 @@ pub fn compress_indexed_v2_with_chunked_span_step_and_telemetry<I: SeedLookup +
     passes: usize,
     hash_bits: usize,
     target_chunk_bytes: usize,
+    deadline: Option<Instant>,
 ) -> Result<(Vec<u8>, IndexedTelemetry), TelomereError> {
     compress_indexed_v2_with_chunk_option_and_telemetry(
         data,
@@ -240,6 +293,91 @@ pub fn compress_indexed_v2_with_chunked_span_step_and_telemetry<I: SeedLookup +
         passes,
         hash_bits,
         Some(target_chunk_bytes),
+        deadline,
+        None,
+        None,
+        None,
+    )
+}
+
+/// Like [`compress_indexed_v2_with_chunked_span_step_and_telemetry`] plus an
+/// optional `progress` sink: when set, it is called once with
+/// [`ProgressEvent::PassStart`] before each pass and once with
+/// [`ProgressEvent::PassEnd`] after each pass that is kept, so CLI wrappers
+/// can stream live progress instead of waiting for the final telemetry blob.
+/// A later pass that is discarded for not shrinking its input gets a
+/// `PassStart` but no `PassEnd`.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_indexed_v2_with_progress_and_telemetry<I: SeedLookup + ?Sized>(
+    data: &[u8],
+    index: &I,
+    hasher: HasherKind,
+    max_seed_len: usize,
+    max_span_len: usize,
+    block_size: usize,
+    span_step: usize,
+    passes: usize,
+    hash_bits: usize,
+    target_chunk_bytes: Option<usize>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
+) -> Result<(Vec<u8>, IndexedTelemetry), TelomereError> {
+    compress_indexed_v2_with_chunk_option_and_telemetry(
+        data,
+        index,
+        hasher,
+        max_seed_len,
+        max_span_len,
+        block_size,
+        span_step,
+        passes,
+        hash_bits,
+        target_chunk_bytes,
+        deadline,
+        progress,
+        None,
+        None,
+    )
+}
+
+/// Like [`compress_indexed_v2_with_progress_and_telemetry`] plus an optional
+/// `checkpoint_path`/`resume` pair: when `checkpoint_path` is set, the pass
+/// loop's state is snapshotted there after every completed pass; when
+/// `resume` is set (typically loaded from that same path via
+/// [`IndexedCheckpoint::load`]), the run continues from `resume.next_pass`
+/// instead of starting over at pass 1.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_indexed_v2_with_checkpoint_and_telemetry<I: SeedLookup + ?Sized>(
+    data: &[u8],
+    index: &I,
+    hasher: HasherKind,
+    max_seed_len: usize,
+    max_span_len: usize,
+    block_size: usize,
+    span_step: usize,
+    passes: usize,
+    hash_bits: usize,
+    target_chunk_bytes: Option<usize>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
+    checkpoint_path: Option<&Path>,
+    resume: Option<IndexedCheckpoint>,
+) -> Result<(Vec<u8>, IndexedTelemetry), TelomereError> {
+    compress_indexed_v2_with_chunk_option_and_telemetry(
+        data,
+        index,
+        hasher,
+        max_seed_len,
+        max_span_len,
+        block_size,
+        span_step,
+        passes,
+        hash_bits,
+        target_chunk_bytes,
+        deadline,
+        progress,
+        checkpoint_path,
+        resume,
     )
 }
 
@@ -255,17 +393,35 @@ fn compress_indexed_v2_with_chunk_option_and_telemetry<I: SeedLookup + ?Sized>(
     passes: usize,
     hash_bits: usize,
     target_chunk_bytes: Option<usize>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
+    checkpoint_path: Option<&Path>,
+    resume: Option<IndexedCheckpoint>,
 ) -> Result<(Vec<u8>, IndexedTelemetry), TelomereError> {
     validate_v2_search_config(max_seed_len, max_span_len, block_size, passes, hash_bits)?;
     validate_v2_span_step(span_step, block_size, max_span_len)?;
     validate_index_for_run(index, hasher, max_seed_len, max_span_len)?;
 
-    let mut current = data.to_vec();
-    let mut layers_inner_to_outer = Vec::new();
-    let mut aggregate = IndexedTelemetry::empty(max_seed_len);
-    aggregate.stop_reason = "max_passes".into();
+    let (mut current, mut layers_inner_to_outer, mut aggregate, start_pass) =
+        if let Some(checkpoint) = resume {
+            (
+                checkpoint.current,
+                checkpoint.layers_inner_to_outer,
+                checkpoint.telemetry,
+                checkpoint.next_pass,
+            )
+        } else {
+            let mut aggregate = IndexedTelemetry::empty(max_seed_len);
+            aggregate.stop_reason = "max_passes".into();
+            (data.to_vec(), Vec::new(), aggregate, 0)
+        };
 
-    for pass_idx in 0..passes {
+    for pass_idx in start_pass..passes {
+        if let Some(progress) = progress {
+            progress(ProgressEvent::PassStart {
+                pass: pass_idx + 1,
+            });
+        }
         let started = Instant::now();
         let (payload, mut telemetry) = encode_indexed_layer(
             pass_idx + 1,
@@ -284,6 +440,17 @@ fn compress_indexed_v2_with_chunk_option_and_telemetry<I: SeedLookup + ?Sized>(
             break;
         }
 
+        if let Some(progress) = progress {
+            progress(ProgressEvent::PassEnd {
+                pass: telemetry.pass,
+                bytes_in: telemetry.bytes_in,
+                payload_bytes: telemetry.payload_bytes,
+                selected_count: telemetry.selected_count,
+                gain_bytes: telemetry.bytes_in as i64 - telemetry.payload_bytes as i64,
+                duration_ms: telemetry.duration_ms,
+            });
+        }
+
         merge_telemetry(&mut aggregate, &telemetry);
         aggregate.layers.push(telemetry);
         layers_inner_to_outer.push(TlmrV2LayerDescriptor::for_decoded_bytes_with_span_step(
@@ -296,9 +463,29 @@ fn compress_indexed_v2_with_chunk_option_and_telemetry<I: SeedLookup + ?Sized>(
             hash_bits,
         ));
         current = payload;
+
+        if let Some(checkpoint_path) = checkpoint_path {
+            IndexedCheckpoint {
+                next_pass: pass_idx + 1,
+                current: current.clone(),
+                layers_inner_to_outer: layers_inner_to_outer.clone(),
+                telemetry: aggregate.clone(),
+            }
+            .save(checkpoint_path)?;
+        }
+
+        if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+            aggregate.stop_reason = "max_seconds".into();
+            break;
+        }
     }
 
     aggregate.final_payload_bytes = current.len();
+    aggregate.pass_diff = aggregate
+        .layers
+        .iter()
+        .map(|layer| pass_diff(layer.pass, layer.selected_count, layer.bundle_count, layer.literal_bytes))
+        .collect();
     let mut layers = layers_inner_to_outer;
     layers.reverse();
     let encoded = encode_v2_file(hasher, hash_bits, data.len() as u64, &layers, &current)?;
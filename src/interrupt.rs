@@ -0,0 +1,30 @@
+//! Cooperative SIGINT handling for long-running compress loops.
+//!
+//! [`install_handler`] arms a Ctrl-C handler that only flips a flag; actual
+//! shutdown happens at the next block boundary the running loop checks
+//! [`is_interrupted`], so in-flight state (stats CSVs, checkpoints) is left
+//! consistent instead of torn mid-write. A second Ctrl-C while a loop is
+//! still honoring the first is not handled specially — the handler stays
+//! installed and just sets the same flag again, so it does not exit the
+//! process.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static INSTALL: Once = Once::new();
+
+/// Arm the Ctrl-C handler. Idempotent: later calls are no-ops.
+pub fn install_handler() {
+    INSTALL.call_once(|| {
+        let _ = ctrlc::set_handler(|| {
+            INTERRUPTED.store(true, Ordering::SeqCst);
+        });
+    });
+}
+
+/// Whether a SIGINT has been requested since the process started. Compress
+/// loops poll this once per block.
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
@@ -0,0 +1,236 @@
+//! `std::io::Read`/[`std::io::Write`] adaptors over the `.tlmr` codec, for
+//! callers that consume or produce a stream rather than a whole buffer (tar
+//! extractors, `serde_json` readers, and similar).
+
+use crate::{compress_with_config, decompress_with_limit, Config, TelomereError};
+use std::io::{self, Read, Write};
+
+/// Wraps a `.tlmr`-encoded [`Read`] and exposes the decoded bytes through
+/// [`Read`].
+///
+/// The v1/v2 bit-stream layouts aren't decodable from a partial prefix (see
+/// [`crate::decompress_with_limit`]'s doc comment), so this adaptor reads
+/// `inner` to completion and decodes once, on the first call to
+/// [`Read::read`], then serves the decoded bytes out of an internal buffer.
+/// Prefer it over decoding up front when the rest of your pipeline already
+/// works in terms of `Read` and you'd rather not thread a `Vec<u8>` through
+/// it yourself.
+pub struct TelomereReader<R: Read> {
+    inner: Option<R>,
+    config: Config,
+    limit: usize,
+    decoded: Vec<u8>,
+    position: usize,
+}
+
+impl<R: Read> TelomereReader<R> {
+    /// Wrap `inner`, decoding against `config` with no output-size limit.
+    pub fn new(inner: R, config: Config) -> Self {
+        Self::with_limit(inner, config, usize::MAX)
+    }
+
+    /// Like [`Self::new`], but rejects output larger than `limit` bytes
+    /// (see [`crate::decompress_with_limit`]).
+    pub fn with_limit(inner: R, config: Config, limit: usize) -> Self {
+        Self {
+            inner: Some(inner),
+            config,
+            limit,
+            decoded: Vec::new(),
+            position: 0,
+        }
+    }
+
+    fn ensure_decoded(&mut self) -> io::Result<()> {
+        let Some(mut inner) = self.inner.take() else {
+            return Ok(());
+        };
+        let mut encoded = Vec::new();
+        inner.read_to_end(&mut encoded)?;
+        self.decoded =
+            decompress_with_limit(&encoded, &self.config, self.limit).map_err(to_io_error)?;
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for TelomereReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.ensure_decoded()?;
+        let remaining = &self.decoded[self.position..];
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+/// Wraps a [`Write`] and compresses everything written to it against
+/// `.tlmr` v1, mirroring `flate2::write::GzEncoder` ergonomics.
+///
+/// Like [`TelomereReader`], this can't stream block-by-block: the codec's
+/// seed search runs over a complete buffer, so writes are only buffered in
+/// memory here and the compressed output isn't written to the inner writer
+/// until [`Self::finish`] (or, best-effort, on drop).
+pub struct TelomereWriter<W: Write> {
+    inner: Option<W>,
+    config: Config,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> TelomereWriter<W> {
+    pub fn new(inner: W, config: Config) -> Self {
+        Self {
+            inner: Some(inner),
+            config,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Compress everything written so far and write it to the inner writer,
+    /// returning the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut inner = self.inner.take().expect("inner writer taken twice");
+        let encoded = compress_with_config(&self.buffer, &self.config).map_err(to_io_error)?;
+        inner.write_all(&encoded)?;
+        Ok(inner)
+    }
+}
+
+impl<W: Write> Write for TelomereWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // The codec only knows how to compress a complete buffer, so there's
+        // nothing to push to `inner` until `finish` runs the seed search.
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for TelomereWriter<W> {
+    fn drop(&mut self) {
+        if let Some(mut inner) = self.inner.take() {
+            if let Ok(encoded) = compress_with_config(&self.buffer, &self.config) {
+                let _ = inner.write_all(&encoded);
+            }
+        }
+    }
+}
+
+/// Surface a [`TelomereError`] through the `io::Error` a [`Read`] impl must
+/// return, preserving the original `io::Error` when that's what it already
+/// was.
+fn to_io_error(err: TelomereError) -> io::Error {
+    match err {
+        TelomereError::Io(e) => e,
+        other => io::Error::new(io::ErrorKind::InvalidData, other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_decoded_bytes_in_chunks() {
+        let config = Config {
+            block_size: 4,
+            max_seed_len: 1,
+            ..Config::default()
+        };
+        let original = b"abcdabcdabcdabcd".to_vec();
+        let encoded = compress_with_config(&original, &config).unwrap();
+
+        let mut reader = TelomereReader::new(io::Cursor::new(encoded), config);
+        let mut out = Vec::new();
+        let mut chunk = [0u8; 3];
+        loop {
+            let n = reader.read(&mut chunk).unwrap();
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&chunk[..n]);
+        }
+        assert_eq!(out, original);
+    }
+
+    #[test]
+    fn read_to_end_matches_direct_decompress() {
+        let config = Config {
+            block_size: 4,
+            max_seed_len: 1,
+            ..Config::default()
+        };
+        let original = b"hello world, hello world".to_vec();
+        let encoded = compress_with_config(&original, &config).unwrap();
+
+        let mut reader = TelomereReader::new(io::Cursor::new(encoded), config);
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, original);
+    }
+
+    #[test]
+    fn with_limit_rejects_oversized_output() {
+        let config = Config {
+            block_size: 4,
+            max_seed_len: 1,
+            ..Config::default()
+        };
+        let original = b"abcdabcdabcdabcd".to_vec();
+        let encoded = compress_with_config(&original, &config).unwrap();
+
+        let mut reader = TelomereReader::with_limit(io::Cursor::new(encoded), config, 4);
+        let mut out = Vec::new();
+        assert!(reader.read_to_end(&mut out).is_err());
+    }
+
+    #[test]
+    fn finish_writes_compressed_bytes_decoding_back_to_original() {
+        let config = Config {
+            block_size: 4,
+            max_seed_len: 1,
+            ..Config::default()
+        };
+        let original = b"abcdabcdabcdabcd".to_vec();
+
+        let mut writer = TelomereWriter::new(Vec::new(), config.clone());
+        writer.write_all(&original).unwrap();
+        let encoded = writer.finish().unwrap();
+
+        let decoded = decompress_with_limit(&encoded, &config, usize::MAX).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn drop_without_finish_still_flushes_compressed_output() {
+        let config = Config {
+            block_size: 4,
+            max_seed_len: 1,
+            ..Config::default()
+        };
+        let original = b"abcdabcdabcdabcd".to_vec();
+        let sink = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        struct SharedSink(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+        impl Write for SharedSink {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        {
+            let mut writer = TelomereWriter::new(SharedSink(sink.clone()), config.clone());
+            writer.write_all(&original).unwrap();
+        }
+
+        let encoded = sink.lock().unwrap().clone();
+        let decoded = decompress_with_limit(&encoded, &config, usize::MAX).unwrap();
+        assert_eq!(decoded, original);
+    }
+}
@@ -92,9 +92,13 @@ pub fn cli_hint(err: &crate::TelomereError) -> String {
         Superposition(msg) => format!("{msg}. Candidate pruning issue."),
         SuperpositionLimitExceeded(i) => format!("Too many candidates at block {i}."),
         Hash(msg) => format!("{msg}. Hash mismatch."),
+        HashMismatch { expected, actual } => {
+            format!("output hash mismatch: expected {expected:#x}, got {actual:#x}. Verify the file is intact.")
+        }
         Config(msg) => format!("{msg}. Invalid configuration."),
         Io(io) => format!("{io}"),
         Internal(msg) => format!("{msg}. This is a bug."),
+        Interrupted => "Stopped by SIGINT.".to_string(),
         Decode(msg) | Other(msg) => msg.clone(),
     }
 }
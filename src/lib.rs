@@ -26,74 +26,136 @@
 //! recursive layers and seed-span records, but they do not claim universal or
 //! open-ended convergence.
 
+mod analysis;
+#[cfg(feature = "archive")]
+pub mod archive;
+#[cfg(feature = "async-io")]
+pub mod async_io;
 mod block;
 mod block_indexer;
 mod bundle;
 mod bundle_select;
 mod bundler;
 mod candidate;
+mod checkpoint;
+mod compare;
 mod compress;
 mod compress_stats;
 mod config;
+pub mod decision_log;
+pub mod determinism;
 mod error;
+mod fingerprint;
 mod gpu;
 mod hash_reader;
+pub mod hash_table;
 pub mod hasher;
 mod header;
 mod hybrid;
 mod indexed;
+pub mod interrupt;
+mod io_adapter;
 pub mod io_utils;
 mod live_window;
+pub mod metrics;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 mod path;
+pub mod plugin;
+mod profile;
+mod progress;
 mod public_preset;
 mod seed;
 mod seed_detect;
 mod seed_expansion_index;
 mod seed_index;
 mod seed_logger;
+pub mod sha_cache;
+pub mod snapshot;
 mod stats;
 mod streaming;
 pub mod superposition;
+mod term;
 mod tile;
 mod tlmr;
 mod tlmr_v2;
 pub mod types;
 
+pub use analysis::{
+    classify_blocks, compute_compressibility_windows, write_records_json, write_windows_json,
+    BlockRecord, CompressibilityWindow, HistogramSummary, SeedCategory,
+};
+#[cfg(feature = "native-io")]
+pub use analysis::{write_records_csv, write_windows_csv};
 pub use block::{
-    print_table_summary, split_into_blocks, BlockId, BlockRef, BlockStore, BranchStatus,
+    print_table_summary, simulate_passes_with_cache, split_into_blocks, BlockId, BlockRef,
+    BlockSeedMatch, BlockStore, BranchStatus, CacheStats, SeedLengthStats, SeedMatchCache,
 };
 pub use block_indexer::{brute_force_seed_tables, IndexedBlock, SeedMatch};
 
-pub use bundle::{apply_bundle, BlockStatus, MutableBlock};
-pub use bundle_select::{select_bundles, AcceptedBundle, BundleRecord};
+pub use bundle::{apply_block_changes, apply_bundle, BlockChange, BlockStatus, MutableBlock};
+pub use bundle_select::{
+    select_bundles, AcceptedBundle, BundleRecord, RejectedRecord, RejectionReason,
+    SelectionReport,
+};
 pub use bundler::bundle_one_layer;
-pub use candidate::{prune_candidates, Block as CandidateBlock, Candidate};
+pub use candidate::{
+    prune_candidates, prune_candidates_with_policy, Block as CandidateBlock, PrunePolicy,
+    TieBreak,
+};
+pub use checkpoint::{IndexedCheckpoint, StreamingCheckpoint};
+pub use compare::{compare_tlmr_files, CompareReport, V1RecordDivergence, V2LayerDivergence};
 pub use compress::{
     compress, compress_block, compress_block_with_config, compress_multi_pass,
-    compress_multi_pass_with_config, compress_with_config, compress_with_run_summary,
+    compress_multi_pass_with_config, compress_multi_pass_with_config_and_decision_log,
+    compress_multi_pass_with_config_and_fingerprint, compress_multi_pass_with_config_and_gpu,
+    compress_multi_pass_with_config_and_limits, compress_multi_pass_with_config_and_profile,
+    compress_multi_pass_with_config_and_stats, compress_with_config, compress_with_run_summary,
+    compress_with_run_summary_and_decision_log, compress_with_run_summary_and_fingerprint,
+    compress_with_run_summary_and_gpu, compress_with_run_summary_and_limits,
+    compress_with_run_summary_and_profile, compress_with_run_summary_and_stats,
 };
-pub use compress_stats::{write_stats_csv, CompressionStats, PassStats, RunSummary};
+pub use compress_stats::{
+    write_stats_json, AtomicCompressionCounters, CompressionStats, CompressionStatsReport,
+    PassStats, RunSummary, StatsAggregator,
+};
+#[cfg(feature = "native-io")]
+pub use compress_stats::{write_aggregated_stats_csv, write_stats_csv};
 pub use config::{Config, HasherKind};
+pub use determinism::{assert_order_independent, decision_fingerprint};
+pub use fingerprint::RunFingerprint;
 pub use error::TelomereError;
-pub use gpu::GpuSeedMatcher;
+pub use gpu::{GpuSeedMatcher, GpuTileConfig};
 pub use hash_reader::lookup_seed;
+#[cfg(feature = "native-io")]
+pub use hash_reader::MmapHashTable;
 pub use header::{
     decode_header, decode_lotus_header, decode_v1_record_from_reader, encode_header,
-    encode_lotus_header, encode_v1_record_into_writer, pack_bits, v1_record_bit_len, BitReader,
-    DecodedHeader, Header, LOTUS_J_BITS, LOTUS_SEED_INDEX_J_BITS, LOTUS_SEED_INDEX_TIERS,
-    LOTUS_TIERS,
+    encode_lotus_header, encode_lotus_header_bytes, encode_v1_record_into_writer, pack_bits,
+    v1_record_bit_len, BitReader, DecodedHeader, Header, LOTUS_J_BITS, LOTUS_SEED_INDEX_J_BITS,
+    LOTUS_SEED_INDEX_TIERS, LOTUS_TIERS,
 };
 pub use hybrid::{compress_hybrid, CpuMatchRecord, GpuMatchRecord};
 pub use indexed::{
+    compress_indexed_v2_with_checkpoint_and_telemetry,
     compress_indexed_v2_with_chunked_span_step_and_telemetry, compress_indexed_v2_with_index,
+    compress_indexed_v2_with_progress_and_telemetry,
     compress_indexed_v2_with_span_step_and_telemetry, compress_indexed_v2_with_telemetry,
-    estimate_target_table_chunk_upper_bound_for_tiers, estimate_target_table_upper_bound_for_tiers,
-    select_weighted_candidates_for_tests, IndexedCandidate, IndexedLayerTelemetry,
-    IndexedTelemetry, IndexedTierTelemetry, SelectedSpanTelemetry,
+    estimate_target_table_chunk_upper_bound_for_tiers,
+    estimate_target_table_upper_bound_for_tiers, select_weighted_candidates_for_tests,
+    IndexedCandidate, IndexedLayerTelemetry, IndexedTelemetry, IndexedTierTelemetry, PassDiff,
+    SelectedSpanTelemetry,
 };
+pub use io_adapter::{TelomereReader, TelomereWriter};
 pub use io_utils::*;
-pub use live_window::{print_window, LiveStats};
+#[cfg(feature = "tui")]
+pub use live_window::LiveDashboard;
+pub use live_window::{print_window, print_window_with_config, LiveStats, WindowConfig};
 pub use path::*;
+#[cfg(feature = "phase-stats")]
+pub use profile::PhaseTimingTotals;
+pub use profile::PhaseTimings;
+pub use progress::{ProgressEvent, ProgressSink};
 pub use public_preset::{
     public_preset_selective_decode_framed, public_preset_selective_framed,
     PublicPresetTransformStats, PUBLIC_PRESET_CODEWORD_LEN, PUBLIC_PRESET_SELECTIVE_MIN_TOKEN_LEN,
@@ -102,17 +164,21 @@ pub use public_preset::{
 pub use seed::find_seed_match;
 pub use seed_detect::{detect_seed_matches, MatchRecord};
 pub use seed_expansion_index::{
-    build_seed_index_to_dir, read_index_manifest, IndexConfig, IndexManifest,
-    MmapSeedExpansionIndex, SeedExpansionIndex, SeedHit, SeedLookup, TierSpec, INDEX_VERSION,
-    SEED_ORDER_VERSION,
+    build_seed_index_to_dir, read_index_manifest, IndexConfig, IndexManifest, SeedExpansionIndex,
+    SeedHit, SeedLookup, TierSpec, INDEX_VERSION, SEED_ORDER_VERSION,
 };
+#[cfg(feature = "native-io")]
+pub use seed_expansion_index::MmapSeedExpansionIndex;
 pub use seed_index::{index_to_seed, seed_to_index};
 pub use seed_logger::{
     log_seed, log_seed_to, resume_seed_index, resume_seed_index_from, HashEntry, ResourceLimits,
+    SeedLogAppender,
 };
 pub use stats::Stats;
 pub use streaming::{
-    compress_streaming_v2, compress_streaming_v2_with_chunked_span_step_and_telemetry,
+    compress_streaming_v2, compress_streaming_v2_with_checkpoint_and_telemetry,
+    compress_streaming_v2_with_chunked_span_step_and_telemetry,
+    compress_streaming_v2_with_progress_and_telemetry,
     compress_streaming_v2_with_public_preset_selective_and_telemetry,
     compress_streaming_v2_with_public_preset_selective_config_and_telemetry,
     compress_streaming_v2_with_seed_limit_and_telemetry,
@@ -126,11 +192,15 @@ pub use streaming::{
     PublicPresetStreamingTelemetry, StreamingLayerTelemetry, StreamingTelemetry,
     StreamingTierTelemetry,
 };
-pub use tile::{chunk_blocks, flush_chunk, load_chunk, BlockChunk, TileMap};
+pub use term::{color_enabled, paint, Color};
+pub use tile::{
+    chunk_blocks, flush_chunk, load_chunk, prefetch_from_chunks, BlockChunk, TileMap,
+    TilePrefetcher,
+};
 pub use tlmr::{
-    decode_tlmr_header, decode_tlmr_header_with_len, encode_tlmr_header, tlmr_header_byte_len,
-    truncated_hash, truncated_hash_bits, TlmrHeader, LOTUS_PRESET_VERSION, TLMR_FORMAT_VERSION,
-    V1_MAGIC_VERSION_LEN,
+    decode_tlmr_header, decode_tlmr_header_with_len, encode_tlmr_header, inspect_v1_records,
+    tlmr_header_byte_len, truncated_hash, truncated_hash_bits, RecordInfo, TlmrHeader,
+    LOTUS_PRESET_VERSION, TLMR_FORMAT_VERSION, TLMR_MAGIC, V1_MAGIC_VERSION_LEN,
 };
 pub use tlmr_v2::{
     decode_layer_descriptor_from, decode_tlmr_v2_header, decode_tlmr_v2_layer_descriptors,
@@ -144,12 +214,15 @@ pub use tlmr_v2::{
     V2_TIER_POLICY_FIXED_SEED_SPAN, V2_TIER_POLICY_PUBLIC_PRESET_SELECTIVE,
     V2_TIER_POLICY_SEED_SPAN,
 };
+pub use types::Candidate;
 
 pub fn print_compression_status(original: usize, compressed: usize) {
     let ratio = 100.0 * (1.0 - compressed as f64 / original as f64);
-    eprintln!(
+    tracing::info!(
         "Compression: {} → {} bytes ({:.2}%)",
-        original, compressed, ratio
+        original,
+        compressed,
+        ratio
     );
 }
 
@@ -220,7 +293,12 @@ pub fn decompress_with_limit(
     header_config.validate()?;
 
     let block_size = header.block_size;
-    let mut out = Vec::new();
+    // `original_len` is already validated against both `limit` and
+    // `config.memory_limit` above, so reserving it up front is bounded by the
+    // same caps the caller already accepted — this just avoids the output
+    // Vec's usual doubling-reallocation-and-copy growth pattern for large
+    // outputs.
+    let mut out = Vec::with_capacity(original_len);
 
     let expander = header_config.get_expander();
 
@@ -317,7 +395,10 @@ pub fn decompress_with_limit(
     }
     let hash = truncated_hash_bits(&out, expander.as_ref(), header.hash_bits);
     if hash != header.output_hash {
-        return Err(TelomereError::Header("output hash mismatch".into()));
+        return Err(TelomereError::HashMismatch {
+            expected: header.output_hash,
+            actual: hash,
+        });
     }
     Ok(out)
 }
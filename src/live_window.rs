@@ -1,7 +1,20 @@
-#[derive(Default)]
+//! Live status display for long-running compression jobs.
+//!
+//! [`LiveStats`]/[`print_window`] are the original lightweight per-block
+//! debug logger. [`LiveDashboard`] (behind the `tui` feature) renders the
+//! same [`crate::ProgressEvent`] stream CLI wrappers already consume for
+//! `--progress-json` as a full-screen ratatui dashboard, for `--tui`.
+
+use std::io::Write;
+
+/// Default number of leading span/seed bytes shown in a live preview line.
+const DEFAULT_PREVIEW_WINDOW: usize = 3;
+
 pub struct LiveStats {
     pub total_blocks: u64,
     pub interval: u64,
+    window_size: usize,
+    writer: Option<Box<dyn Write + Send>>,
 }
 
 impl LiveStats {
@@ -9,24 +22,46 @@ impl LiveStats {
         Self {
             total_blocks: 0,
             interval,
+            window_size: DEFAULT_PREVIEW_WINDOW,
+            writer: None,
         }
     }
 
+    /// Show `window_size` leading bytes of span/seed in the preview line
+    /// instead of the default 3, for embedders that want a wider peek.
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = window_size;
+        self
+    }
+
+    /// Write the live preview to `writer` instead of a `tracing::debug!`
+    /// span, so embedders can route it into their own UI.
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = Some(Box::new(writer));
+        self
+    }
+
     /// Call whenever a block has been processed.
     pub fn tick_block(&mut self) {
         self.total_blocks += 1;
     }
 
     /// Optionally print a short summary of the current span/seed pair.
-    pub fn maybe_log(&self, span: &[u8], seed: &[u8], is_greedy: bool) {
+    pub fn maybe_log(&mut self, span: &[u8], seed: &[u8], is_greedy: bool) {
         if self.interval > 0 && self.total_blocks % self.interval == 0 {
-            println!(
+            let line = format!(
                 "[offset {:>6}] span: {:02X?}  seed: {:02X?}  method: {}",
                 self.total_blocks,
-                &span[..3.min(span.len())],
-                &seed[..3.min(seed.len())],
+                &span[..self.window_size.min(span.len())],
+                &seed[..self.window_size.min(seed.len())],
                 if is_greedy { "GREEDY" } else { "FALLBACK" }
             );
+            match self.writer.as_mut() {
+                Some(w) => {
+                    let _ = writeln!(w, "{line}");
+                }
+                None => tracing::debug!("{line}"),
+            }
         }
     }
 }
@@ -42,6 +77,42 @@ pub struct Stats {
 #[allow(dead_code)]
 use crate::compress_stats::CompressionStats;
 
+/// Configuration for [`print_window_with_config`]'s per-block live
+/// preview: how many leading bytes of span/seed to show (`window_size`),
+/// how often in blocks to print (`refresh_interval`), and where to write
+/// it. Built the same way [`crate::CompressionStats`]'s output sinks are —
+/// `with_*` methods chained off a constructor — so embedders can redirect
+/// the preview into their own UI instead of `tracing`.
+pub struct WindowConfig {
+    window_size: usize,
+    refresh_interval: u64,
+    writer: Option<Box<dyn Write + Send>>,
+}
+
+impl WindowConfig {
+    pub fn new(refresh_interval: u64) -> Self {
+        Self {
+            window_size: DEFAULT_PREVIEW_WINDOW,
+            refresh_interval,
+            writer: None,
+        }
+    }
+
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = window_size;
+        self
+    }
+
+    pub fn with_writer(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.writer = Some(Box::new(writer));
+        self
+    }
+}
+
+/// Print a live span/seed preview every `interval` blocks, via
+/// `tracing::debug!`. Kept for existing callers; wraps
+/// [`print_window_with_config`] with a default [`WindowConfig`] — use that
+/// directly for a configurable preview width or output stream.
 pub fn print_window(
     span: &[u8],
     seed: &[u8],
@@ -49,17 +120,193 @@ pub fn print_window(
     stats: &CompressionStats,
     interval: u64,
 ) {
-    if interval == 0 {
+    let mut config = WindowConfig::new(interval);
+    print_window_with_config(span, seed, is_greedy, stats, &mut config);
+}
+
+pub fn print_window_with_config(
+    span: &[u8],
+    seed: &[u8],
+    is_greedy: bool,
+    stats: &CompressionStats,
+    config: &mut WindowConfig,
+) {
+    if config.refresh_interval == 0 {
         return;
     }
-    let interval_usize = interval as usize;
+    let interval_usize = config.refresh_interval as usize;
     if stats.total_blocks % interval_usize == 0 {
-        println!(
+        let line = format!(
             "[{:>6}] span: {:02X?} seed: {:02X?} method: {}",
             stats.total_blocks,
-            &span[..3.min(span.len())],
-            &seed[..3.min(seed.len())],
+            &span[..config.window_size.min(span.len())],
+            &seed[..config.window_size.min(seed.len())],
             if is_greedy { "GREEDY" } else { "FALLBACK" },
         );
+        match config.writer.as_mut() {
+            Some(w) => {
+                let _ = writeln!(w, "{line}");
+            }
+            None => tracing::debug!("{line}"),
+        }
     }
 }
+
+#[cfg(feature = "tui")]
+mod dashboard {
+    use crate::progress::ProgressEvent;
+    use ratatui::backend::CrosstermBackend;
+    use ratatui::layout::{Constraint, Direction, Layout};
+    use ratatui::style::{Color, Style};
+    use ratatui::text::{Line, Span};
+    use ratatui::widgets::{Block, Borders, Gauge, Paragraph};
+    use ratatui::Terminal;
+    use std::io::Stdout;
+    use std::time::Instant;
+    use sysinfo::{System, SystemExt};
+
+    /// Rolling state the dashboard renders, updated from the same
+    /// [`ProgressEvent`] stream `--progress-json` consumes.
+    #[derive(Default)]
+    struct DashboardState {
+        pass: usize,
+        bytes_in: usize,
+        bytes_out: usize,
+        original_bytes: usize,
+        last_pass_duration_ms: u64,
+        last_selected_count: usize,
+        passes_completed: usize,
+        _pass_started_at: Option<Instant>,
+    }
+
+    impl DashboardState {
+        fn ratio(&self) -> f64 {
+            if self.original_bytes == 0 {
+                1.0
+            } else {
+                self.bytes_out as f64 / self.original_bytes as f64
+            }
+        }
+
+        fn matches_per_sec(&self) -> f64 {
+            if self.last_pass_duration_ms == 0 {
+                0.0
+            } else {
+                self.last_selected_count as f64 / (self.last_pass_duration_ms as f64 / 1000.0)
+            }
+        }
+    }
+
+    /// Full-screen `--tui` dashboard: pass progress, rolling ratio,
+    /// matches/sec, system memory in use, and an ETA estimated from the last
+    /// pass's duration. Restores the terminal on drop so a crash mid-run
+    /// doesn't leave the user's shell in raw/alt-screen mode.
+    pub struct LiveDashboard {
+        terminal: Terminal<CrosstermBackend<Stdout>>,
+        state: DashboardState,
+    }
+
+    impl LiveDashboard {
+        pub fn new(original_bytes: usize) -> std::io::Result<Self> {
+            crossterm::terminal::enable_raw_mode()?;
+            let mut stdout = std::io::stdout();
+            crossterm::execute!(stdout, crossterm::terminal::EnterAlternateScreen)?;
+            let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+            Ok(Self {
+                terminal,
+                state: DashboardState {
+                    original_bytes,
+                    ..Default::default()
+                },
+            })
+        }
+
+        /// Feed one [`ProgressEvent`] from the engine's [`crate::ProgressSink`]
+        /// callback and redraw.
+        pub fn on_event(&mut self, event: &ProgressEvent) {
+            match event {
+                ProgressEvent::PassStart { pass } => {
+                    self.state.pass = *pass;
+                    self.state._pass_started_at = Some(Instant::now());
+                }
+                ProgressEvent::PassEnd {
+                    bytes_in,
+                    payload_bytes,
+                    selected_count,
+                    duration_ms,
+                    ..
+                } => {
+                    self.state.bytes_in = *bytes_in;
+                    self.state.bytes_out = *payload_bytes;
+                    self.state.last_selected_count = *selected_count;
+                    self.state.last_pass_duration_ms = *duration_ms;
+                    self.state.passes_completed += 1;
+                }
+            }
+            let _ = self.render();
+        }
+
+        fn render(&mut self) -> std::io::Result<()> {
+            let ratio = self.state.ratio();
+            let matches_per_sec = self.state.matches_per_sec();
+            let pass = self.state.pass;
+            let bytes_in = self.state.bytes_in;
+            let bytes_out = self.state.bytes_out;
+            let eta_ms = self.state.last_pass_duration_ms;
+            let mut sys = System::new();
+            sys.refresh_memory();
+            let memory_mb = sys.used_memory() / (1024 * 1024);
+
+            self.terminal.draw(|f| {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                        Constraint::Min(0),
+                    ])
+                    .split(f.size());
+
+                let gauge = Gauge::default()
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(format!("Pass {pass}")),
+                    )
+                    .gauge_style(Style::default().fg(Color::Cyan))
+                    .ratio(ratio.clamp(0.0, 1.0));
+                f.render_widget(gauge, chunks[0]);
+
+                let stats = Paragraph::new(Line::from(vec![Span::raw(format!(
+                    "{bytes_in} -> {bytes_out} bytes ({:.2}%)  matches/sec: {matches_per_sec:.1}  memory: {memory_mb} MB",
+                    ratio * 100.0,
+                ))]))
+                .block(Block::default().borders(Borders::ALL).title("Stats"));
+                f.render_widget(stats, chunks[1]);
+
+                let eta_text = if eta_ms == 0 {
+                    "n/a (no completed pass yet)".to_string()
+                } else {
+                    format!("~{:.1}s per pass at current rate", eta_ms as f64 / 1000.0)
+                };
+                let eta = Paragraph::new(Line::from(vec![Span::raw(eta_text)]))
+                    .block(Block::default().borders(Borders::ALL).title("ETA"));
+                f.render_widget(eta, chunks[2]);
+            })?;
+            Ok(())
+        }
+    }
+
+    impl Drop for LiveDashboard {
+        fn drop(&mut self) {
+            let _ = crossterm::terminal::disable_raw_mode();
+            let _ = crossterm::execute!(
+                self.terminal.backend_mut(),
+                crossterm::terminal::LeaveAlternateScreen
+            );
+        }
+    }
+}
+
+#[cfg(feature = "tui")]
+pub use dashboard::LiveDashboard;
@@ -2,23 +2,66 @@
 
 use clap::{Parser, Subcommand, ValueEnum};
 use serde::Serialize;
+use std::io::{Read, Write};
 use std::time::Instant;
 use std::{fs, path::PathBuf};
 use telomere::{
-    build_seed_index_to_dir, decompress_with_limit, estimate_streaming_target_chunk_upper_bound,
+    build_seed_index_to_dir, decode_tlmr_header_with_len, decode_v2_header_and_descriptors,
+    decompress_with_limit, estimate_streaming_target_chunk_upper_bound,
     estimate_streaming_target_table_upper_bound, estimate_target_table_chunk_upper_bound_for_tiers,
-    estimate_target_table_upper_bound_for_tiers, read_index_manifest, Config, HasherKind,
-    IndexConfig, MmapSeedExpansionIndex, PassStats, RunSummary, TelomereError,
+    estimate_target_table_upper_bound_for_tiers, inspect_v1_records, read_index_manifest, Config,
+    HasherKind, IndexConfig, MmapSeedExpansionIndex, PassStats, RunSummary, TelomereError,
+    TLMR_FORMAT_VERSION, TLMR_MAGIC, TLMR_V2_FORMAT_VERSION,
 };
-use tracing::{error, info, warn};
+use tracing::{error, info};
 
 #[derive(Parser)]
 #[command(name = "telomere", author, version, about)]
 struct Cli {
+    /// Suppress informational output; only warnings and errors are printed
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Increase logging verbosity (-v for debug, -vv for trace)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Format for the final fatal-error report on stderr. `json` emits a
+    /// structured object (code, message, cause chain) instead of the
+    /// colored one-line message, for orchestration tools that would
+    /// otherwise have to pattern-match CLI error text.
+    #[arg(long, global = true, value_enum, default_value_t = ErrorFormat::Text)]
+    error_format: ErrorFormat,
+
+    /// OTLP/HTTP collector endpoint (e.g. http://localhost:4318/v1/traces)
+    /// to export tracing spans to. Requires the `otlp` feature.
+    #[cfg(feature = "otlp")]
+    #[arg(long, global = true)]
+    otlp_endpoint: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum ErrorFormat {
+    Text,
+    Json,
+}
+
+impl Cli {
+    fn log_level(&self) -> tracing::Level {
+        if self.quiet {
+            return tracing::Level::WARN;
+        }
+        match self.verbose {
+            0 => tracing::Level::INFO,
+            1 => tracing::Level::DEBUG,
+            _ => tracing::Level::TRACE,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Compress a file
@@ -27,8 +70,131 @@ enum Commands {
     /// Decompress a file
     #[command(alias = "d")]
     Decompress(DecompressArgs),
+    /// Check a compressed file's integrity without writing output
+    Verify(VerifyArgs),
+    /// Decompress a file straight to stdout, writing no temp files
+    Cat(CatArgs),
+    /// Print a compressed file's header and region listing
+    Inspect(InspectArgs),
+    /// Diff two compressed files' region lists, reporting the first divergence
+    Compare(CompareArgs),
+    /// Run a matrix of compression settings and report ratio/time per configuration
+    Bench(BenchArgs),
     /// Build and inspect experimental seed expansion indexes
     Index(IndexArgs),
+    /// Build and query a precomputed seed hash table
+    #[command(alias = "seeds")]
+    Table(TableArgs),
+    /// Classify each block of a file by shortest known-seed length against a
+    /// precomputed hash table
+    Analyze(AnalyzeArgs),
+    /// Run a battery of built-in round-trips and report GPU/table
+    /// availability, so packagers and users can validate an installation
+    Selftest(SelftestArgs),
+    /// Check the environment for common installation problems: hash table
+    /// health, GPU feature availability, a writable cache directory, and
+    /// available memory
+    Doctor(DoctorArgs),
+    /// Compress a file with per-pass phase timings broken out, so
+    /// performance regressions can be localized without an external profiler
+    Profile(ProfileArgs),
+}
+
+#[derive(clap::Args)]
+struct ProfileArgs {
+    /// File to compress for profiling
+    input: PathBuf,
+
+    /// Fixed block size in bytes
+    #[arg(long, default_value_t = 4)]
+    block_size: usize,
+
+    /// Max seed length in bytes
+    #[arg(long, default_value_t = 1)]
+    seed_depth: usize,
+
+    /// Compression passes to run
+    #[arg(long, default_value_t = 1)]
+    passes: u32,
+
+    /// Print the report as JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct DoctorArgs {
+    /// Hash table file to check. Missing is reported but not required to pass.
+    #[arg(long)]
+    table: Option<PathBuf>,
+
+    /// Directory used for disk-backed superposition spill (see
+    /// `--max-memory-bytes`); checked for writability.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// Print the report as JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct SelftestArgs {
+    /// Hash table file to check for availability. Existence is reported but
+    /// not required to pass.
+    #[arg(long)]
+    table: Option<PathBuf>,
+
+    /// Print the report as JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct AnalyzeArgs {
+    /// Input file to analyze
+    input: PathBuf,
+
+    /// Hash table file to read. Falls back to `telomere.toml`'s
+    /// `table_path`, then "hash_table.bin".
+    #[arg(long)]
+    table: Option<PathBuf>,
+
+    /// Block size in bytes
+    #[arg(long, default_value_t = 3)]
+    block_size: usize,
+
+    /// Minimum seed bit length to count as a match
+    #[arg(long, default_value_t = 1)]
+    min_bits: u32,
+
+    /// Maximum seed bit length to count as a match
+    #[arg(long, default_value_t = 256)]
+    max_bits: u32,
+
+    /// Only print summary totals, not one line per block
+    #[arg(long)]
+    summary: bool,
+
+    /// Optional CSV output path for per-block results
+    #[arg(long)]
+    csv: Option<PathBuf>,
+
+    /// Optional JSON output path for per-block results
+    #[arg(long)]
+    json: Option<PathBuf>,
+
+    /// Number of blocks per compressibility heatmap window
+    #[arg(long, default_value_t = 16)]
+    heatmap_window: usize,
+
+    /// Optional CSV output path for per-window compressibility scores
+    #[arg(long)]
+    heatmap_csv: Option<PathBuf>,
+
+    /// Optional JSON output path for per-window compressibility scores
+    #[arg(long)]
+    heatmap_json: Option<PathBuf>,
 }
 
 #[derive(clap::Args)]
@@ -77,28 +243,186 @@ struct IndexPathArgs {
 }
 
 #[derive(clap::Args)]
-struct CompressArgs {
-    /// Input file path
-    input: PathBuf,
-    /// Output file path
-    output: PathBuf,
+struct TableArgs {
+    #[command(subcommand)]
+    command: TableCommand,
+}
+
+#[derive(Subcommand)]
+enum TableCommand {
+    /// Generate a seed hash table and write it to disk
+    Build(TableBuildArgs),
+    /// List hash table entries within a seed bit-length range
+    Dump(TableDumpArgs),
+    /// Look up entries matching an input's hash prefix
+    Find(TableFindArgs),
+}
+
+#[derive(clap::Args)]
+struct TableBuildArgs {
+    /// Output path for the generated hash table. Falls back to
+    /// `telomere.toml`'s `table_path`, then "hash_table.bin".
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Max seed length in bytes to generate (1-3)
+    #[arg(long, default_value_t = 3)]
+    max_seed_len: u8,
+}
 
-    /// Max seed length in bytes (1-3 for MVP; larger values are exponentially slower)
+#[derive(clap::Args)]
+struct TableDumpArgs {
+    /// Hash table file to read. Falls back to `telomere.toml`'s
+    /// `table_path`, then "hash_table.bin".
+    #[arg(long)]
+    path: Option<PathBuf>,
+
+    /// Minimum seed bit length to include
     #[arg(long, default_value_t = 1)]
-    seed_depth: usize,
+    min_bits: u32,
+
+    /// Maximum seed bit length to include
+    #[arg(long, default_value_t = 256)]
+    max_bits: u32,
+}
+
+#[derive(clap::Args)]
+struct TableFindArgs {
+    /// Hash table file to read. Falls back to `telomere.toml`'s
+    /// `table_path`, then "hash_table.bin".
+    #[arg(long)]
+    path: Option<PathBuf>,
+
+    /// Input file path, hex-encoded bytes, or `-` for hex on stdin
+    input: String,
+}
+
+#[derive(clap::Args)]
+struct CompressArgs {
+    /// Input file path(s). With more than one, --output must be omitted and
+    /// each file is compressed to its own derived output path. Omit entirely
+    /// when using --filelist.
+    inputs: Vec<PathBuf>,
+    /// Output file path. Defaults to `INPUT.tlmr`; required when --recursive
+    /// is set, since there the output is a directory, not a derived name.
+    /// Incompatible with more than one input.
+    output: Option<PathBuf>,
+
+    /// Read input paths from a manifest file, one path per line (blank lines
+    /// and `#`-prefixed comments ignored), instead of `inputs`. Use `-` to
+    /// read the manifest from stdin. Shares the same caches and produces the
+    /// same consolidated bytes-in/bytes-out summary as passing multiple
+    /// `inputs` directly.
+    #[arg(long, conflicts_with = "inputs")]
+    filelist: Option<PathBuf>,
+
+    /// Max seed length in bytes (1-3 for MVP; larger values are exponentially slower).
+    /// Falls back to `telomere.toml`'s `seed_depth`, then 1.
+    #[arg(long)]
+    seed_depth: Option<usize>,
 
     /// Experimental streaming/v2 seed budget as the first 2^N canonical seeds
     #[arg(long)]
     seed_bits: Option<usize>,
 
-    /// Max compression passes
-    #[arg(long, default_value_t = 1)]
-    passes: u32,
+    /// Max compression passes. Falls back to `telomere.toml`'s `passes`,
+    /// then 1.
+    #[arg(long)]
+    passes: Option<u32>,
+
+    /// Threads for the parallel seed-search backend (default: logical cores).
+    /// `--threads 1` guarantees the sequential code path for reproducibility
+    /// comparisons.
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Wall-clock budget in seconds for the whole compression run. Once
+    /// exceeded, the indexed/streaming v2 engines stop after finishing their
+    /// current pass and emit the best layer stack found so far; the brute v1
+    /// engine has no intermediate passes to stop between, so it only checks
+    /// the budget before starting.
+    #[arg(long)]
+    max_seconds: Option<u64>,
+
+    /// Emit one JSON object per line to stderr for each pass_start/pass_end
+    /// event of the indexed/streaming v2 engines, so GUI wrappers and CI
+    /// dashboards can render live progress without scraping the indicatif
+    /// bar. The brute v1 engine only ever runs one pass, so it emits a single
+    /// pass_start/pass_end pair.
+    #[arg(long)]
+    progress_json: bool,
+
+    /// Render a full-screen ratatui dashboard (pass progress, rolling ratio,
+    /// matches/sec, memory, ETA) instead of the indicatif progress bar, for
+    /// multi-hour runs where scrolling log output isn't useful. Fed by the
+    /// same pass_start/pass_end events as --progress-json; incompatible with
+    /// it since both want the terminal. Requires the `tui` build feature.
+    #[cfg(feature = "tui")]
+    #[arg(long, conflicts_with = "progress_json")]
+    tui: bool,
 
     /// Save checkpoint every N minutes
     #[arg(long, default_value_t = 10)]
     checkpoint_every: u32,
 
+    /// Write a CSV snapshot (elapsed seconds, blocks seen/compressed,
+    /// greedy/fallback counts) of the `--engine brute --format v1` pass loop
+    /// as it runs, one row per block. Other engines don't tick per block and
+    /// ignore this flag.
+    #[arg(long)]
+    stats_csv: Option<PathBuf>,
+
+    /// Log every Nth block's chosen candidate at debug level. Only takes
+    /// effect alongside `--stats-csv`.
+    #[arg(long, default_value_t = 0)]
+    stats_interval: u64,
+
+    /// Write a JSONL decision log to this path: one record per emitted block
+    /// range with its chosen candidate, the alternatives superposition
+    /// pruning left behind, and their bit costs. Only takes effect with
+    /// `--engine brute --format v1`.
+    #[arg(long)]
+    decision_log: Option<PathBuf>,
+
+    /// Compute a deterministic digest over every emitted block's (index,
+    /// seed, bit cost) and print it alongside the run summary/JSON, so two
+    /// runs claiming the same settings can be compared for nondeterminism
+    /// without diffing full output. Only takes effect with `--engine brute
+    /// --format v1`.
+    #[arg(long)]
+    fingerprint: bool,
+
+    /// Run blocks through the GpuSeedMatcher research backend (see
+    /// `src/gpu.rs`) alongside the CPU search. Only takes effect with
+    /// `--engine brute --format v1`; other engines ignore it.
+    #[arg(long)]
+    gpu: bool,
+
+    /// Device index recorded against GPU-sourced candidates for telemetry.
+    /// Neither the CPU-simulated nor research GPU backend currently
+    /// discriminates by device; this is forward compatibility for a real
+    /// backend. Only takes effect alongside `--gpu`.
+    #[arg(long, default_value_t = 0)]
+    gpu_device: u32,
+
+    /// Blocks per simulated GPU tile. Only takes effect alongside `--gpu`.
+    #[arg(long, default_value_t = 4096)]
+    gpu_tile_blocks: usize,
+
+    /// Max bytes `seed_log.bin` may grow to before compression aborts
+    /// (e.g. "4GB"). Enables `ResourceLimits` enforcement across seed
+    /// logging, superposition cache growth, and tile spill for this run;
+    /// omit both this and `--max-memory-bytes` to run without it, as
+    /// before. Only takes effect with `--engine brute --format v1`.
+    #[arg(long)]
+    max_disk_bytes: Option<String>,
+
+    /// Max resident memory the superposition candidate cache may grow to
+    /// before compression aborts (e.g. "4GB", "80%"). See
+    /// `--max-disk-bytes`.
+    #[arg(long)]
+    max_memory_bytes: Option<String>,
+
     /// Max RAM usage (e.g. "4GB", "80%")
     #[arg(long, default_value = "80%")]
     memory_limit: String,
@@ -107,7 +431,18 @@ struct CompressArgs {
     #[arg(long, value_enum, default_value_t = ArgHasher::Blake3)]
     hasher: ArgHasher,
 
-    /// Resume from checkpoint file
+    /// Snapshot indexed/streaming v2 pass-loop progress to this path after
+    /// every completed pass, so an interrupted run can continue with
+    /// `--resume` instead of restarting from pass 1. Not supported by
+    /// `--engine brute --format v1`, which has no intermediate pass state to
+    /// snapshot.
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+
+    /// Resume an indexed/streaming v2 run from a snapshot written by an
+    /// earlier `--checkpoint` run. The engine, format, and search parameters
+    /// must match the interrupted run; passes already recorded in the
+    /// snapshot are not re-run.
     #[arg(long)]
     resume: Option<PathBuf>,
 
@@ -119,13 +454,43 @@ struct CompressArgs {
     #[arg(long)]
     force: bool,
 
+    /// Refuse to write over an existing output file, exiting silently with a
+    /// distinct status instead of an error. Matches coreutils' `cp
+    /// --no-clobber`.
+    #[arg(long, conflicts_with = "backup")]
+    no_clobber: bool,
+
+    /// Rename an existing output file to `OUTPUT.SUFFIX` (default `~`)
+    /// before writing, instead of refusing or requiring `--force`. Matches
+    /// coreutils' `cp --backup[=SUFFIX]`.
+    #[arg(long, num_args = 0..=1, default_missing_value = "~", conflicts_with = "no_clobber")]
+    backup: Option<String>,
+
+    /// Delete the input file once the output has been fully written and
+    /// fsynced
+    #[arg(long, conflicts_with = "keep")]
+    rm: bool,
+
+    /// Keep the input file after compression (default)
+    #[arg(long)]
+    keep: bool,
+
     /// Print JSON summary of per-pass statistics to stdout
     #[arg(long)]
     json: bool,
 
-    /// Block size in bytes
-    #[arg(long, default_value_t = 4)]
-    block_size: usize,
+    /// Run the full compression pipeline and report what would be written —
+    /// output path, size, ratio, pass count, and (for `--engine brute
+    /// --format v1`) a per-arity block count breakdown — without writing the
+    /// output file or deleting `--rm`'d input. Combine with `--json` for a
+    /// machine-readable report.
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Block size in bytes. Falls back to `telomere.toml`'s `block_size`,
+    /// then 4.
+    #[arg(long)]
+    block_size: Option<usize>,
 
     /// Compression engine
     #[arg(long, value_enum, default_value_t = EngineKind::Brute)]
@@ -155,6 +520,14 @@ struct CompressArgs {
     #[arg(long)]
     target_chunk_bytes: Option<String>,
 
+    /// Write process-wide counters (blocks processed, seed probes, matches
+    /// per arity, bytes in/out, GPU matches) to this path in Prometheus text
+    /// exposition format after the run, for node_exporter's textfile
+    /// collector. Requires the `metrics` build feature.
+    #[cfg(feature = "metrics")]
+    #[arg(long)]
+    metrics_textfile: Option<PathBuf>,
+
     /// Experimental reversible preconditioner for streaming/v2 research
     #[arg(long, value_enum, default_value_t = TransformKind::None)]
     transform: TransformKind,
@@ -166,19 +539,46 @@ struct CompressArgs {
     /// Experimental codeword byte length for --transform public-preset-selective
     #[arg(long)]
     public_preset_codeword_len: Option<usize>,
+
+    /// Treat `input`/`output` as directories: walk `input` recursively and
+    /// compress each file into `output`, preserving relative paths
+    #[arg(long)]
+    recursive: bool,
 }
 
 #[derive(clap::Args)]
 struct DecompressArgs {
     /// Input file path
     input: PathBuf,
-    /// Output file path
-    output: PathBuf,
+    /// Output file path. Defaults to INPUT with its `.tlmr` suffix stripped;
+    /// refuses to guess when INPUT doesn't end in `.tlmr`.
+    output: Option<PathBuf>,
 
     /// Overwrite existing output
     #[arg(long)]
     force: bool,
 
+    /// Refuse to write over an existing output file, exiting silently with a
+    /// distinct status instead of an error. Matches coreutils' `cp
+    /// --no-clobber`.
+    #[arg(long, conflicts_with = "backup")]
+    no_clobber: bool,
+
+    /// Rename an existing output file to `OUTPUT.SUFFIX` (default `~`)
+    /// before writing, instead of refusing or requiring `--force`. Matches
+    /// coreutils' `cp --backup[=SUFFIX]`.
+    #[arg(long, num_args = 0..=1, default_missing_value = "~", conflicts_with = "no_clobber")]
+    backup: Option<String>,
+
+    /// Delete the input file once the output has been fully written and
+    /// fsynced
+    #[arg(long, conflicts_with = "keep")]
+    rm: bool,
+
+    /// Keep the input file after decompression (default)
+    #[arg(long)]
+    keep: bool,
+
     /// Hash function override for legacy files; v1/v2 files select the hasher from the header
     #[arg(long, value_enum, default_value_t = ArgHasher::Blake3)]
     hasher: ArgHasher,
@@ -188,6 +588,75 @@ struct DecompressArgs {
     memory_limit: String,
 }
 
+#[derive(clap::Args)]
+struct VerifyArgs {
+    /// .tlmr file to verify
+    input: PathBuf,
+
+    /// Max decompressed output / intermediate layer allocation (e.g. "4GB", "80%")
+    #[arg(long, default_value = "80%")]
+    memory_limit: String,
+}
+
+#[derive(clap::Args)]
+struct CatArgs {
+    /// .tlmr file to decompress
+    input: PathBuf,
+
+    /// Max decompressed output / intermediate layer allocation (e.g. "4GB", "80%")
+    #[arg(long, default_value = "80%")]
+    memory_limit: String,
+}
+
+#[derive(clap::Args)]
+struct InspectArgs {
+    /// .tlmr file to inspect
+    input: PathBuf,
+
+    /// Print the header and region listing as JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct CompareArgs {
+    /// First .tlmr file
+    a: PathBuf,
+
+    /// Second .tlmr file
+    b: PathBuf,
+
+    /// Print the comparison report as JSON instead of text
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct BenchArgs {
+    /// File (or sample) to benchmark
+    input: PathBuf,
+
+    /// Comma-separated block sizes to try
+    #[arg(long, default_value = "2,3,4", value_delimiter = ',')]
+    block_sizes: Vec<usize>,
+
+    /// Compression passes per configuration
+    #[arg(long, default_value_t = 1)]
+    passes: u32,
+
+    /// Max seed length in bytes
+    #[arg(long, default_value_t = 1)]
+    seed_depth: usize,
+
+    /// Only benchmark the first N bytes of the input
+    #[arg(long)]
+    sample_bytes: Option<usize>,
+
+    /// Print results as JSON
+    #[arg(long)]
+    json: bool,
+}
+
 #[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
 enum ArgHasher {
     Blake3,
@@ -230,31 +699,501 @@ impl From<ArgHasher> for HasherKind {
     }
 }
 
-fn main() {
+/// Install the global `tracing` subscriber: a stderr formatter always, plus
+/// (behind the `otlp` feature, when `--otlp-endpoint` is given) an
+/// OpenTelemetry layer exporting the same spans to a collector.
+#[cfg(feature = "otlp")]
+fn init_tracing(cli: &Cli) {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use tracing_subscriber::Layer;
+
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_filter(tracing_subscriber::filter::LevelFilter::from_level(
+            cli.log_level(),
+        ));
+    let registry = tracing_subscriber::registry().with(fmt_layer);
+
+    let Some(endpoint) = cli.otlp_endpoint.as_deref() else {
+        registry.init();
+        return;
+    };
+    match telomere::otlp::init_tracer(endpoint) {
+        Ok(tracer) => {
+            let otel_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+            registry.with(otel_layer).init();
+        }
+        Err(e) => {
+            registry.init();
+            error!("failed to initialize OTLP exporter for {endpoint}: {e}");
+        }
+    }
+}
+
+#[cfg(not(feature = "otlp"))]
+fn init_tracing(cli: &Cli) {
     tracing_subscriber::fmt()
         .with_writer(std::io::stderr)
+        .with_max_level(cli.log_level())
         .init();
+}
 
-    if let Err(e) = run() {
-        error!("Fatal error: {}", e);
-        eprintln!("Fatal error: {}", e);
-        std::process::exit(1);
+fn main() {
+    let cli = Cli::parse();
+    init_tracing(&cli);
+    telomere::interrupt::install_handler();
+    let error_format = cli.error_format;
+
+    if let Err(e) = run(cli) {
+        let code = exit_code_for_error(e.as_ref());
+        let interrupted = e
+            .downcast_ref::<TelomereError>()
+            .is_some_and(|te| matches!(te, TelomereError::Interrupted));
+        if interrupted {
+            info!("Interrupted, stopping after the current block");
+        } else {
+            match error_format {
+                ErrorFormat::Json => {
+                    eprintln!("{}", build_error_report(code, e.as_ref()).to_json());
+                }
+                ErrorFormat::Text => {
+                    error!("Fatal error: {}", e);
+                    eprintln!(
+                        "{}",
+                        telomere::paint(&format!("Fatal error: {}", e), telomere::Color::Red)
+                    );
+                }
+            }
+        }
+        std::process::exit(code);
     }
 }
 
-fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let cli = Cli::parse();
+/// Structured stderr error report for `--error-format json`: an orchestrator
+/// can match on `code` instead of parsing `message` text, and `causes`
+/// preserves the full `source()` chain the text format collapses into one
+/// `Display` line.
+#[derive(Serialize)]
+struct ErrorReport {
+    code: i32,
+    message: String,
+    causes: Vec<String>,
+}
+
+impl ErrorReport {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e))
+    }
+}
+
+fn build_error_report(code: i32, err: &(dyn std::error::Error + 'static)) -> ErrorReport {
+    let mut causes = Vec::new();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+    ErrorReport {
+        code,
+        message: err.to_string(),
+        causes,
+    }
+}
+
+/// Exit code for a bad invocation: missing/conflicting flags, an input that
+/// fails a precondition check (wrong extension, output already exists).
+const EXIT_USAGE: i32 = 1;
+/// Exit code for an I/O failure reading or writing a file.
+const EXIT_IO: i32 = 2;
+/// Exit code for a `.tlmr` file that fails to decode or whose reconstructed
+/// output hash mismatches its header.
+const EXIT_CORRUPT: i32 = 3;
+/// Exit code for a `.tlmr` file whose header names a protocol version this
+/// build does not support.
+const EXIT_VERSION_MISMATCH: i32 = 4;
+/// Exit code for a run that exceeded `--memory-limit` or another resource
+/// bound.
+const EXIT_RESOURCE_LIMIT: i32 = 5;
+/// Exit code for `telomere compare` finding the two files' region lists
+/// diverge. Distinct from [`EXIT_CORRUPT`]: both files decoded fine, they
+/// just aren't the same.
+const EXIT_MISMATCH: i32 = 6;
+/// Exit code for `--no-clobber` refusing to write over an existing output
+/// file. Distinct from [`EXIT_USAGE`]: this is an expected "already done"
+/// outcome for automation, not a malformed invocation.
+const EXIT_NO_CLOBBER: i32 = 7;
+/// Exit code for a run stopped by SIGINT. Distinct from every failure class
+/// above: the run didn't error, the user asked it to stop, and it honored
+/// that at the next block boundary instead of leaving a torn output file.
+const EXIT_INTERRUPTED: i32 = 8;
+
+/// Classify a top-level error into the exit-code taxonomy above, so scripts
+/// can branch on failure class instead of parsing stderr text. Every
+/// subcommand funnels its final error through [`main`] to this function.
+fn exit_code_for_error(err: &(dyn std::error::Error + 'static)) -> i32 {
+    if let Some(te) = err.downcast_ref::<TelomereError>() {
+        return match te {
+            TelomereError::Io(_) => EXIT_IO,
+            TelomereError::Interrupted => EXIT_INTERRUPTED,
+            TelomereError::SuperpositionLimitExceeded(_) => EXIT_RESOURCE_LIMIT,
+            TelomereError::Config(msg) if msg.contains("limit") => EXIT_RESOURCE_LIMIT,
+            TelomereError::Header(msg) | TelomereError::Decode(msg) if msg.contains("version") => {
+                EXIT_VERSION_MISMATCH
+            }
+            TelomereError::Header(_) | TelomereError::Decode(_) => EXIT_CORRUPT,
+            _ => EXIT_USAGE,
+        };
+    }
+    if err.downcast_ref::<std::io::Error>().is_some() {
+        return EXIT_IO;
+    }
+
+    let detail = err.to_string().to_lowercase();
+    if detail.contains("version") {
+        EXIT_VERSION_MISMATCH
+    } else if detail.contains("limit") {
+        EXIT_RESOURCE_LIMIT
+    } else if detail.contains("corrupt") || detail.contains("truncated") {
+        EXIT_CORRUPT
+    } else {
+        EXIT_USAGE
+    }
+}
 
+fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
     match cli.command {
         Commands::Compress(args) => compress_command(*args),
         Commands::Decompress(args) => decompress_command(args),
+        Commands::Verify(args) => verify_command(args),
+        Commands::Cat(args) => cat_command(args),
+        Commands::Inspect(args) => inspect_command(args),
+        Commands::Compare(args) => compare_command(args),
+        Commands::Bench(args) => bench_command(args),
         Commands::Index(args) => index_command(args),
+        Commands::Table(args) => table_command(args),
+        Commands::Analyze(args) => analyze_command(args),
+        Commands::Selftest(args) => selftest_command(args),
+        Commands::Doctor(args) => doctor_command(args),
+        Commands::Profile(args) => profile_command(args),
     }
 }
 
-fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>> {
-    if args.resume.is_some() {
-        warn!("Resume functionality not yet implemented");
+/// Verify a `.tlmr` file's integrity: parse its header, expand every region,
+/// and check the reconstructed output against the header's hash, without
+/// writing anything. Exits 0 on success, [`EXIT_CORRUPT`] if the file fails
+/// to decode or its hash mismatches, and [`EXIT_VERSION_MISMATCH`] if the
+/// header names an unsupported protocol version.
+fn verify_command(args: VerifyArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let ext = args
+        .input
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    if ext != "tlmr" {
+        return Err(format!(
+            "Invalid file extension '.{}' — input must be a .tlmr file",
+            ext
+        )
+        .into());
+    }
+
+    let input_data = fs::read(&args.input)?;
+    let memory_limit_bytes = parse_memory_limit(&args.memory_limit)?;
+    let config = Config {
+        memory_limit: memory_limit_bytes,
+        ..Default::default()
+    };
+
+    match decompress_with_limit(&input_data, &config, usize::MAX) {
+        Ok(_) => {
+            info!("{:?}: ok", args.input);
+            Ok(())
+        }
+        Err(err) => {
+            let detail = err.to_string();
+            if detail.contains("version") {
+                error!("{:?}: unsupported version ({detail})", args.input);
+                std::process::exit(EXIT_VERSION_MISMATCH);
+            }
+            error!("{:?}: corrupt ({detail})", args.input);
+            std::process::exit(EXIT_CORRUPT);
+        }
+    }
+}
+
+/// Decompress a `.tlmr` file straight to stdout, like `zcat`, writing no
+/// intermediate or temp files. Output goes to stdout; progress/log output
+/// still goes to stderr so piping stays clean.
+fn cat_command(args: CatArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let ext = args
+        .input
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    if ext != "tlmr" {
+        return Err(format!(
+            "Invalid file extension '.{}' — input must be a .tlmr file",
+            ext
+        )
+        .into());
+    }
+
+    let input_data = fs::read(&args.input)?;
+    let memory_limit_bytes = parse_memory_limit(&args.memory_limit)?;
+    let config = Config {
+        memory_limit: memory_limit_bytes,
+        ..Default::default()
+    };
+
+    let out = decompress_with_limit(&input_data, &config, usize::MAX)?;
+    std::io::stdout().write_all(&out)?;
+    Ok(())
+}
+
+/// Print a `.tlmr` file's header fields and a region-by-region record/layer
+/// listing, without decompressing. v2 files list their layer descriptors;
+/// v1 files list each record's kind, arity, seed index, and bit offsets via
+/// [`inspect_v1_records`].
+fn inspect_command(args: InspectArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let data = fs::read(&args.input)?;
+
+    if data.len() >= 5 && data[0..4] == TLMR_MAGIC && data[4] == TLMR_V2_FORMAT_VERSION {
+        let (header, layers, _) = decode_v2_header_and_descriptors(&data)?;
+        if args.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "format": "v2",
+                    "header": header,
+                    "layers": layers,
+                }))?
+            );
+        } else {
+            println!("format: v2");
+            println!("{:#?}", header);
+            println!("layers:");
+            for (i, layer) in layers.iter().enumerate() {
+                println!(
+                    "  [{i}] decoded_len={} block_size={} max_span_len={} tier_policy={} span_step={}",
+                    layer.decoded_len,
+                    layer.block_size,
+                    layer.max_span_len,
+                    layer.tier_policy,
+                    layer.span_step
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    if data.len() >= 5 && data[0..4] == TLMR_MAGIC && data[4] == TLMR_FORMAT_VERSION {
+        let (header, payload_start) = decode_tlmr_header_with_len(&data)?;
+        let records = inspect_v1_records(&header, &data[payload_start..])?;
+        if args.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "format": "v1",
+                    "header": header,
+                    "records": records,
+                }))?
+            );
+        } else {
+            println!("format: v1");
+            println!("{:#?}", header);
+            println!("records:");
+            for (i, rec) in records.iter().enumerate() {
+                let kind = if rec.is_literal { "literal" } else { "seed" };
+                let seed_index = rec
+                    .seed_index
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "-".into());
+                println!(
+                    "  [{i}] kind={kind} arity={} seed_index={seed_index} bit_offset={} bit_len={}",
+                    rec.arity, rec.bit_offset, rec.bit_len
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    Err("Unrecognized file: missing TLMR magic or unsupported format version".into())
+}
+
+/// Diff two `.tlmr` files' decoded region lists and report the first point
+/// where they diverge. Exits [`EXIT_MISMATCH`] if the files decode fine but
+/// disagree, so the command can gate a CI determinism check.
+fn compare_command(args: CompareArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let a = fs::read(&args.a)?;
+    let b = fs::read(&args.b)?;
+    let report = telomere::compare_tlmr_files(&a, &b)?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        match &report {
+            telomere::CompareReport::V1 {
+                identical,
+                a_bytes,
+                b_bytes,
+                size_delta_bytes,
+                a_original_len,
+                b_original_len,
+                a_record_count,
+                b_record_count,
+                first_divergence,
+            } => {
+                println!("format: v1");
+                println!("identical: {identical}");
+                println!("a: {a_bytes} bytes, original_len={a_original_len}, records={a_record_count}");
+                println!("b: {b_bytes} bytes, original_len={b_original_len}, records={b_record_count}");
+                println!("size_delta_bytes: {size_delta_bytes}");
+                match first_divergence {
+                    Some(d) => println!(
+                        "first divergence at record [{}]:\n  a: {:?}\n  b: {:?}",
+                        d.index, d.a, d.b
+                    ),
+                    None if *a_record_count != *b_record_count => {
+                        println!("no divergence within common prefix, but record counts differ")
+                    }
+                    None => println!("no divergence"),
+                }
+            }
+            telomere::CompareReport::V2 {
+                identical,
+                a_bytes,
+                b_bytes,
+                size_delta_bytes,
+                a_original_len,
+                b_original_len,
+                a_layer_count,
+                b_layer_count,
+                first_divergence,
+            } => {
+                println!("format: v2");
+                println!("identical: {identical}");
+                println!("a: {a_bytes} bytes, original_len={a_original_len}, layers={a_layer_count}");
+                println!("b: {b_bytes} bytes, original_len={b_original_len}, layers={b_layer_count}");
+                println!("size_delta_bytes: {size_delta_bytes}");
+                match first_divergence {
+                    Some(d) => println!(
+                        "first divergence at layer [{}]:\n  a: {:?}\n  b: {:?}",
+                        d.index, d.a, d.b
+                    ),
+                    None if *a_layer_count != *b_layer_count => {
+                        println!("no divergence within common prefix, but layer counts differ")
+                    }
+                    None => println!("no divergence"),
+                }
+            }
+        }
+    }
+
+    if !report.identical() {
+        std::process::exit(EXIT_MISMATCH);
+    }
+    Ok(())
+}
+
+/// One `telomere bench` configuration's result.
+#[derive(Serialize)]
+struct BenchResult {
+    block_size: usize,
+    original_bytes: usize,
+    final_bytes: usize,
+    delta_pct: f64,
+    duration_ms: u64,
+}
+
+/// Run brute-engine v1 compression across a matrix of block sizes and report
+/// the resulting ratio and time for each, so users can pick parameters
+/// empirically instead of guessing.
+fn bench_command(args: BenchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if args.block_sizes.is_empty() {
+        return Err("--block-sizes must name at least one block size".into());
+    }
+
+    let mut input_data = fs::read(&args.input)?;
+    if let Some(sample_bytes) = args.sample_bytes {
+        input_data.truncate(sample_bytes);
+    }
+
+    let mut results = Vec::with_capacity(args.block_sizes.len());
+    for &block_size in &args.block_sizes {
+        let config = Config {
+            block_size,
+            max_seed_len: args.seed_depth,
+            ..Default::default()
+        };
+        config.validate()?;
+        let (out, summary) =
+            telomere::compress_with_run_summary(&input_data, &config, args.passes as usize)?;
+        results.push(BenchResult {
+            block_size,
+            original_bytes: summary.original_bytes,
+            final_bytes: out.len(),
+            delta_pct: summary.total_delta_pct,
+            duration_ms: summary.total_duration_ms,
+        });
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else {
+        println!(
+            "{:>10} {:>14} {:>11} {:>10} {:>9}",
+            "block_size", "orig_bytes", "out_bytes", "delta_pct", "time_ms"
+        );
+        for r in &results {
+            println!(
+                "{:>10} {:>14} {:>11} {:>10.2} {:>9}",
+                r.block_size, r.original_bytes, r.final_bytes, r.delta_pct, r.duration_ms
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Read input paths from a `--filelist` manifest: one path per line, blank
+/// lines and `#`-prefixed comments ignored. `-` reads the manifest from
+/// stdin rather than a file.
+fn read_filelist(path: &std::path::Path) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let contents = if path == std::path::Path::new("-") {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf)?;
+        buf
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(PathBuf::from)
+        .collect())
+}
+
+fn compress_command(mut args: CompressArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(filelist) = &args.filelist {
+        args.inputs = read_filelist(filelist)?;
+        if args.inputs.is_empty() {
+            return Err(format!("--filelist {filelist:?} contained no input paths").into());
+        }
+    }
+    if args.inputs.is_empty() {
+        return Err("no input files: pass INPUT(S) or --filelist".into());
+    }
+
+    if (args.resume.is_some() || args.checkpoint.is_some())
+        && !matches!(
+            (args.engine, args.format),
+            (EngineKind::Indexed | EngineKind::Streaming, FormatKind::V2)
+        )
+    {
+        return Err(
+            "--checkpoint/--resume are supported only by indexed/streaming v2 compression".into(),
+        );
     }
 
     if args.span_step.is_some()
@@ -305,20 +1244,46 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
     {
         return Err("--seed-bits is supported only by streaming v2 compression".into());
     }
-
-    if args.output.exists() && !args.force {
-        return Err(format!(
-            "Output file {:?} exists (use --force to overwrite)",
-            args.output
+    if (args.max_disk_bytes.is_some() || args.max_memory_bytes.is_some())
+        && !matches!(
+            (args.engine, args.format),
+            (EngineKind::Brute, FormatKind::V1)
         )
-        .into());
+    {
+        return Err(
+            "--max-disk-bytes/--max-memory-bytes are supported only by brute-force v1 compression"
+                .into(),
+        );
+    }
+    if args.gpu
+        && !matches!(
+            (args.engine, args.format),
+            (EngineKind::Brute, FormatKind::V1)
+        )
+    {
+        return Err(
+            "--gpu/--gpu-device/--gpu-tile-blocks are supported only by brute-force v1 compression"
+                .into(),
+        );
+    }
+
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|e| format!("failed to configure --threads {threads}: {e}"))?;
     }
 
+    let file_config = load_file_config()?;
+    let block_size = args.block_size.or(file_config.block_size).unwrap_or(4);
+    let seed_depth = args.seed_depth.or(file_config.seed_depth).unwrap_or(1);
+    let passes = args.passes.or(file_config.passes).unwrap_or(1);
+
     let memory_limit_bytes = parse_memory_limit(&args.memory_limit)?;
     let hasher: HasherKind = args.hasher.into();
     let config = Config {
-        block_size: args.block_size,
-        max_seed_len: args.seed_depth,
+        block_size,
+        max_seed_len: seed_depth,
         max_arity: 5,
         hash_bits: 13,
         hasher,
@@ -331,23 +1296,377 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
         .seed_bits
         .map(telomere::seed_limit_from_bits)
         .transpose()?;
+    let deadline = args
+        .max_seconds
+        .map(|secs| Instant::now() + std::time::Duration::from_secs(secs));
 
-    let input_data = fs::read(&args.input)?;
+    if args.recursive {
+        if args.inputs.len() != 1 {
+            return Err("--recursive takes exactly one input directory".into());
+        }
+        let output = args
+            .output
+            .clone()
+            .ok_or("--recursive requires an explicit output directory")?;
+        let result = compress_recursive(
+            &args.inputs[0],
+            &output,
+            &args,
+            &config,
+            seed_limit,
+            passes,
+            deadline,
+        );
+        write_metrics_textfile(&args)?;
+        return result;
+    }
+
+    if args.inputs.len() > 1 && args.output.is_some() {
+        return Err("--output is ambiguous with more than one input file".into());
+    }
+
+    if args.inputs.len() == 1 {
+        let input = &args.inputs[0];
+        let output = args
+            .output
+            .clone()
+            .unwrap_or_else(|| default_compress_output_path(input));
+        prepare_overwrite(&output, args.force, args.no_clobber, args.backup.as_deref())?;
+        let (_, out_len, _) = compress_file_to_path(
+            input, &output, &args, &config, seed_limit, passes, deadline, false,
+        )?;
+        info!("Wrote {} bytes to {:?}", out_len, output);
+        write_metrics_textfile(&args)?;
+        return Ok(());
+    }
+
+    let mut total_in = 0u64;
+    let mut total_out = 0u64;
+    let mut stats_aggregator = telomere::StatsAggregator::new();
+    for input in &args.inputs {
+        let output = default_compress_output_path(input);
+        prepare_overwrite(&output, args.force, args.no_clobber, args.backup.as_deref())?;
+        let (in_len, out_len, stats) = compress_file_to_path(
+            input, &output, &args, &config, seed_limit, passes, deadline, true,
+        )?;
+        info!("Wrote {} bytes to {:?}", out_len, output);
+        total_in += in_len as u64;
+        total_out += out_len as u64;
+        if let Some(mut stats) = stats {
+            stats_aggregator.add(&mut stats);
+        }
+    }
+    info!(
+        "Compressed {} files: {} bytes -> {} bytes",
+        args.inputs.len(),
+        total_in,
+        total_out
+    );
+    if let Some(path) = args.stats_csv.as_deref() {
+        let path = path.to_str().ok_or("--stats-csv path must be valid UTF-8")?;
+        telomere::write_aggregated_stats_csv(&stats_aggregator, path)?;
+    }
+    write_metrics_textfile(&args)?;
+    Ok(())
+}
+
+/// Write the process-wide `metrics` counters (see `telomere::metrics`) to
+/// `--metrics-textfile`, if set. No-op (and the flag doesn't exist) unless
+/// built with `--features metrics`, since the counters themselves aren't
+/// tracked otherwise.
+#[cfg(feature = "metrics")]
+fn write_metrics_textfile(args: &CompressArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(path) = &args.metrics_textfile {
+        telomere::metrics::global().write_textfile(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "metrics"))]
+fn write_metrics_textfile(_args: &CompressArgs) -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+/// Resolve the overwrite policy for an output path that already exists:
+/// `--backup` renames it aside with `SUFFIX` first, `--no-clobber` exits
+/// [`EXIT_NO_CLOBBER`] immediately without printing an error, `--force`
+/// allows the caller to overwrite it directly, and otherwise the write is
+/// refused. A no-op when `path` doesn't exist yet.
+fn prepare_overwrite(
+    path: &std::path::Path,
+    force: bool,
+    no_clobber: bool,
+    backup: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !path.exists() {
+        return Ok(());
+    }
+    if no_clobber {
+        std::process::exit(EXIT_NO_CLOBBER);
+    }
+    if let Some(suffix) = backup {
+        let backup_name = format!(
+            "{}{suffix}",
+            path.file_name().and_then(|n| n.to_str()).unwrap_or_default()
+        );
+        fs::rename(path, path.with_file_name(backup_name))?;
+        return Ok(());
+    }
+    if !force {
+        return Err(format!("Output file {path:?} exists (use --force to overwrite)").into());
+    }
+    Ok(())
+}
+
+/// Derive the default compression output path for an input with no explicit
+/// `--output`: `INPUT` with a `.tlmr` extension appended, matching gzip's
+/// `file` -> `file.gz` convention.
+fn default_compress_output_path(input: &std::path::Path) -> PathBuf {
+    let mut name = input.file_name().unwrap_or_default().to_os_string();
+    name.push(".tlmr");
+    input.with_file_name(name)
+}
+
+/// Recursively compress every regular file under `input` into `output`,
+/// preserving relative paths and appending `.tlmr` to each output file
+/// name, then report aggregate bytes in/out across the tree.
+fn compress_recursive(
+    input: &PathBuf,
+    output: &PathBuf,
+    args: &CompressArgs,
+    config: &Config,
+    seed_limit: Option<usize>,
+    passes: u32,
+    deadline: Option<Instant>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !input.is_dir() {
+        return Err(format!("{:?} is not a directory (required by --recursive)", input).into());
+    }
+    fs::create_dir_all(output)?;
+
+    let mut total_in = 0u64;
+    let mut total_out = 0u64;
+    let mut file_count = 0usize;
+    let mut stats_aggregator = telomere::StatsAggregator::new();
+
+    let mut dirs = vec![input.clone()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+
+            let relative = path
+                .strip_prefix(input)
+                .map_err(|e| format!("failed to compute relative path for {path:?}: {e}"))?;
+            let mut out_path = output.join(relative);
+            let out_name = match out_path.file_name().and_then(|n| n.to_str()) {
+                Some(name) => format!("{name}.tlmr"),
+                None => return Err(format!("invalid file name in {path:?}").into()),
+            };
+            out_path.set_file_name(out_name);
+            if let Some(parent) = out_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            prepare_overwrite(&out_path, args.force, args.no_clobber, args.backup.as_deref())?;
+
+            let (in_len, out_len, stats) = compress_file_to_path(
+                &path, &out_path, args, config, seed_limit, passes, deadline, true,
+            )?;
+            total_in += in_len as u64;
+            total_out += out_len as u64;
+            file_count += 1;
+            if let Some(mut stats) = stats {
+                stats_aggregator.add(&mut stats);
+            }
+        }
+    }
+
+    info!(
+        "Compressed {} files under {:?}: {} bytes -> {} bytes",
+        file_count, input, total_in, total_out
+    );
+    if let Some(path) = args.stats_csv.as_deref() {
+        let path = path.to_str().ok_or("--stats-csv path must be valid UTF-8")?;
+        telomere::write_aggregated_stats_csv(&stats_aggregator, path)?;
+    }
+    Ok(())
+}
+
+/// Compress `input_path` to `output_path` per `args`/`config`, returning the
+/// original and compressed byte counts, plus the finished [`CompressionStats`]
+/// when `--stats-csv` was set and the v1 brute engine ran. Shared by the
+/// single-file and `--recursive`/multi-file batch paths in
+/// [`compress_command`].
+///
+/// `aggregate_stats` switches `--stats-csv` from writing a live per-block CSV
+/// (the single-file behavior) to building a plain in-memory
+/// [`CompressionStats`] the caller folds into a [`telomere::StatsAggregator`]
+/// instead — running it per-block in batch mode would otherwise make every
+/// file after the first clobber the same CSV path.
+#[allow(clippy::too_many_arguments)]
+fn compress_file_to_path(
+    input_path: &std::path::Path,
+    output_path: &std::path::Path,
+    args: &CompressArgs,
+    config: &Config,
+    seed_limit: Option<usize>,
+    passes: u32,
+    deadline: Option<Instant>,
+    aggregate_stats: bool,
+) -> Result<(usize, usize, Option<telomere::CompressionStats>), Box<dyn std::error::Error>> {
+    if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+        return Err(format!("--max-seconds budget exhausted before compressing {input_path:?}").into());
+    }
+
+    let hasher: HasherKind = args.hasher.into();
+    let input_data = fs::read(input_path)?;
     info!(
         "Compressing {} bytes with engine={:?} format={:?} seed_depth={} passes={}...",
         input_data.len(),
         args.engine,
         args.format,
-        args.seed_depth,
-        args.passes
+        config.max_seed_len,
+        passes
     );
 
+    let emit_progress_json = |event: telomere::ProgressEvent| {
+        if let Ok(line) = serde_json::to_string(&event) {
+            eprintln!("{line}");
+        }
+    };
+    #[cfg(feature = "tui")]
+    let dashboard_cell = if args.tui {
+        Some(std::cell::RefCell::new(telomere::LiveDashboard::new(
+            input_data.len(),
+        )?))
+    } else {
+        None
+    };
+    #[cfg(feature = "tui")]
+    let emit_tui = |event: telomere::ProgressEvent| {
+        if let Some(dashboard) = &dashboard_cell {
+            dashboard.borrow_mut().on_event(&event);
+        }
+    };
+
+    let progress: Option<telomere::ProgressSink> = if args.progress_json {
+        Some(&emit_progress_json)
+    } else {
+        #[cfg(feature = "tui")]
+        {
+            if args.tui {
+                Some(&emit_tui)
+            } else {
+                None
+            }
+        }
+        #[cfg(not(feature = "tui"))]
+        {
+            None
+        }
+    };
+    let checkpoint_path = args.checkpoint.as_deref();
+
+    let mut stats_result: Option<telomere::CompressionStats> = None;
     let started = Instant::now();
+    // Below this size, `compress_with_run_summary_and_fingerprint`'s
+    // RunSummary/PassStats bookkeeping and progress-event plumbing cost more
+    // relative to the work itself than for a normal-sized run, and every bit
+    // of it is wasted unless the caller actually asked for stats/GPU/limits/
+    // a decision log/a fingerprint/progress events. For the "compress many
+    // small files" workload that overhead is paid once per file, so bypass
+    // it and call the single-pass compressor ([`telomere::compress_with_config`])
+    // directly when none of that instrumentation was requested.
+    const SMALL_INPUT_FAST_PATH_BYTES: usize = 4096;
+    let wants_brute_telemetry = args.stats_csv.is_some()
+        || args.gpu
+        || args.max_disk_bytes.is_some()
+        || args.max_memory_bytes.is_some()
+        || args.decision_log.is_some()
+        || args.fingerprint
+        || progress.is_some();
+
     let out = match (args.engine, args.format) {
+        (EngineKind::Brute, FormatKind::V1)
+            if input_data.len() <= SMALL_INPUT_FAST_PATH_BYTES && !wants_brute_telemetry =>
+        {
+            let out = telomere::compress_with_config(&input_data, config)?;
+            if !args.dry_run {
+                let summary = one_pass_summary(input_data.len(), out.len(), started);
+                emit_summary(&summary, args.json);
+            }
+            out
+        }
         (EngineKind::Brute, FormatKind::V1) => {
-            let (out, summary) =
-                telomere::compress_with_run_summary(&input_data, &config, args.passes as usize)?;
-            emit_summary(&summary, args.json);
+            if let Some(progress) = progress {
+                progress(telomere::ProgressEvent::PassStart { pass: 1 });
+            }
+            let mut stats = match args.stats_csv.as_deref() {
+                Some(_) if aggregate_stats => {
+                    Some(telomere::CompressionStats::new().with_interval(args.stats_interval))
+                }
+                Some(path) => {
+                    let path = path.to_str().ok_or("--stats-csv path must be valid UTF-8")?;
+                    Some(telomere::CompressionStats::with_csv(path)?.with_interval(args.stats_interval))
+                }
+                None => None,
+            };
+            let gpu_config = args.gpu.then_some(telomere::GpuTileConfig {
+                device: args.gpu_device,
+                tile_blocks: args.gpu_tile_blocks,
+            });
+            let resource_limits = if args.max_disk_bytes.is_some() || args.max_memory_bytes.is_some() {
+                let max_disk_bytes = match &args.max_disk_bytes {
+                    Some(s) => parse_byte_size(s)?,
+                    None => u64::MAX,
+                };
+                let max_memory_bytes = match &args.max_memory_bytes {
+                    Some(s) => parse_memory_limit(s)? as u64,
+                    None => u64::MAX,
+                };
+                Some(telomere::ResourceLimits {
+                    max_disk_bytes,
+                    max_memory_bytes,
+                })
+            } else {
+                None
+            };
+            let mut decision_logger = match args.decision_log.as_deref() {
+                Some(path) => Some(telomere::decision_log::DecisionLogger::create(path)?),
+                None => None,
+            };
+            let mut fingerprint = args.fingerprint.then(telomere::RunFingerprint::new);
+            let (out, summary) = telomere::compress_with_run_summary_and_fingerprint(
+                &input_data,
+                config,
+                passes as usize,
+                stats.as_mut(),
+                gpu_config.as_ref(),
+                resource_limits.as_ref(),
+                None,
+                decision_logger.as_mut(),
+                fingerprint.as_mut(),
+            )?;
+            if let (Some(progress), Some(pass)) = (progress, summary.passes.first()) {
+                // v1's RunSummary doesn't track a per-pass selected-record
+                // count, unlike the v2 engines' IndexedLayerTelemetry/
+                // StreamingLayerTelemetry, so selected_count is always 0 here.
+                progress(telomere::ProgressEvent::PassEnd {
+                    pass: pass.pass,
+                    bytes_in: pass.bytes_in,
+                    payload_bytes: pass.bytes_out,
+                    selected_count: 0,
+                    gain_bytes: -pass.delta_bytes,
+                    duration_ms: pass.duration_ms,
+                });
+            }
+            if !args.dry_run {
+                emit_summary(&summary, args.json);
+            }
+            stats_result = stats;
             out
         }
         (EngineKind::Indexed, FormatKind::V2) => {
@@ -359,7 +1678,7 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
             let max_span_len = args
                 .max_span_len
                 .unwrap_or_else(|| index.manifest().max_span_len);
-            let span_step = args.span_step.unwrap_or(args.block_size);
+            let span_step = args.span_step.unwrap_or(config.block_size);
             let tier_lengths: Vec<usize> = index
                 .manifest()
                 .tiers
@@ -372,6 +1691,11 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                 .as_deref()
                 .map(parse_memory_limit)
                 .transpose()?;
+            let resume = args
+                .resume
+                .as_deref()
+                .map(telomere::IndexedCheckpoint::load)
+                .transpose()?;
             let (out, telemetry) = if let Some(target_chunk_bytes) = target_chunk_bytes {
                 enforce_target_table_memory_limit(
                     "indexed chunk",
@@ -383,17 +1707,21 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                     )?,
                     config.memory_limit,
                 )?;
-                telomere::compress_indexed_v2_with_chunked_span_step_and_telemetry(
+                telomere::compress_indexed_v2_with_checkpoint_and_telemetry(
                     &input_data,
                     &index,
                     hasher,
-                    args.seed_depth,
+                    config.max_seed_len,
                     max_span_len,
-                    args.block_size,
+                    config.block_size,
                     span_step,
-                    args.passes as usize,
+                    passes as usize,
                     config.hash_bits,
-                    target_chunk_bytes,
+                    Some(target_chunk_bytes),
+                    deadline,
+                    progress,
+                    checkpoint_path,
+                    resume,
                 )?
             } else {
                 enforce_target_table_memory_limit(
@@ -405,33 +1733,51 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                     ),
                     config.memory_limit,
                 )?;
-                telomere::compress_indexed_v2_with_span_step_and_telemetry(
+                telomere::compress_indexed_v2_with_checkpoint_and_telemetry(
                     &input_data,
                     &index,
                     hasher,
-                    args.seed_depth,
+                    config.max_seed_len,
                     max_span_len,
-                    args.block_size,
+                    config.block_size,
                     span_step,
-                    args.passes as usize,
+                    passes as usize,
                     config.hash_bits,
+                    None,
+                    deadline,
+                    progress,
+                    checkpoint_path,
+                    resume,
                 )?
             };
             let summary = one_pass_summary(input_data.len(), out.len(), started);
-            emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+            if !args.dry_run {
+                emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+            }
             out
         }
         (EngineKind::Streaming, FormatKind::V2) => {
             let max_span_len = args
                 .max_span_len
-                .unwrap_or(args.block_size * config.max_arity as usize);
-            let span_step = args.span_step.unwrap_or(args.block_size);
+                .unwrap_or(config.block_size * config.max_arity as usize);
+            let span_step = args.span_step.unwrap_or(config.block_size);
             let target_chunk_bytes = args
                 .target_chunk_bytes
                 .as_deref()
                 .map(parse_memory_limit)
                 .transpose()?;
+            let resume = args
+                .resume
+                .as_deref()
+                .map(telomere::StreamingCheckpoint::load)
+                .transpose()?;
             if args.transform == TransformKind::PublicPresetSelective {
+                if checkpoint_path.is_some() || resume.is_some() {
+                    return Err(
+                        "--checkpoint/--resume are not supported with --transform public-preset-selective"
+                            .into(),
+                    );
+                }
                 let public_preset_min_token_len = args
                     .public_preset_min_token_len
                     .unwrap_or(telomere::PUBLIC_PRESET_SELECTIVE_MIN_TOKEN_LEN);
@@ -451,7 +1797,7 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                     estimate_streaming_target_table_upper_bound(
                         estimated_len,
                         max_span_len,
-                        args.block_size,
+                        config.block_size,
                         span_step,
                         config.max_arity,
                     )?,
@@ -461,20 +1807,24 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                     telomere::compress_streaming_v2_with_public_preset_selective_config_and_telemetry(
                         &input_data,
                         hasher,
-                        args.seed_depth,
+                        config.max_seed_len,
                         max_span_len,
-                        args.block_size,
+                        config.block_size,
                         span_step,
                         config.max_arity,
-                        args.passes as usize,
+                        passes as usize,
                         config.hash_bits,
                         target_chunk_bytes,
                         seed_limit,
                         public_preset_min_token_len,
                         public_preset_codeword_len,
+                        deadline,
+                        progress,
                     )?;
                 let summary = one_pass_summary(input_data.len(), out.len(), started);
-                emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+                if !args.dry_run {
+                    emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+                }
                 out
             } else if let Some(target_chunk_bytes) = target_chunk_bytes {
                 enforce_target_table_memory_limit(
@@ -482,42 +1832,34 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                     estimate_streaming_target_chunk_upper_bound(
                         input_data.len(),
                         max_span_len,
-                        args.block_size,
+                        config.block_size,
                         span_step,
                         config.max_arity,
                         target_chunk_bytes,
                     )?,
                     config.memory_limit,
                 )?;
-                let (out, telemetry) = if let Some(seed_limit) = seed_limit {
-                    telomere::compress_streaming_v2_with_seed_limit_and_telemetry(
-                        &input_data,
-                        hasher,
-                        seed_limit,
-                        max_span_len,
-                        args.block_size,
-                        span_step,
-                        config.max_arity,
-                        args.passes as usize,
-                        config.hash_bits,
-                        Some(target_chunk_bytes),
-                    )?
-                } else {
-                    telomere::compress_streaming_v2_with_chunked_span_step_and_telemetry(
-                        &input_data,
-                        hasher,
-                        args.seed_depth,
-                        max_span_len,
-                        args.block_size,
-                        span_step,
-                        config.max_arity,
-                        args.passes as usize,
-                        config.hash_bits,
-                        target_chunk_bytes,
-                    )?
-                };
+                let (out, telemetry) = telomere::compress_streaming_v2_with_checkpoint_and_telemetry(
+                    &input_data,
+                    hasher,
+                    config.max_seed_len,
+                    max_span_len,
+                    config.block_size,
+                    span_step,
+                    config.max_arity,
+                    passes as usize,
+                    config.hash_bits,
+                    Some(target_chunk_bytes),
+                    seed_limit,
+                    deadline,
+                    progress,
+                    checkpoint_path,
+                    resume,
+                )?;
                 let summary = one_pass_summary(input_data.len(), out.len(), started);
-                emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+                if !args.dry_run {
+                    emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+                }
                 out
             } else {
                 enforce_target_table_memory_limit(
@@ -525,40 +1867,33 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
                     estimate_streaming_target_table_upper_bound(
                         input_data.len(),
                         max_span_len,
-                        args.block_size,
+                        config.block_size,
                         span_step,
                         config.max_arity,
                     )?,
                     config.memory_limit,
                 )?;
-                let (out, telemetry) = if let Some(seed_limit) = seed_limit {
-                    telomere::compress_streaming_v2_with_seed_limit_and_telemetry(
-                        &input_data,
-                        hasher,
-                        seed_limit,
-                        max_span_len,
-                        args.block_size,
-                        span_step,
-                        config.max_arity,
-                        args.passes as usize,
-                        config.hash_bits,
-                        None,
-                    )?
-                } else {
-                    telomere::compress_streaming_v2_with_span_step_and_telemetry(
-                        &input_data,
-                        hasher,
-                        args.seed_depth,
-                        max_span_len,
-                        args.block_size,
-                        span_step,
-                        config.max_arity,
-                        args.passes as usize,
-                        config.hash_bits,
-                    )?
-                };
+                let (out, telemetry) = telomere::compress_streaming_v2_with_checkpoint_and_telemetry(
+                    &input_data,
+                    hasher,
+                    config.max_seed_len,
+                    max_span_len,
+                    config.block_size,
+                    span_step,
+                    config.max_arity,
+                    passes as usize,
+                    config.hash_bits,
+                    None,
+                    seed_limit,
+                    deadline,
+                    progress,
+                    checkpoint_path,
+                    resume,
+                )?;
                 let summary = one_pass_summary(input_data.len(), out.len(), started);
-                emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+                if !args.dry_run {
+                    emit_summary_with_telemetry(&summary, &telemetry, args.json, args.telemetry_limit);
+                }
                 out
             }
         }
@@ -579,19 +1914,73 @@ fn compress_command(args: CompressArgs) -> Result<(), Box<dyn std::error::Error>
         info!("Verification successful");
     }
 
-    fs::write(&args.output, &out)?;
-    info!("Wrote {} bytes to {:?}", out.len(), args.output);
-    Ok(())
+    if args.dry_run {
+        let arity_block_counts = if matches!((args.engine, args.format), (EngineKind::Brute, FormatKind::V1))
+        {
+            Some(v1_arity_block_counts(&out)?)
+        } else {
+            None
+        };
+        let delta_bytes = out.len() as i64 - input_data.len() as i64;
+        let delta_pct = if input_data.is_empty() {
+            0.0
+        } else {
+            delta_bytes as f64 / input_data.len() as f64 * 100.0
+        };
+        let report = DryRunReport {
+            output_path: output_path.to_path_buf(),
+            input_bytes: input_data.len(),
+            output_bytes: out.len(),
+            delta_bytes,
+            delta_pct,
+            passes,
+            arity_block_counts,
+        };
+        if args.json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print_dry_run_report(&report);
+        }
+        return Ok((input_data.len(), out.len(), stats_result));
+    }
+
+    write_fsynced(output_path, &out)?;
+    if args.rm {
+        fs::remove_file(input_path)?;
+    }
+    Ok((input_data.len(), out.len(), stats_result))
 }
 
-fn decompress_command(args: DecompressArgs) -> Result<(), Box<dyn std::error::Error>> {
-    if args.output.exists() && !args.force {
-        return Err(format!(
-            "Output file {:?} exists (use --force to overwrite)",
-            args.output
-        )
-        .into());
+/// Write `data` to `path` atomically: the bytes land in a temp file next to
+/// `path` (same directory, so the rename below can't cross filesystems),
+/// fsynced, then renamed into place. An interrupted run leaves the temp file
+/// behind instead of a truncated `path`, and callers can safely delete the
+/// input only after this returns successfully.
+fn write_fsynced(path: &std::path::Path, data: &[u8]) -> std::io::Result<()> {
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => std::path::Path::new("."),
+    };
+    let file_name = path.file_name().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "output path has no file name")
+    })?;
+    let tmp_path = dir.join(format!(".{}.tmp{}", file_name.to_string_lossy(), std::process::id()));
+
+    let write_result = (|| -> std::io::Result<()> {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(data)?;
+        file.sync_all()
+    })();
+
+    if let Err(err) = write_result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err);
     }
+
+    fs::rename(&tmp_path, path)
+}
+
+fn decompress_command(args: DecompressArgs) -> Result<(), Box<dyn std::error::Error>> {
     let ext = args
         .input
         .extension()
@@ -605,6 +1994,12 @@ fn decompress_command(args: DecompressArgs) -> Result<(), Box<dyn std::error::Er
         .into());
     }
 
+    let output = match args.output.clone() {
+        Some(output) => output,
+        None => args.input.with_extension(""),
+    };
+    prepare_overwrite(&output, args.force, args.no_clobber, args.backup.as_deref())?;
+
     let input_data = fs::read(&args.input)?;
     let _hasher_override: HasherKind = args.hasher.into();
     let memory_limit_bytes = parse_memory_limit(&args.memory_limit)?;
@@ -623,8 +2018,11 @@ fn decompress_command(args: DecompressArgs) -> Result<(), Box<dyn std::error::Er
         }
     })?;
 
-    fs::write(&args.output, &out)?;
-    info!("Wrote decompressed data to {:?}", args.output);
+    write_fsynced(&output, &out)?;
+    if args.rm {
+        fs::remove_file(&args.input)?;
+    }
+    info!("Wrote decompressed data to {:?}", output);
     Ok(())
 }
 
@@ -653,6 +2051,472 @@ fn index_command(args: IndexArgs) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn table_command(args: TableArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let file_config = load_file_config()?;
+    let resolve_table_path = |explicit: Option<PathBuf>| {
+        explicit
+            .or_else(|| file_config.table_path.clone())
+            .unwrap_or_else(|| PathBuf::from("hash_table.bin"))
+    };
+
+    match args.command {
+        TableCommand::Build(args) => {
+            let output = resolve_table_path(args.output);
+            let entries = telomere::hash_table::build_hash_table(args.max_seed_len)?;
+            telomere::hash_table::write_hash_table(&entries, &output)?;
+            info!("Wrote {} hash table entries to {:?}", entries.len(), output);
+        }
+        TableCommand::Dump(args) => {
+            let path = resolve_table_path(args.path);
+            let entries = telomere::hash_table::read_hash_table(&path)?;
+            let matches = telomere::hash_table::dump_hash_table(&entries, args.min_bits, args.max_bits);
+            for entry in &matches {
+                let len = entry.seed_len as usize;
+                println!(
+                    "{:02x}{:02x}{:02x}  {}  {}  {}",
+                    entry.hash_prefix[0],
+                    entry.hash_prefix[1],
+                    entry.hash_prefix[2],
+                    entry.seed_len,
+                    hex::encode(&entry.seed[..len]),
+                    telomere::hash_table::seed_bit_length(&entry.seed[..len])
+                );
+            }
+            println!("Total matching seeds: {}", matches.len());
+        }
+        TableCommand::Find(args) => {
+            let path = resolve_table_path(args.path);
+            let input_bytes = if args.input == "-" {
+                let mut buf = String::new();
+                std::io::stdin().read_to_string(&mut buf)?;
+                hex::decode(buf.trim())?
+            } else {
+                let candidate = PathBuf::from(&args.input);
+                if candidate.exists() {
+                    fs::read(&candidate)?
+                } else {
+                    hex::decode(args.input.trim())?
+                }
+            };
+
+            let entries = telomere::hash_table::read_hash_table(&path)?;
+            let (prefix, matches) = telomere::hash_table::find_hash_table(&entries, &input_bytes);
+            let prefix_hex = hex::encode(prefix);
+            for entry in &matches {
+                let len = entry.seed_len as usize;
+                println!(
+                    "{prefix_hex}  {}  {}  {}",
+                    entry.seed_len,
+                    hex::encode(&entry.seed[..len]),
+                    telomere::hash_table::seed_bit_length(&entry.seed[..len])
+                );
+            }
+            println!(
+                "Total matching seeds for prefix {prefix_hex}: {}",
+                matches.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn analyze_command(args: AnalyzeArgs) -> Result<(), Box<dyn std::error::Error>> {
+    if args.min_bits > args.max_bits {
+        return Err("--min-bits must be <= --max-bits".into());
+    }
+
+    let file_config = load_file_config()?;
+    let table_path = args
+        .table
+        .or_else(|| file_config.table_path.clone())
+        .unwrap_or_else(|| PathBuf::from("hash_table.bin"));
+
+    let input_data = fs::read(&args.input)?;
+    let entries = telomere::hash_table::read_hash_table(&table_path)?;
+    let (records, summary) = telomere::classify_blocks(
+        &input_data,
+        args.block_size,
+        &entries,
+        args.min_bits,
+        args.max_bits,
+    );
+
+    if !args.summary {
+        for record in &records {
+            println!("block {}: {}", record.index, record.category);
+        }
+    }
+
+    if let Some(csv_path) = &args.csv {
+        telomere::write_records_csv(&records, csv_path)?;
+    }
+    if let Some(json_path) = &args.json {
+        telomere::write_records_json(&records, json_path)?;
+    }
+
+    if args.heatmap_csv.is_some() || args.heatmap_json.is_some() {
+        let windows = telomere::compute_compressibility_windows(
+            &input_data,
+            args.block_size,
+            args.heatmap_window,
+            &entries,
+            args.min_bits,
+            args.max_bits,
+        );
+        if let Some(csv_path) = &args.heatmap_csv {
+            telomere::write_windows_csv(&windows, csv_path)?;
+        }
+        if let Some(json_path) = &args.heatmap_json {
+            telomere::write_windows_json(&windows, json_path)?;
+        }
+    }
+
+    summary.print_summary();
+    Ok(())
+}
+
+/// One test vector's round-trip outcome in a [`SelftestReport`].
+#[derive(Debug, Clone, Serialize)]
+struct SelftestCase {
+    name: &'static str,
+    block_size: usize,
+    passed: bool,
+    detail: Option<String>,
+}
+
+/// What `telomere selftest` reports: round-trip pass/fail per test vector
+/// plus whether GPU and a hash table are available, so packagers can tell
+/// "installed and working" from "installed but unusable" at a glance.
+#[derive(Debug, Clone, Serialize)]
+struct SelftestReport {
+    cases: Vec<SelftestCase>,
+    gpu_feature_compiled: bool,
+    table_path: PathBuf,
+    table_available: bool,
+    all_passed: bool,
+}
+
+fn selftest_pseudo_random_bytes(len: usize) -> Vec<u8> {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"telomere-selftest-pseudo-random");
+    let mut buf = vec![0u8; len];
+    hasher.finalize_xof().fill(&mut buf);
+    buf
+}
+
+/// Compress then decompress `data` under `config`, failing if the round trip
+/// doesn't reproduce the input exactly.
+fn selftest_roundtrip(data: &[u8], config: &Config) -> Result<(), String> {
+    let compressed =
+        telomere::compress_with_config(data, config).map_err(|e| format!("compress: {e}"))?;
+    let decompressed =
+        telomere::decompress(&compressed, config).map_err(|e| format!("decompress: {e}"))?;
+    if decompressed != data {
+        return Err("round-trip output did not match input".into());
+    }
+    Ok(())
+}
+
+/// Run built-in round-trips (zeros, pseudo-random, alternating, and
+/// seed-expandable data) across a few block sizes against the brute/v1
+/// engine, and report whether the `gpu` feature was compiled in and whether
+/// a hash table is available at `args.table`.
+fn selftest_command(args: SelftestArgs) -> Result<(), Box<dyn std::error::Error>> {
+    const BLOCK_SIZES: [usize; 2] = [2, 4];
+
+    let mut cases = Vec::new();
+    for &block_size in &BLOCK_SIZES {
+        let config = Config {
+            block_size,
+            max_seed_len: 1,
+            ..Default::default()
+        };
+        let seed_expandable = {
+            let mut buf = vec![0u8; block_size];
+            config.get_expander().expand_into(&[0x01], &mut buf);
+            buf
+        };
+        let vectors: [(&'static str, Vec<u8>); 4] = [
+            ("zeros", vec![0u8; block_size * 8]),
+            (
+                "alternating",
+                (0..block_size * 8)
+                    .map(|i| if i % 2 == 0 { 0xAA } else { 0x55 })
+                    .collect(),
+            ),
+            ("pseudo-random", selftest_pseudo_random_bytes(block_size * 8)),
+            ("seed-expandable", seed_expandable),
+        ];
+        for (name, data) in vectors {
+            let result = selftest_roundtrip(&data, &config);
+            cases.push(SelftestCase {
+                name,
+                block_size,
+                passed: result.is_ok(),
+                detail: result.err(),
+            });
+        }
+    }
+
+    let table_path = args.table.unwrap_or_else(|| PathBuf::from("hash_table.bin"));
+    let table_available = table_path.exists();
+    let all_passed = cases.iter().all(|c| c.passed);
+
+    let report = SelftestReport {
+        cases,
+        gpu_feature_compiled: cfg!(feature = "gpu"),
+        table_path,
+        table_available,
+        all_passed,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for case in &report.cases {
+            let status = if case.passed { "ok" } else { "FAIL" };
+            println!("[{status}] block_size={} {}", case.block_size, case.name);
+            if let Some(detail) = &case.detail {
+                println!("    {detail}");
+            }
+        }
+        println!("gpu feature compiled: {}", report.gpu_feature_compiled);
+        println!(
+            "hash table available ({:?}): {}",
+            report.table_path, report.table_available
+        );
+    }
+
+    if !report.all_passed {
+        std::process::exit(EXIT_MISMATCH);
+    }
+    Ok(())
+}
+
+/// Severity of a single [`DoctorCheck`]: `Fail` is a real problem, `Warn` is
+/// an optional feature that's simply unconfigured (a missing hash table is
+/// normal — brute-force search works without one).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+enum DoctorStatus {
+    Ok,
+    Warn,
+    Fail,
+}
+
+impl std::fmt::Display for DoctorStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DoctorStatus::Ok => "ok",
+            DoctorStatus::Warn => "warn",
+            DoctorStatus::Fail => "fail",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DoctorCheck {
+    name: &'static str,
+    status: DoctorStatus,
+    detail: String,
+    hint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DoctorReport {
+    checks: Vec<DoctorCheck>,
+    any_failed: bool,
+}
+
+/// Check the hash table at `table_path`: missing is a [`DoctorStatus::Warn`]
+/// (brute-force search doesn't require one), present-but-unreadable or
+/// out-of-order entries are a [`DoctorStatus::Fail`].
+fn doctor_check_table(table_path: &std::path::Path) -> DoctorCheck {
+    if !table_path.exists() {
+        return DoctorCheck {
+            name: "hash table",
+            status: DoctorStatus::Warn,
+            detail: format!("no hash table at {table_path:?}"),
+            hint: Some("run `telomere table build` if you want precomputed-table lookups".into()),
+        };
+    }
+    match telomere::hash_table::read_hash_table(table_path) {
+        Ok(entries) => {
+            let sorted = entries.windows(2).all(|w| w[0].hash_prefix <= w[1].hash_prefix);
+            if sorted {
+                DoctorCheck {
+                    name: "hash table",
+                    status: DoctorStatus::Ok,
+                    detail: format!("{} entries at {table_path:?}, sorted", entries.len()),
+                    hint: None,
+                }
+            } else {
+                DoctorCheck {
+                    name: "hash table",
+                    status: DoctorStatus::Fail,
+                    detail: format!("{} entries at {table_path:?}, NOT sorted by hash_prefix", entries.len()),
+                    hint: Some("rebuild it with `telomere table build`; lookups assume ascending order".into()),
+                }
+            }
+        }
+        Err(e) => DoctorCheck {
+            name: "hash table",
+            status: DoctorStatus::Fail,
+            detail: format!("{table_path:?} exists but failed to read: {e}"),
+            hint: Some(telomere::cli_hint(&e)),
+        },
+    }
+}
+
+/// Check whether the `gpu` feature was compiled in. It's research-only and
+/// simulated on CPU regardless (see `src/gpu.rs`), so this only ever reports
+/// a [`DoctorStatus::Warn`] when absent, never a [`DoctorStatus::Fail`].
+fn doctor_check_gpu() -> DoctorCheck {
+    if cfg!(feature = "gpu") {
+        DoctorCheck {
+            name: "gpu feature",
+            status: DoctorStatus::Ok,
+            detail: "compiled (research/simulated backend, not a real OpenCL path)".into(),
+            hint: None,
+        }
+    } else {
+        DoctorCheck {
+            name: "gpu feature",
+            status: DoctorStatus::Warn,
+            detail: "not compiled".into(),
+            hint: Some("rebuild with `--features gpu` to enable `--gpu`; still simulated on CPU".into()),
+        }
+    }
+}
+
+/// Check that `dir` exists (creating it if missing) and is actually
+/// writable, by round-tripping a small probe file.
+fn doctor_check_cache_dir(dir: &std::path::Path) -> DoctorCheck {
+    if let Err(e) = fs::create_dir_all(dir) {
+        return DoctorCheck {
+            name: "cache directory",
+            status: DoctorStatus::Fail,
+            detail: telomere::format_io_error("creating", dir, &e),
+            hint: Some("pass --cache-dir to point at a writable location".into()),
+        };
+    }
+    let probe = dir.join(format!(".doctor-probe-{}", std::process::id()));
+    match fs::write(&probe, b"probe") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            DoctorCheck {
+                name: "cache directory",
+                status: DoctorStatus::Ok,
+                detail: format!("{dir:?} is writable"),
+                hint: None,
+            }
+        }
+        Err(e) => DoctorCheck {
+            name: "cache directory",
+            status: DoctorStatus::Fail,
+            detail: telomere::format_io_error("writing to", dir, &e),
+            hint: Some("pass --cache-dir to point at a writable location".into()),
+        },
+    }
+}
+
+/// Check available system memory against a conservative floor: below it,
+/// `--max-memory-bytes`-bounded runs and the unbounded default both risk
+/// thrashing or OOM on anything but a toy input.
+fn doctor_check_memory() -> DoctorCheck {
+    use sysinfo::{System, SystemExt};
+
+    const MIN_RECOMMENDED_BYTES: u64 = 512 * 1024 * 1024;
+    let mut sys = System::new();
+    sys.refresh_memory();
+    let available = sys.available_memory();
+    if available >= MIN_RECOMMENDED_BYTES {
+        DoctorCheck {
+            name: "available memory",
+            status: DoctorStatus::Ok,
+            detail: format!("{} MB available", available / 1024 / 1024),
+            hint: None,
+        }
+    } else {
+        DoctorCheck {
+            name: "available memory",
+            status: DoctorStatus::Warn,
+            detail: format!("only {} MB available", available / 1024 / 1024),
+            hint: Some("close other programs or pass --max-memory-bytes to cap candidate cache growth".into()),
+        }
+    }
+}
+
+fn doctor_command(args: DoctorArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let file_config = load_file_config()?;
+    let table_path = args
+        .table
+        .or_else(|| file_config.table_path.clone())
+        .unwrap_or_else(|| PathBuf::from("hash_table.bin"));
+    let cache_dir = args
+        .cache_dir
+        .unwrap_or_else(|| std::env::temp_dir().join("telomere-spill"));
+
+    let checks = vec![
+        doctor_check_table(&table_path),
+        doctor_check_gpu(),
+        doctor_check_cache_dir(&cache_dir),
+        doctor_check_memory(),
+    ];
+    let any_failed = checks.iter().any(|c| c.status == DoctorStatus::Fail);
+    let report = DoctorReport { checks, any_failed };
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for check in &report.checks {
+            println!("[{}] {}: {}", check.status, check.name, check.detail);
+            if let Some(hint) = &check.hint {
+                println!("    hint: {hint}");
+            }
+        }
+    }
+
+    if report.any_failed {
+        std::process::exit(EXIT_MISMATCH);
+    }
+    Ok(())
+}
+
+fn profile_command(args: ProfileArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config {
+        block_size: args.block_size,
+        max_seed_len: args.seed_depth,
+        ..Default::default()
+    };
+    config.validate()?;
+
+    let input_data = fs::read(&args.input)?;
+    let mut timings: Vec<telomere::PhaseTimings> = Vec::new();
+    let (out, _) = telomere::compress_multi_pass_with_config_and_profile(
+        &input_data,
+        &config,
+        args.passes as usize,
+        false,
+        None,
+        None,
+        None,
+        Some(&mut timings),
+    )?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&timings)?);
+    } else {
+        for t in &timings {
+            println!(
+                "pass {}: block_split={}ms seed_search={}ms pruning={}ms emit={}ms hashing={}ms",
+                t.pass, t.block_split_ms, t.seed_search_ms, t.pruning_ms, t.emit_ms, t.hashing_ms
+            );
+        }
+        println!("{} -> {} bytes", input_data.len(), out.len());
+    }
+    Ok(())
+}
+
 fn emit_summary(summary: &RunSummary, json: bool) {
     if json {
         println!("{}", summary.to_json());
@@ -728,6 +2592,55 @@ fn truncate_selected_spans(value: &mut serde_json::Value, limit: usize) {
     );
 }
 
+/// What `--dry-run` reports instead of writing `output_path`.
+#[derive(Debug, Clone, Serialize)]
+struct DryRunReport {
+    output_path: PathBuf,
+    input_bytes: usize,
+    output_bytes: usize,
+    delta_bytes: i64,
+    delta_pct: f64,
+    passes: u32,
+    /// Block counts keyed by `"literal"` or `"arity_N"`, from the v1 record
+    /// list. `None` for v2 formats, which bundle by span length rather than
+    /// block-count arity.
+    arity_block_counts: Option<std::collections::BTreeMap<String, usize>>,
+}
+
+fn print_dry_run_report(report: &DryRunReport) {
+    println!("Dry run: would write {:?}", report.output_path);
+    println!(
+        "  {} -> {} bytes ({:+.2}%), {} pass(es)",
+        report.input_bytes, report.output_bytes, report.delta_pct, report.passes
+    );
+    match &report.arity_block_counts {
+        Some(counts) => {
+            println!("  block counts:");
+            for (kind, count) in counts {
+                println!("    {kind}: {count}");
+            }
+        }
+        None => println!("  block counts: n/a (v2 formats bundle by span length, not arity)"),
+    }
+}
+
+/// Tally v1 record kinds in an already-compressed `.tlmr` v1 file, keyed by
+/// `"literal"` or `"arity_N"`, for [`DryRunReport`].
+fn v1_arity_block_counts(out: &[u8]) -> Result<std::collections::BTreeMap<String, usize>, TelomereError> {
+    let (header, payload_start) = decode_tlmr_header_with_len(out)?;
+    let records = inspect_v1_records(&header, &out[payload_start..])?;
+    let mut counts = std::collections::BTreeMap::new();
+    for record in &records {
+        let key = if record.is_literal {
+            "literal".to_string()
+        } else {
+            format!("arity_{}", record.arity)
+        };
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    Ok(counts)
+}
+
 fn one_pass_summary(original_bytes: usize, final_bytes: usize, started: Instant) -> RunSummary {
     RunSummary::new(
         original_bytes,
@@ -768,6 +2681,32 @@ fn enforce_target_table_memory_limit(
     Ok(())
 }
 
+/// Parse an absolute byte-size string ("4GB", "512MB", "1024"). Unlike
+/// [`parse_memory_limit`], percentages don't apply here since there's no
+/// "percent of disk" equivalent to total system RAM.
+fn parse_byte_size(s: &str) -> Result<u64, Box<dyn std::error::Error>> {
+    let s = s.trim().to_uppercase();
+    let mut mul = 1.0;
+    let num_str;
+    if s.ends_with("GB") {
+        mul = 1e9;
+        num_str = s.trim_end_matches("GB");
+    } else if s.ends_with("MB") {
+        mul = 1e6;
+        num_str = s.trim_end_matches("MB");
+    } else if s.ends_with("KB") {
+        mul = 1e3;
+        num_str = s.trim_end_matches("KB");
+    } else {
+        num_str = &s;
+    }
+    let val = num_str.parse::<f64>()?;
+    if val <= 0.0 {
+        return Err("byte size must be greater than zero".into());
+    }
+    Ok((val * mul) as u64)
+}
+
 fn parse_memory_limit(s: &str) -> Result<usize, Box<dyn std::error::Error>> {
     use sysinfo::{System, SystemExt};
 
@@ -802,3 +2741,45 @@ fn parse_memory_limit(s: &str) -> Result<usize, Box<dyn std::error::Error>> {
         Ok((val * mul) as usize)
     }
 }
+
+/// Team-shared defaults loaded from `telomere.toml`, overridden by any
+/// explicit CLI flag. See [`load_file_config`] for the search order.
+#[derive(Debug, Default, serde::Deserialize)]
+struct TelomereFileConfig {
+    block_size: Option<usize>,
+    seed_depth: Option<usize>,
+    passes: Option<u32>,
+    table_path: Option<PathBuf>,
+    /// Not read: `--gpu` is only set on the command line. Accepted so a
+    /// shared `telomere.toml` doesn't fail to parse on teams running the
+    /// `gpu` feature build.
+    #[allow(dead_code)]
+    gpu: Option<bool>,
+}
+
+/// Load `telomere.toml`, checked first in the current directory, then in
+/// the XDG config directory (`$XDG_CONFIG_HOME/telomere.toml`, falling back
+/// to `~/.config/telomere.toml`). Returns the default (empty) config if no
+/// file is found.
+fn load_file_config() -> Result<TelomereFileConfig, Box<dyn std::error::Error>> {
+    let candidates = [Some(PathBuf::from("telomere.toml")), xdg_config_path()];
+    for path in candidates.into_iter().flatten() {
+        if path.is_file() {
+            let text = fs::read_to_string(&path)?;
+            let config: TelomereFileConfig =
+                toml::from_str(&text).map_err(|e| format!("failed to parse {path:?}: {e}"))?;
+            info!("Loaded config from {:?}", path);
+            return Ok(config);
+        }
+    }
+    Ok(TelomereFileConfig::default())
+}
+
+fn xdg_config_path() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(dir).join("telomere.toml"));
+    }
+    std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".config").join("telomere.toml"))
+}
@@ -0,0 +1,129 @@
+//! Optional process-wide counters, gated behind the `metrics` feature.
+//!
+//! This does not open a socket or pull in a metrics crate: [`global`] hands
+//! back a process-wide [`Metrics`], and [`Metrics::write_textfile`] renders
+//! it in Prometheus text exposition format so a long-lived embedder can drop
+//! it where node_exporter's textfile collector (or any other scraper that
+//! reads files) picks it up.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+/// Highest arity tracked individually; matches above this fold into the last
+/// bucket rather than growing the array per `Config::max_arity`.
+const ARITY_BUCKETS: usize = 8;
+
+#[derive(Default)]
+pub struct Metrics {
+    pub blocks_processed: AtomicU64,
+    pub seed_probes: AtomicU64,
+    matches_by_arity: [AtomicU64; ARITY_BUCKETS],
+    pub bytes_in: AtomicU64,
+    pub bytes_out: AtomicU64,
+    pub gpu_matches: AtomicU64,
+}
+
+static GLOBAL: OnceLock<Metrics> = OnceLock::new();
+
+/// The process-wide metrics registry, created on first use.
+pub fn global() -> &'static Metrics {
+    GLOBAL.get_or_init(Metrics::default)
+}
+
+impl Metrics {
+    pub fn record_block(&self) {
+        self.blocks_processed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_seed_probe(&self) {
+        self.seed_probes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_match(&self, arity: usize) {
+        let bucket = arity.saturating_sub(1).min(ARITY_BUCKETS - 1);
+        self.matches_by_arity[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_gpu_match(&self) {
+        self.gpu_matches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn add_bytes(&self, bytes_in: u64, bytes_out: u64) {
+        self.bytes_in.fetch_add(bytes_in, Ordering::Relaxed);
+        self.bytes_out.fetch_add(bytes_out, Ordering::Relaxed);
+    }
+
+    /// Render current counters in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# TYPE telomere_blocks_processed_total counter\n");
+        out.push_str(&format!(
+            "telomere_blocks_processed_total {}\n",
+            self.blocks_processed.load(Ordering::Relaxed)
+        ));
+        out.push_str("# TYPE telomere_seed_probes_total counter\n");
+        out.push_str(&format!(
+            "telomere_seed_probes_total {}\n",
+            self.seed_probes.load(Ordering::Relaxed)
+        ));
+        out.push_str("# TYPE telomere_matches_total counter\n");
+        for (i, bucket) in self.matches_by_arity.iter().enumerate() {
+            let arity_label = if i + 1 == ARITY_BUCKETS {
+                format!("{}+", ARITY_BUCKETS)
+            } else {
+                (i + 1).to_string()
+            };
+            out.push_str(&format!(
+                "telomere_matches_total{{arity=\"{arity_label}\"}} {}\n",
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        out.push_str("# TYPE telomere_bytes_in_total counter\n");
+        out.push_str(&format!(
+            "telomere_bytes_in_total {}\n",
+            self.bytes_in.load(Ordering::Relaxed)
+        ));
+        out.push_str("# TYPE telomere_bytes_out_total counter\n");
+        out.push_str(&format!(
+            "telomere_bytes_out_total {}\n",
+            self.bytes_out.load(Ordering::Relaxed)
+        ));
+        out.push_str("# TYPE telomere_gpu_matches_total counter\n");
+        out.push_str(&format!(
+            "telomere_gpu_matches_total {}\n",
+            self.gpu_matches.load(Ordering::Relaxed)
+        ));
+        out
+    }
+
+    /// Write [`render`] to `path`, for node_exporter's textfile collector.
+    /// Embedders that want a pull endpoint instead can serve [`render`]
+    /// themselves; this module deliberately stays transport-agnostic.
+    pub fn write_textfile(&self, path: &std::path::Path) -> std::io::Result<()> {
+        std::fs::write(path, self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_includes_all_counters() {
+        let m = Metrics::default();
+        m.record_block();
+        m.record_seed_probe();
+        m.record_match(2);
+        m.record_match(99);
+        m.record_gpu_match();
+        m.add_bytes(10, 4);
+        let rendered = m.render();
+        assert!(rendered.contains("telomere_blocks_processed_total 1"));
+        assert!(rendered.contains("telomere_seed_probes_total 1"));
+        assert!(rendered.contains("arity=\"2\"} 1"));
+        assert!(rendered.contains("arity=\"8+\"} 1"));
+        assert!(rendered.contains("telomere_bytes_in_total 10"));
+        assert!(rendered.contains("telomere_bytes_out_total 4"));
+        assert!(rendered.contains("telomere_gpu_matches_total 1"));
+    }
+}
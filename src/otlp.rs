@@ -0,0 +1,36 @@
+//! OTLP trace export, behind the `otlp` feature.
+//!
+//! Exports the same spans [`trace-spans`](crate) emits (`compress_pass`,
+//! `seed_search`, `bundle_one_layer`, `prune_end_of_pass`, ...) to an OTLP
+//! collector over HTTP/protobuf via [`tracing_opentelemetry`], so a run
+//! embedded in a data pipeline shows up in whatever observability stack
+//! already ingests traces. `trace-spans` should be enabled alongside this
+//! feature for per-phase granularity; without it only `tracing`'s default
+//! command-level spans are exported.
+
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::Tracer;
+
+use crate::error::TelomereError;
+
+/// Build an OTLP tracer exporting spans to `endpoint`
+/// (e.g. `http://localhost:4318/v1/traces`) over HTTP/protobuf.
+pub fn init_tracer(endpoint: &str) -> Result<Tracer, TelomereError> {
+    let exporter = opentelemetry_otlp::new_exporter()
+        .http()
+        .with_endpoint(endpoint);
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .with_trace_config(opentelemetry_sdk::trace::config().with_resource(
+            opentelemetry_sdk::Resource::new(vec![opentelemetry::KeyValue::new(
+                "service.name",
+                "telomere",
+            )]),
+        ))
+        .install_simple()
+        .map_err(|e| TelomereError::Config(format!("OTLP exporter setup failed: {e}")))?;
+
+    Ok(tracer)
+}
@@ -0,0 +1,187 @@
+//! Stable extension points for seed search, header encoding, and seed
+//! expansion, so a downstream crate can plug in an experimental strategy
+//! (a smarter search heuristic, an alternate header bit layout, a new
+//! hasher) by registering it, without forking the compressor to do it.
+//!
+//! The defaults registered by [`global_registry`] — brute-force
+//! [`find_seed_match`](crate::find_seed_match), the canonical Lotus header
+//! codec ([`crate::header`]), and [`Blake3Expander`]/[`Sha256Expander`] —
+//! remain the consensus-stable path; nothing here changes what
+//! `compress`/`decompress` do unless a caller explicitly looks a plugin up
+//! and uses it.
+
+use crate::hasher::{Blake3Expander, SeedExpander, Sha256Expander};
+use crate::header::{decode_lotus_header, encode_lotus_header_bytes, DecodedHeader};
+use crate::{find_seed_match, TelomereError};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// A strategy for finding a seed whose expansion reproduces a byte span.
+pub trait SeedSource: Send + Sync {
+    fn find_match(
+        &self,
+        slice: &[u8],
+        max_seed_len: usize,
+        expander: &dyn SeedExpander,
+    ) -> Result<Option<usize>, TelomereError>;
+}
+
+/// A strategy for encoding/decoding the `(arity, seed_index)` record header.
+pub trait HeaderCodec: Send + Sync {
+    fn encode(&self, arity: usize, seed_index: u64) -> Result<Vec<u8>, TelomereError>;
+
+    /// Returns the decoded header and the number of bits consumed.
+    fn decode(&self, data: &[u8]) -> Result<(DecodedHeader, usize), TelomereError>;
+}
+
+/// Alias for the existing seed-expansion trait, named to match the other
+/// plugin kinds in this module.
+pub use crate::hasher::SeedExpander as Expander;
+
+struct BruteForceSeedSource;
+
+impl SeedSource for BruteForceSeedSource {
+    fn find_match(
+        &self,
+        slice: &[u8],
+        max_seed_len: usize,
+        expander: &dyn SeedExpander,
+    ) -> Result<Option<usize>, TelomereError> {
+        find_seed_match(slice, max_seed_len, expander)
+    }
+}
+
+struct LotusHeaderCodec;
+
+impl HeaderCodec for LotusHeaderCodec {
+    fn encode(&self, arity: usize, seed_index: u64) -> Result<Vec<u8>, TelomereError> {
+        let (bytes, _bit_len) = encode_lotus_header_bytes(arity, seed_index)?;
+        Ok(bytes)
+    }
+
+    fn decode(&self, data: &[u8]) -> Result<(DecodedHeader, usize), TelomereError> {
+        decode_lotus_header(data)
+    }
+}
+
+/// Named, thread-safe lookup of registered plugins. Registering a name that
+/// already exists replaces it — there is no versioning beyond "last
+/// registration wins", matching how most of this crate's other
+/// caller-supplied hooks (e.g. [`crate::hasher::SeedExpander`] impls) work.
+#[derive(Default)]
+pub struct PluginRegistry {
+    seed_sources: RwLock<HashMap<String, Arc<dyn SeedSource>>>,
+    header_codecs: RwLock<HashMap<String, Arc<dyn HeaderCodec>>>,
+    expanders: RwLock<HashMap<String, Arc<dyn Expander>>>,
+}
+
+impl PluginRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register_seed_source(&self, name: impl Into<String>, source: Arc<dyn SeedSource>) {
+        self.seed_sources
+            .write()
+            .expect("seed_sources lock poisoned")
+            .insert(name.into(), source);
+    }
+
+    pub fn seed_source(&self, name: &str) -> Option<Arc<dyn SeedSource>> {
+        self.seed_sources
+            .read()
+            .expect("seed_sources lock poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    pub fn register_header_codec(&self, name: impl Into<String>, codec: Arc<dyn HeaderCodec>) {
+        self.header_codecs
+            .write()
+            .expect("header_codecs lock poisoned")
+            .insert(name.into(), codec);
+    }
+
+    pub fn header_codec(&self, name: &str) -> Option<Arc<dyn HeaderCodec>> {
+        self.header_codecs
+            .read()
+            .expect("header_codecs lock poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    pub fn register_expander(&self, name: impl Into<String>, expander: Arc<dyn Expander>) {
+        self.expanders
+            .write()
+            .expect("expanders lock poisoned")
+            .insert(name.into(), expander);
+    }
+
+    pub fn expander(&self, name: &str) -> Option<Arc<dyn Expander>> {
+        self.expanders
+            .read()
+            .expect("expanders lock poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    fn with_defaults() -> Self {
+        let registry = Self::new();
+        registry.register_seed_source("brute-force", Arc::new(BruteForceSeedSource));
+        registry.register_header_codec("lotus", Arc::new(LotusHeaderCodec));
+        registry.register_expander("blake3", Arc::new(Blake3Expander));
+        registry.register_expander("sha256", Arc::new(Sha256Expander));
+        registry
+    }
+}
+
+/// The process-wide registry, pre-populated with the built-in defaults.
+pub fn global_registry() -> &'static PluginRegistry {
+    static REGISTRY: OnceLock<PluginRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(PluginRegistry::with_defaults)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_registry_has_built_ins() {
+        let registry = global_registry();
+        assert!(registry.seed_source("brute-force").is_some());
+        assert!(registry.header_codec("lotus").is_some());
+        assert!(registry.expander("blake3").is_some());
+        assert!(registry.expander("sha256").is_some());
+        assert!(registry.seed_source("nonexistent").is_none());
+    }
+
+    #[test]
+    fn custom_seed_source_can_be_registered_and_used() {
+        struct AlwaysMiss;
+        impl SeedSource for AlwaysMiss {
+            fn find_match(
+                &self,
+                _slice: &[u8],
+                _max_seed_len: usize,
+                _expander: &dyn SeedExpander,
+            ) -> Result<Option<usize>, TelomereError> {
+                Ok(None)
+            }
+        }
+
+        let registry = PluginRegistry::new();
+        registry.register_seed_source("always-miss", Arc::new(AlwaysMiss));
+        let source = registry.seed_source("always-miss").unwrap();
+        let result = source.find_match(b"abcd", 1, &Blake3Expander).unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn lotus_header_codec_round_trips() {
+        let codec = LotusHeaderCodec;
+        let encoded = codec.encode(3, 42).unwrap();
+        let (decoded, _) = codec.decode(&encoded).unwrap();
+        assert_eq!(decoded.arity, 3);
+        assert_eq!(decoded.seed_index, 42);
+    }
+}
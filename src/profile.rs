@@ -0,0 +1,46 @@
+//! Per-pass phase timing breakdown for `telomere profile`.
+//!
+//! Instruments the same stages every pass of the brute/v1 loop goes
+//! through, so a regression can be localized to "seed search got slower"
+//! instead of "the whole compress got slower" without reaching for an
+//! external profiler.
+
+use serde::Serialize;
+
+/// Wall-clock time spent in each named stage of one compression pass.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PhaseTimings {
+    pub pass: usize,
+    pub block_split_ms: u64,
+    pub seed_search_ms: u64,
+    pub pruning_ms: u64,
+    pub emit_ms: u64,
+    pub hashing_ms: u64,
+}
+
+/// Running sum of [`PhaseTimings`] across every pass of a run, gated behind
+/// the `phase-stats` feature (see
+/// [`crate::compress_stats::CompressionStats::log_phase_timings`]) so a
+/// caller can read where total wall time went without reaching for
+/// `telomere profile`'s per-pass report or an external profiler.
+#[cfg(feature = "phase-stats")]
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct PhaseTimingTotals {
+    pub block_split_ms: u64,
+    pub seed_search_ms: u64,
+    pub pruning_ms: u64,
+    pub emit_ms: u64,
+    pub hashing_ms: u64,
+}
+
+#[cfg(feature = "phase-stats")]
+impl PhaseTimingTotals {
+    /// Fold one pass's [`PhaseTimings`] into the running totals.
+    pub fn add(&mut self, timings: &PhaseTimings) {
+        self.block_split_ms += timings.block_split_ms;
+        self.seed_search_ms += timings.seed_search_ms;
+        self.pruning_ms += timings.pruning_ms;
+        self.emit_ms += timings.emit_ms;
+        self.hashing_ms += timings.hashing_ms;
+    }
+}
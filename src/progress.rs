@@ -0,0 +1,29 @@
+//! Progress events emitted by the multi-pass compression engines.
+//!
+//! These are consumed by CLI plumbing (`--progress-json`) so batch wrappers
+//! and CI dashboards can render live progress without scraping the indicatif
+//! bar or waiting for the final telemetry blob to be written.
+use serde::Serialize;
+
+/// One JSON-lines-friendly progress event, emitted around each pass of a
+/// multi-pass compression run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent {
+    PassStart {
+        pass: usize,
+    },
+    PassEnd {
+        pass: usize,
+        bytes_in: usize,
+        payload_bytes: usize,
+        selected_count: usize,
+        gain_bytes: i64,
+        duration_ms: u64,
+    },
+}
+
+/// Callback signature accepted by the engines for progress reporting.
+/// Borrowed for the duration of a single compression call; engines invoke it
+/// synchronously and never store it.
+pub type ProgressSink<'a> = &'a dyn Fn(ProgressEvent);
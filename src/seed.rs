@@ -7,7 +7,9 @@
 //! `find_first` preserves enumeration order so results are deterministic
 //! regardless of thread scheduling.
 
+use crate::hash_table::TruncHashTable;
 use crate::hasher::SeedExpander;
+use crate::sha_cache::ShaCache;
 use crate::TelomereError;
 use rayon::prelude::*;
 
@@ -18,16 +20,76 @@ pub fn find_seed_match(
     max_seed_len: usize,
     expander: &dyn SeedExpander,
 ) -> Result<Option<usize>, TelomereError> {
-    if slice.is_empty() || max_seed_len == 0 {
+    let (found, _scanned) = find_seed_match_with_scan_count(slice, max_seed_len, expander)?;
+    Ok(found)
+}
+
+/// Like [`find_seed_match`], but skips the search entirely when `prefilter`
+/// proves `slice` can't be in the table the prefilter was built from.
+///
+/// Not currently called from `compress.rs`'s real search loop — that calls
+/// [`find_seed_match_with_scan_count_and_cache`] directly. [`TruncHashTable`]
+/// indexes seeds by the prefix of their own `SHA256` digest (see
+/// [`crate::hash_table::find_hash_table`]), which is the lookup the `table
+/// find` CLI command needs; it doesn't test the actual match condition
+/// `expander.expand_into(seed, ..) == slice` for an arbitrary configured
+/// `SeedExpander`, so wiring it into the general compress hot path as a
+/// fast-reject would silently skip findable matches rather than only
+/// skipping provable non-matches. Kept (and exercised by its own tests)
+/// for direct callers that specifically want a SHA256-keyed prefilter.
+#[allow(dead_code)]
+pub fn find_seed_match_with_prefilter(
+    slice: &[u8],
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+    prefilter: &TruncHashTable,
+) -> Result<Option<usize>, TelomereError> {
+    if !prefilter.contains(slice) {
         return Ok(None);
     }
+    find_seed_match(slice, max_seed_len, expander)
+}
+
+/// Like [`find_seed_match`], but also returns how many seeds were tried to
+/// reach the result, so callers can attribute a backend's seeds-per-match
+/// ratio (see [`crate::compress_stats::CompressionStats::log_seeds_scanned`]).
+///
+/// Every length bucket strictly shorter than the one the match (or, on a
+/// miss, `max_seed_len`) lives in is counted in full, since brute force
+/// always tries shortest-first; the matching bucket is counted in full too,
+/// since `find_first`'s parallel early exit does not expose how many
+/// candidates a given worker actually visited before the winning one — this
+/// reports the upper bound of what could have been tried rather than a
+/// work-stealing-dependent exact count.
+pub fn find_seed_match_with_scan_count(
+    slice: &[u8],
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+) -> Result<(Option<usize>, usize), TelomereError> {
+    find_seed_match_with_scan_count_and_cache(slice, max_seed_len, expander, None)
+}
+
+/// Like [`find_seed_match_with_scan_count`], but memoizes seed expansions in
+/// `cache` (when given) so the same candidate seed — common across nearby
+/// blocks, since the search always starts from the shortest length bucket —
+/// is only ever hashed once. See [`crate::sha_cache::ShaCache`]'s doc comment.
+pub fn find_seed_match_with_scan_count_and_cache(
+    slice: &[u8],
+    max_seed_len: usize,
+    expander: &dyn SeedExpander,
+    cache: Option<&ShaCache>,
+) -> Result<(Option<usize>, usize), TelomereError> {
+    if slice.is_empty() || max_seed_len == 0 {
+        return Ok((None, 0));
+    }
 
-    let target_bits = slice.len() * 8;
     let mut global_offset: usize = 0;
+    let mut scanned: usize = 0;
 
     for len in 1..=max_seed_len {
         let count = 1usize << (8 * len);
         let offset = global_offset;
+        scanned += count;
 
         // Parallel search within this length bucket.
         // find_first returns the lowest local_idx that satisfies the predicate,
@@ -39,25 +101,30 @@ pub fn find_seed_match(
                 seed[i] = (v & 0xFF) as u8;
                 v >>= 8;
             }
-            // Fast reject: check prefix bits before full expansion.
-            if !expander.prefix_matches(&seed, slice, target_bits) {
-                return false;
+
+            if let Some(cache) = cache {
+                if let Some(expanded) = cache.get(&seed) {
+                    return expanded == slice;
+                }
             }
-            // Verify exact match (prefix_matches may have trailing-byte false positives
-            // only when bits%8 != 0, but we double-check for safety).
+
             let mut expanded = vec![0u8; slice.len()];
             expander.expand_into(&seed, &mut expanded);
-            expanded == slice
+            let is_match = expanded == slice;
+            if let Some(cache) = cache {
+                cache.insert(seed, expanded);
+            }
+            is_match
         });
 
         if let Some(local_idx) = found {
-            return Ok(Some(offset + local_idx));
+            return Ok((Some(offset + local_idx), scanned));
         }
 
         global_offset += count;
     }
 
-    Ok(None)
+    Ok((None, scanned))
 }
 
 #[cfg(test)]
@@ -126,4 +193,55 @@ mod tests {
         let r2 = find_seed_match(&target, 1, &expander).unwrap();
         assert_eq!(r1, r2, "parallel search must be deterministic");
     }
+
+    #[test]
+    fn scan_count_covers_every_bucket_up_to_and_including_the_match() {
+        // max_seed_len=2 means the 1-byte bucket (256 seeds) is exhausted
+        // before the 2-byte bucket is even tried, so a 1-byte match should
+        // report exactly 256 scanned regardless of max_seed_len.
+        let expander = Blake3Expander;
+        let mut target = [0u8; 1];
+        expander.expand_into(&[0x00], &mut target);
+        let (found, scanned) =
+            find_seed_match_with_scan_count(&target, 2, &expander).unwrap();
+        assert_eq!(found, Some(0));
+        assert_eq!(scanned, 256);
+    }
+
+    #[test]
+    fn scan_count_on_a_miss_sums_every_bucket_tried() {
+        // A 2-byte slice can never match a 1-byte seed's 1-byte expansion, so
+        // this exhausts the whole 1-byte bucket and finds nothing.
+        let expander = Blake3Expander;
+        let (found, scanned) =
+            find_seed_match_with_scan_count(&[0u8, 0u8], 1, &expander).unwrap();
+        assert_eq!(found, None);
+        assert_eq!(scanned, 256);
+    }
+
+    #[test]
+    fn prefilter_short_circuits_on_a_provable_miss() {
+        use crate::hash_table::{build_hash_table, TruncHashTable};
+
+        let entries = build_hash_table(1).unwrap();
+        let prefilter = TruncHashTable::build(&entries);
+        let result =
+            find_seed_match_with_prefilter(b"definitely not a stored seed", 1, &Blake3Expander, &prefilter)
+                .unwrap();
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn prefilter_falls_through_to_a_real_search_on_a_possible_hit() {
+        use crate::hash_table::{build_hash_table, TruncHashTable};
+
+        let expander = Blake3Expander;
+        let mut target = [0u8; 1];
+        expander.expand_into(&[0x00], &mut target);
+
+        let entries = build_hash_table(1).unwrap();
+        let prefilter = TruncHashTable::build(&entries);
+        let result = find_seed_match_with_prefilter(&target, 1, &expander, &prefilter).unwrap();
+        assert_eq!(result, Some(0));
+    }
 }
@@ -2,6 +2,7 @@ use crate::config::HasherKind;
 use crate::seed_index::{index_to_seed, seed_to_index};
 use crate::tlmr::MAX_SEED_LEN;
 use crate::TelomereError;
+#[cfg(feature = "native-io")]
 use memmap2::Mmap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -233,11 +234,16 @@ impl SeedLookup for SeedExpansionIndex {
     }
 }
 
+/// Memory-mapped, on-disk tier reader. Requires the `native-io` feature,
+/// since `memmap2`'s mapping call isn't available on targets like
+/// `wasm32-unknown-unknown`; use [`SeedExpansionIndex`] there instead.
+#[cfg(feature = "native-io")]
 pub struct MmapSeedExpansionIndex {
     manifest: IndexManifest,
     tiers: HashMap<usize, MmapTier>,
 }
 
+#[cfg(feature = "native-io")]
 struct MmapTier {
     mmap: Mmap,
     record_size: usize,
@@ -245,6 +251,7 @@ struct MmapTier {
     max_seed_len: usize,
 }
 
+#[cfg(feature = "native-io")]
 impl MmapSeedExpansionIndex {
     pub fn open_dir(path: &Path) -> Result<Self, TelomereError> {
         let manifest = read_manifest(path)?;
@@ -302,6 +309,7 @@ impl MmapSeedExpansionIndex {
     }
 }
 
+#[cfg(feature = "native-io")]
 impl MmapTier {
     fn key_at(&self, record_idx: usize, span_len: usize) -> &[u8] {
         let start = record_idx * self.record_size;
@@ -324,6 +332,7 @@ impl MmapTier {
     }
 }
 
+#[cfg(feature = "native-io")]
 impl SeedLookup for MmapSeedExpansionIndex {
     fn manifest(&self) -> &IndexManifest {
         &self.manifest
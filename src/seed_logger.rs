@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufReader, Write};
 use std::path::Path;
+#[cfg(feature = "native-io")]
 use sysinfo::{System, SystemExt};
 
 #[derive(Serialize, Deserialize)]
@@ -41,18 +42,21 @@ fn check_limits(
     }
 
     // ---- RAM check stays as-is ----
-    let mut sys = System::new();
+    #[cfg(feature = "native-io")]
+    {
+        let mut sys = System::new();
 
-    sys.refresh_memory();
-    let used = sys.used_memory() * 1024;
-    if used > limits.max_memory_bytes {
-        return Err(crate::TelomereError::Io(io::Error::new(
-            io::ErrorKind::Other,
-            format!(
-                "memory limit exceeded: {} > {}",
-                used, limits.max_memory_bytes
-            ),
-        )));
+        sys.refresh_memory();
+        let used = sys.used_memory();
+        if used > limits.max_memory_bytes {
+            return Err(crate::TelomereError::Io(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "memory limit exceeded: {} > {}",
+                    used, limits.max_memory_bytes
+                ),
+            )));
+        }
     }
     Ok(())
 }
@@ -114,3 +118,84 @@ pub fn log_seed_to(
     file.write_all(&bytes).map_err(crate::TelomereError::from)?;
     Ok(())
 }
+
+/// How many entries a [`SeedLogAppender`] buffers between [`check_limits`]
+/// calls. `check_limits` is the expensive half of logging a seed (a
+/// `sysinfo::System::refresh_memory()` per call when `native-io` is on), so
+/// a hot loop that logs a match per block would otherwise pay it once per
+/// block regardless of how the writes themselves are batched.
+const LIMIT_CHECK_INTERVAL: u32 = 64;
+
+/// A buffered, persistent-handle alternative to calling [`log_seed_to`] in a
+/// loop.
+///
+/// [`log_seed_to`] opens and closes `path` and, when limits are set, spins
+/// up a fresh `sysinfo::System` on every single call — fine for the
+/// occasional standalone log entry, but a real cost multiplier for a
+/// compression pass that logs one entry per matched block. `SeedLogAppender`
+/// keeps the file open behind a [`BufWriter`] for the appender's whole
+/// lifetime and only re-checks resource limits every
+/// [`LIMIT_CHECK_INTERVAL`] entries, so a run that logs thousands of matches
+/// pays a handful of opens and limit checks instead of one per entry.
+///
+/// The disk-limit check in [`check_limits`] reads `path`'s on-disk size,
+/// which does not yet include bytes still sitting in the `BufWriter` — the
+/// same approximation periodic checking already accepts for the memory
+/// check, so this is consistent rather than a new source of slack. Call
+/// [`Self::flush`] (or just drop the appender) to make sure the last
+/// buffered entries reach disk.
+pub struct SeedLogAppender {
+    writer: io::BufWriter<File>,
+    path: std::path::PathBuf,
+    limits: Option<ResourceLimits>,
+    entries_since_check: u32,
+}
+
+impl SeedLogAppender {
+    /// Open `path` for appending, creating it if needed.
+    pub fn open(path: &Path, limits: Option<ResourceLimits>) -> Result<Self, crate::TelomereError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(crate::TelomereError::from)?;
+        Ok(SeedLogAppender {
+            writer: io::BufWriter::new(file),
+            path: path.to_path_buf(),
+            limits,
+            entries_since_check: 0,
+        })
+    }
+
+    /// Buffer one seed entry, checking resource limits every
+    /// [`LIMIT_CHECK_INTERVAL`]th call.
+    pub fn log(&mut self, seed_index: u64, hash: [u8; 32]) -> Result<(), crate::TelomereError> {
+        let entry = HashEntry { seed_index, hash };
+        let bytes = bincode::serialize(&entry)
+            .map_err(|e| crate::TelomereError::Io(io::Error::new(io::ErrorKind::Other, e)))?;
+
+        if let Some(limits) = &self.limits {
+            if self.entries_since_check == 0 {
+                check_limits(limits, &self.path, bytes.len() as u64)?;
+            }
+        }
+        self.entries_since_check = (self.entries_since_check + 1) % LIMIT_CHECK_INTERVAL;
+
+        self.writer
+            .write_all(&bytes)
+            .map_err(crate::TelomereError::from)
+    }
+
+    /// Flush buffered entries to disk without closing the appender.
+    pub fn flush(&mut self) -> Result<(), crate::TelomereError> {
+        self.writer.flush().map_err(crate::TelomereError::from)
+    }
+}
+
+impl Drop for SeedLogAppender {
+    fn drop(&mut self) {
+        // Best-effort: a failure here has nowhere left to report to, and the
+        // alternative (panicking in a drop) is worse than a short log.
+        let _ = self.writer.flush();
+    }
+}
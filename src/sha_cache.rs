@@ -0,0 +1,238 @@
+//! Sharded, `O(1)`-touch LRU cache for seed-expansion results.
+//!
+//! Without a cache, [`crate::seed::find_seed_match_with_scan_count`] calls
+//! [`crate::hasher::SeedExpander::expand_into`] fresh for every candidate
+//! seed it tries; when the same short seeds come up again across nearby
+//! blocks (common — the search always starts from the shortest length
+//! bucket), memoizing the expansion avoids redoing the hash work.
+//! [`crate::seed::find_seed_match_with_scan_count_and_cache`] is the
+//! cache-aware variant, and [`crate::compress::compress_multi_pass_with_config_and_fingerprint`]
+//! builds one `ShaCache` per call and shares it across every pass and block.
+//! Sharded by seed hash, rather than one cache behind one lock, so the
+//! `rayon`-parallel search in `seed.rs` doesn't serialize all of its workers
+//! on a single mutex.
+//!
+//! [`ShaCache::save`]/[`ShaCache::load`] let a long-running workflow carry
+//! expansion results across process restarts, the same way
+//! [`crate::hash_table::write_hash_table`]/[`crate::hash_table::read_hash_table`]
+//! persist the precomputed seed table — the cache just adds a format-version
+//! field and per-shard LRU order on top of that raw-dump convention.
+
+use crate::TelomereError;
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::Mutex;
+
+const DEFAULT_SHARD_COUNT: usize = 16;
+
+/// Bumped whenever the on-disk shape saved by [`ShaCache::save`] changes in a
+/// way that breaks `bincode` compatibility with files already on disk.
+pub const SHA_CACHE_FORMAT_VERSION: u32 = 1;
+
+/// On-disk form of a [`ShaCache`]: each shard's entries, most-recently-used
+/// first, so [`ShaCache::load`] can rebuild the same LRU order it saved.
+#[derive(Serialize, Deserialize)]
+struct ShaCacheFile {
+    format_version: u32,
+    capacity_per_shard: usize,
+    shards: Vec<Vec<(Vec<u8>, Vec<u8>)>>,
+}
+
+/// A `Sync` cache mapping seed bytes to their expansion, evicting the least
+/// recently used entry per shard once that shard is full.
+pub struct ShaCache {
+    shards: Vec<Mutex<LruCache<Vec<u8>, Vec<u8>>>>,
+}
+
+impl ShaCache {
+    /// A cache with `DEFAULT_SHARD_COUNT` shards, each holding up to
+    /// `capacity_per_shard` entries.
+    pub fn new(capacity_per_shard: usize) -> Self {
+        Self::with_shards(capacity_per_shard, DEFAULT_SHARD_COUNT)
+    }
+
+    pub fn with_shards(capacity_per_shard: usize, shard_count: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity_per_shard.max(1)).expect("max(1) is nonzero");
+        let shards = (0..shard_count.max(1))
+            .map(|_| Mutex::new(LruCache::new(capacity)))
+            .collect();
+        Self { shards }
+    }
+
+    fn shard_for(&self, seed: &[u8]) -> &Mutex<LruCache<Vec<u8>, Vec<u8>>> {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        let index = (hasher.finish() as usize) % self.shards.len();
+        &self.shards[index]
+    }
+
+    /// Returns a clone of the cached expansion for `seed`, touching it as
+    /// most-recently-used, or `None` on a miss.
+    pub fn get(&self, seed: &[u8]) -> Option<Vec<u8>> {
+        self.shard_for(seed)
+            .lock()
+            .expect("ShaCache shard poisoned")
+            .get(seed)
+            .cloned()
+    }
+
+    /// Records `expansion` as the result of expanding `seed`, evicting the
+    /// shard's least-recently-used entry if it was already at capacity.
+    pub fn insert(&self, seed: Vec<u8>, expansion: Vec<u8>) {
+        self.shard_for(&seed)
+            .lock()
+            .expect("ShaCache shard poisoned")
+            .put(seed, expansion);
+    }
+
+    /// Total number of entries cached across all shards.
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().expect("ShaCache shard poisoned").len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Dumps the cache to `path` (bincode), for warm reload across process
+    /// restarts via [`ShaCache::load`]. Mirrors [`crate::snapshot::PipelineSnapshot::save`]'s
+    /// convention of a leading format-version field.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TelomereError> {
+        let shards: Vec<Vec<(Vec<u8>, Vec<u8>)>> = self
+            .shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .lock()
+                    .expect("ShaCache shard poisoned")
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect()
+            })
+            .collect();
+        let capacity_per_shard = self.shards[0]
+            .lock()
+            .expect("ShaCache shard poisoned")
+            .cap()
+            .get();
+        let file = ShaCacheFile {
+            format_version: SHA_CACHE_FORMAT_VERSION,
+            capacity_per_shard,
+            shards,
+        };
+        let bytes = bincode::serialize(&file)
+            .map_err(|e| TelomereError::Internal(format!("sha cache: {e}")))?;
+        std::fs::write(path, bytes).map_err(TelomereError::Io)
+    }
+
+    /// Rebuilds a [`ShaCache`] previously written by [`ShaCache::save`],
+    /// restoring the same shard count, per-shard capacity, and LRU order.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TelomereError> {
+        let bytes = std::fs::read(path).map_err(TelomereError::Io)?;
+        let file: ShaCacheFile = bincode::deserialize(&bytes)
+            .map_err(|e| TelomereError::Internal(format!("sha cache: {e}")))?;
+        if file.format_version != SHA_CACHE_FORMAT_VERSION {
+            return Err(TelomereError::Internal(format!(
+                "sha cache format version {} unsupported (expected {})",
+                file.format_version, SHA_CACHE_FORMAT_VERSION
+            )));
+        }
+        let capacity = NonZeroUsize::new(file.capacity_per_shard.max(1)).expect("max(1) is nonzero");
+        let shards = file
+            .shards
+            .into_iter()
+            .map(|entries| {
+                let mut cache = LruCache::new(capacity);
+                // Saved most-recent-first; insert oldest-first so `put`'s
+                // own MRU bump leaves the final order matching the original.
+                for (k, v) in entries.into_iter().rev() {
+                    cache.put(k, v);
+                }
+                Mutex::new(cache)
+            })
+            .collect();
+        Ok(Self { shards })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn is_send_and_sync() {
+        assert_send_sync::<ShaCache>();
+    }
+
+    #[test]
+    fn round_trips_an_entry() {
+        let cache = ShaCache::new(4);
+        assert_eq!(cache.get(b"seed"), None);
+        cache.insert(b"seed".to_vec(), b"expansion".to_vec());
+        assert_eq!(cache.get(b"seed"), Some(b"expansion".to_vec()));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_within_a_shard() {
+        // One shard makes eviction order deterministic and observable.
+        let cache = ShaCache::with_shards(2, 1);
+        cache.insert(vec![1], vec![0x11]);
+        cache.insert(vec![2], vec![0x22]);
+        // Touch [1] so [2] becomes the least-recently-used entry.
+        assert_eq!(cache.get(&[1]), Some(vec![0x11]));
+        cache.insert(vec![3], vec![0x33]);
+        assert_eq!(cache.get(&[2]), None, "least-recently-used entry should be evicted");
+        assert_eq!(cache.get(&[1]), Some(vec![0x11]));
+        assert_eq!(cache.get(&[3]), Some(vec![0x33]));
+    }
+
+    #[test]
+    fn round_trips_through_save_and_load() {
+        let cache = ShaCache::with_shards(4, 2);
+        cache.insert(vec![1], vec![0x11]);
+        cache.insert(vec![2], vec![0x22]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sha_cache.bin");
+        cache.save(&path).unwrap();
+
+        let loaded = ShaCache::load(&path).unwrap();
+        assert_eq!(loaded.get(&[1]), Some(vec![0x11]));
+        assert_eq!(loaded.get(&[2]), Some(vec![0x22]));
+        assert_eq!(loaded.len(), 2);
+    }
+
+    #[test]
+    fn rejects_mismatched_format_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sha_cache.bin");
+        let file = ShaCacheFile {
+            format_version: SHA_CACHE_FORMAT_VERSION + 1,
+            capacity_per_shard: 4,
+            shards: vec![vec![]],
+        };
+        std::fs::write(&path, bincode::serialize(&file).unwrap()).unwrap();
+        assert!(ShaCache::load(&path).is_err());
+    }
+
+    #[test]
+    fn distinct_seeds_do_not_collide_across_shards() {
+        let cache = ShaCache::new(8);
+        for i in 0u8..64 {
+            cache.insert(vec![i], vec![i, i]);
+        }
+        for i in 0u8..64 {
+            assert_eq!(cache.get(&[i]), Some(vec![i, i]));
+        }
+        assert_eq!(cache.len(), 64);
+    }
+}
@@ -0,0 +1,94 @@
+//! Full pipeline state snapshot, for checkpointing, debugging tools, and
+//! the compare/inspect commands that want more than the pass-loop-only
+//! state [`crate::checkpoint::IndexedCheckpoint`]/[`crate::checkpoint::StreamingCheckpoint`]
+//! track: the block table, superposition lattice, and run stats as they
+//! stood at the moment of the snapshot, alongside the config that produced
+//! them.
+//!
+//! Bincode, the same on-disk convention [`crate::block::BlockStore::save`]
+//! and the checkpoint types use.
+
+use crate::block::BlockStore;
+use crate::compress_stats::RunSummary;
+use crate::superposition::SuperpositionManager;
+use crate::{Config, TelomereError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Bumped whenever [`PipelineSnapshot`]'s fields change shape in a way that
+/// breaks `bincode` compatibility with snapshots already on disk.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Point-in-time dump of an in-flight compression run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineSnapshot {
+    pub format_version: u32,
+    /// The config active when the snapshot was taken.
+    pub config: Config,
+    /// Current stream contents (the pass loop's working buffer).
+    pub stream: Vec<u8>,
+    /// Block-table state, for the non-superposition compression path.
+    pub block_store: Option<BlockStore>,
+    /// Superposition lattice state, for the superposition compression path.
+    pub superposition: Option<SuperpositionManager>,
+    /// Run stats accumulated so far.
+    pub stats: Option<RunSummary>,
+}
+
+impl PipelineSnapshot {
+    pub fn new(config: Config, stream: Vec<u8>) -> Self {
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            config,
+            stream,
+            block_store: None,
+            superposition: None,
+            stats: None,
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TelomereError> {
+        let bytes = bincode::serialize(self)
+            .map_err(|e| TelomereError::Header(format!("pipeline snapshot: {e}")))?;
+        std::fs::write(path, bytes).map_err(TelomereError::Io)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TelomereError> {
+        let bytes = std::fs::read(path).map_err(TelomereError::Io)?;
+        let snapshot: Self = bincode::deserialize(&bytes)
+            .map_err(|e| TelomereError::Header(format!("pipeline snapshot: {e}")))?;
+        if snapshot.format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(TelomereError::Header(format!(
+                "pipeline snapshot format version {} unsupported (expected {})",
+                snapshot.format_version, SNAPSHOT_FORMAT_VERSION
+            )));
+        }
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_bincode() {
+        let snapshot = PipelineSnapshot::new(Config::default(), vec![1, 2, 3]);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.bin");
+        snapshot.save(&path).unwrap();
+        let loaded = PipelineSnapshot::load(&path).unwrap();
+        assert_eq!(loaded.stream, vec![1, 2, 3]);
+        assert_eq!(loaded.format_version, SNAPSHOT_FORMAT_VERSION);
+    }
+
+    #[test]
+    fn rejects_mismatched_format_version() {
+        let mut snapshot = PipelineSnapshot::new(Config::default(), vec![]);
+        snapshot.format_version = SNAPSHOT_FORMAT_VERSION + 1;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.bin");
+        snapshot.save(&path).unwrap();
+        assert!(PipelineSnapshot::load(&path).is_err());
+    }
+}
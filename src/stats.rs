@@ -33,9 +33,12 @@ impl Stats {
     }
 
     pub fn report(&self) {
-        eprintln!(
+        tracing::info!(
             "Processed {} blocks, matches: greedy {}, lazy {}, matched blocks {}",
-            self.total_blocks, self.greedy_matches, self.lazy_matches, self.matched_blocks
+            self.total_blocks,
+            self.greedy_matches,
+            self.lazy_matches,
+            self.matched_blocks
         );
     }
 }
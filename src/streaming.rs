@@ -1,8 +1,8 @@
 use crate::config::HasherKind;
 use crate::indexed::{
     encode_fixed_span_layer_records, encode_layer_records, estimate_target_table_bytes,
-    estimate_target_table_upper_bound_for_tiers, select_weighted_candidates,
-    selected_span_telemetry, IndexedCandidate, SelectedSpanTelemetry,
+    estimate_target_table_upper_bound_for_tiers, pass_diff, select_weighted_candidates,
+    selected_span_telemetry, IndexedCandidate, PassDiff, SelectedSpanTelemetry,
 };
 use crate::public_preset::{
     public_preset_selective_framed, PublicPresetTransformStats, PUBLIC_PRESET_CODEWORD_LEN,
@@ -14,12 +14,15 @@ use crate::tlmr_v2::{
     decode_v2_header_and_descriptors, encode_v2_file, v2_fixed_seed_span_record_bit_len,
     validate_v2_search_config, validate_v2_span_step, TlmrV2LayerDescriptor, MAX_V2_SEED_LEN,
 };
+use crate::checkpoint::StreamingCheckpoint;
+use crate::progress::{ProgressEvent, ProgressSink};
 use crate::TelomereError;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use std::time::Instant;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StreamingTierTelemetry {
     pub span_len: usize,
     pub unique_spans: usize,
@@ -32,7 +35,7 @@ pub struct StreamingTierTelemetry {
     pub estimated_target_table_bytes: usize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StreamingLayerTelemetry {
     pub pass: usize,
     pub bytes_in: usize,
@@ -49,7 +52,10 @@ pub struct StreamingLayerTelemetry {
     pub tiers: Vec<StreamingTierTelemetry>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// `Deserialize` is needed so [`crate::checkpoint::StreamingCheckpoint`] can
+/// reload the aggregate telemetry accumulated before a `--resume`d run
+/// continues past it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StreamingTelemetry {
     pub candidate_count: usize,
     pub selected_count: usize,
@@ -62,6 +68,7 @@ pub struct StreamingTelemetry {
     pub selected_spans: Vec<SelectedSpanTelemetry>,
     pub tiers: Vec<StreamingTierTelemetry>,
     pub layers: Vec<StreamingLayerTelemetry>,
+    pub pass_diff: Vec<PassDiff>,
     pub final_payload_bytes: usize,
     pub container_bytes: usize,
     pub stop_reason: String,
@@ -88,6 +95,7 @@ impl StreamingTelemetry {
             selected_spans: Vec::new(),
             tiers: Vec::new(),
             layers: Vec::new(),
+            pass_diff: Vec::new(),
             final_payload_bytes: 0,
             container_bytes: 0,
             stop_reason: "not_started".into(),
@@ -225,9 +233,14 @@ pub fn compress_streaming_v2_with_telemetry(
         max_arity,
         passes,
         hash_bits,
+        None,
     )
 }
 
+/// Like [`compress_streaming_v2_with_telemetry`] plus an explicit span step,
+/// and an optional wall-clock `deadline`: once a pass finishes at or past
+/// `deadline`, the loop stops and returns the best layer stack built so far
+/// instead of starting another pass.
 #[allow(clippy::too_many_arguments)]
 pub fn compress_streaming_v2_with_span_step_and_telemetry(
     data: &[u8],
@@ -239,6 +252,7 @@ pub fn compress_streaming_v2_with_span_step_and_telemetry(
     max_arity: u8,
     passes: usize,
     hash_bits: usize,
+    deadline: Option<Instant>,
 ) -> Result<(Vec<u8>, StreamingTelemetry), TelomereError> {
     compress_streaming_v2_with_chunk_option_and_telemetry(
         data,
@@ -252,6 +266,10 @@ pub fn compress_streaming_v2_with_span_step_and_telemetry(
         hash_bits,
         None,
         None,
+        deadline,
+        None,
+        None,
+        None,
     )
 }
 
@@ -267,6 +285,7 @@ pub fn compress_streaming_v2_with_chunked_span_step_and_telemetry(
     passes: usize,
     hash_bits: usize,
     target_chunk_bytes: usize,
+    deadline: Option<Instant>,
 ) -> Result<(Vec<u8>, StreamingTelemetry), TelomereError> {
     compress_streaming_v2_with_chunk_option_and_telemetry(
         data,
@@ -280,6 +299,10 @@ pub fn compress_streaming_v2_with_chunked_span_step_and_telemetry(
         hash_bits,
         Some(target_chunk_bytes),
         None,
+        deadline,
+        None,
+        None,
+        None,
     )
 }
 
@@ -295,6 +318,7 @@ pub fn compress_streaming_v2_with_seed_limit_and_telemetry(
     passes: usize,
     hash_bits: usize,
     target_chunk_bytes: Option<usize>,
+    deadline: Option<Instant>,
 ) -> Result<(Vec<u8>, StreamingTelemetry), TelomereError> {
     let max_seed_len = max_seed_len_for_seed_limit(seed_limit)?;
     compress_streaming_v2_with_chunk_option_and_telemetry(
@@ -309,6 +333,105 @@ pub fn compress_streaming_v2_with_seed_limit_and_telemetry(
         hash_bits,
         target_chunk_bytes,
         Some(seed_limit),
+        deadline,
+        None,
+        None,
+        None,
+    )
+}
+
+/// Like [`compress_streaming_v2_with_seed_limit_and_telemetry`] plus an
+/// optional `progress` sink: when set, it is called once with
+/// [`ProgressEvent::PassStart`] before each pass and once with
+/// [`ProgressEvent::PassEnd`] after each pass that is kept, so CLI wrappers
+/// can stream live progress instead of waiting for the final telemetry blob.
+/// A later pass that is discarded for not shrinking its input gets a
+/// `PassStart` but no `PassEnd`.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_streaming_v2_with_progress_and_telemetry(
+    data: &[u8],
+    hasher: HasherKind,
+    max_seed_len: usize,
+    max_span_len: usize,
+    block_size: usize,
+    span_step: usize,
+    max_arity: u8,
+    passes: usize,
+    hash_bits: usize,
+    target_chunk_bytes: Option<usize>,
+    seed_limit: Option<usize>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
+) -> Result<(Vec<u8>, StreamingTelemetry), TelomereError> {
+    let max_seed_len = if let Some(seed_limit) = seed_limit {
+        max_seed_len_for_seed_limit(seed_limit)?
+    } else {
+        max_seed_len
+    };
+    compress_streaming_v2_with_chunk_option_and_telemetry(
+        data,
+        hasher,
+        max_seed_len,
+        max_span_len,
+        block_size,
+        span_step,
+        max_arity,
+        passes,
+        hash_bits,
+        target_chunk_bytes,
+        seed_limit,
+        deadline,
+        progress,
+        None,
+        None,
+    )
+}
+
+/// Like [`compress_streaming_v2_with_progress_and_telemetry`] plus an
+/// optional `checkpoint_path`/`resume` pair: when `checkpoint_path` is set,
+/// the pass loop's state is snapshotted there after every completed pass;
+/// when `resume` is set (typically loaded from that same path via
+/// [`StreamingCheckpoint::load`]), the run continues from `resume.next_pass`
+/// instead of starting over at pass 1.
+#[allow(clippy::too_many_arguments)]
+pub fn compress_streaming_v2_with_checkpoint_and_telemetry(
+    data: &[u8],
+    hasher: HasherKind,
+    max_seed_len: usize,
+    max_span_len: usize,
+    block_size: usize,
+    span_step: usize,
+    max_arity: u8,
+    passes: usize,
+    hash_bits: usize,
+    target_chunk_bytes: Option<usize>,
+    seed_limit: Option<usize>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
+    checkpoint_path: Option<&Path>,
+    resume: Option<StreamingCheckpoint>,
+) -> Result<(Vec<u8>, StreamingTelemetry), TelomereError> {
+    let max_seed_len = if let Some(seed_limit) = seed_limit {
+        max_seed_len_for_seed_limit(seed_limit)?
+    } else {
+        max_seed_len
+    };
+    compress_streaming_v2_with_chunk_option_and_telemetry(
+        data,
+        hasher,
+        max_seed_len,
+        max_span_len,
+        block_size,
+        span_step,
+        max_arity,
+        passes,
+        hash_bits,
+        target_chunk_bytes,
+        seed_limit,
+        deadline,
+        progress,
+        checkpoint_path,
+        resume,
     )
 }
 
@@ -325,6 +448,10 @@ fn compress_streaming_v2_with_chunk_option_and_telemetry(
     hash_bits: usize,
     target_chunk_bytes: Option<usize>,
     seed_limit: Option<usize>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
+    checkpoint_path: Option<&Path>,
+    resume: Option<StreamingCheckpoint>,
 ) -> Result<(Vec<u8>, StreamingTelemetry), TelomereError> {
     validate_streaming_config(
         max_seed_len,
@@ -337,13 +464,27 @@ fn compress_streaming_v2_with_chunk_option_and_telemetry(
     )?;
     validate_seed_limit(max_seed_len, seed_limit)?;
 
-    let mut current = data.to_vec();
-    let mut layers_inner_to_outer = Vec::new();
-    let mut aggregate = StreamingTelemetry::empty(max_seed_len);
-    aggregate.seed_limit = seed_limit;
-    aggregate.stop_reason = "max_passes".into();
+    let (mut current, mut layers_inner_to_outer, mut aggregate, start_pass) =
+        if let Some(checkpoint) = resume {
+            (
+                checkpoint.current,
+                checkpoint.layers_inner_to_outer,
+                checkpoint.telemetry,
+                checkpoint.next_pass,
+            )
+        } else {
+            let mut aggregate = StreamingTelemetry::empty(max_seed_len);
+            aggregate.seed_limit = seed_limit;
+            aggregate.stop_reason = "max_passes".into();
+            (data.to_vec(), Vec::new(), aggregate, 0)
+        };
 
-    for pass_idx in 0..passes {
+    for pass_idx in start_pass..passes {
+        if let Some(progress) = progress {
+            progress(ProgressEvent::PassStart {
+                pass: pass_idx + 1,
+            });
+        }
         let started = Instant::now();
         let (payload, mut telemetry) = encode_streaming_layer(
             pass_idx + 1,
@@ -363,6 +504,17 @@ fn compress_streaming_v2_with_chunk_option_and_telemetry(
             break;
         }
 
+        if let Some(progress) = progress {
+            progress(ProgressEvent::PassEnd {
+                pass: telemetry.pass,
+                bytes_in: telemetry.bytes_in,
+                payload_bytes: telemetry.payload_bytes,
+                selected_count: telemetry.selected_count,
+                gain_bytes: telemetry.bytes_in as i64 - telemetry.payload_bytes as i64,
+                duration_ms: telemetry.duration_ms,
+            });
+        }
+
         merge_telemetry(&mut aggregate, &telemetry);
         aggregate.layers.push(telemetry);
         let descriptor = if let Some(fixed_span_len) =
@@ -389,9 +541,36 @@ fn compress_streaming_v2_with_chunk_option_and_telemetry(
         };
         layers_inner_to_outer.push(descriptor);
         current = payload;
+
+        if let Some(checkpoint_path) = checkpoint_path {
+            StreamingCheckpoint {
+                next_pass: pass_idx + 1,
+                current: current.clone(),
+                layers_inner_to_outer: layers_inner_to_outer.clone(),
+                telemetry: aggregate.clone(),
+            }
+            .save(checkpoint_path)?;
+        }
+
+        if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+            aggregate.stop_reason = "max_seconds".into();
+            break;
+        }
     }
 
     aggregate.final_payload_bytes = current.len();
+    aggregate.pass_diff = aggregate
+        .layers
+        .iter()
+        .map(|layer| {
+            pass_diff(
+                layer.pass,
+                layer.selected_count,
+                layer.bundle_count,
+                layer.literal_bytes,
+            )
+        })
+        .collect();
     let mut layers = layers_inner_to_outer;
     layers.reverse();
     let encoded = encode_v2_file(hasher, hash_bits, data.len() as u64, &layers, &current)?;
@@ -412,6 +591,7 @@ pub fn compress_streaming_v2_with_public_preset_selective_and_telemetry(
     hash_bits: usize,
     target_chunk_bytes: Option<usize>,
     seed_limit: Option<usize>,
+    deadline: Option<Instant>,
 ) -> Result<(Vec<u8>, PublicPresetStreamingTelemetry), TelomereError> {
     compress_streaming_v2_with_public_preset_selective_config_and_telemetry(
         data,
@@ -427,6 +607,8 @@ pub fn compress_streaming_v2_with_public_preset_selective_and_telemetry(
         seed_limit,
         PUBLIC_PRESET_SELECTIVE_MIN_TOKEN_LEN,
         PUBLIC_PRESET_CODEWORD_LEN,
+        deadline,
+        None,
     )
 }
 
@@ -445,6 +627,8 @@ pub fn compress_streaming_v2_with_public_preset_selective_config_and_telemetry(
     seed_limit: Option<usize>,
     public_preset_min_token_len: usize,
     public_preset_codeword_len: usize,
+    deadline: Option<Instant>,
+    progress: Option<ProgressSink>,
 ) -> Result<(Vec<u8>, PublicPresetStreamingTelemetry), TelomereError> {
     let max_seed_len = if let Some(seed_limit) = seed_limit {
         max_seed_len_for_seed_limit(seed_limit)?
@@ -469,6 +653,10 @@ pub fn compress_streaming_v2_with_public_preset_selective_config_and_telemetry(
         hash_bits,
         target_chunk_bytes,
         seed_limit,
+        deadline,
+        progress,
+        None,
+        None,
     )?;
     let (_header, mut layers, payload_start) = decode_v2_header_and_descriptors(&inner_file)?;
     layers.push(
@@ -854,6 +1042,7 @@ fn scan_streaming_tiers(
             .take(tier_count)
             .collect(),
         layers: Vec::new(),
+        pass_diff: Vec::new(),
         final_payload_bytes: 0,
         container_bytes: 0,
         stop_reason: "scan_only".into(),
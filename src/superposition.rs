@@ -1,8 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 
+use crate::candidate::PrunePolicy;
+use crate::tile::{load_from_disk, spill_to_disk};
 use crate::types::{Candidate, TelomereError};
 
-#[derive(Debug, Clone)]
+/// Bounded-memory mode configuration: once `superposed` holds more than
+/// `capacity` block indices, the least-recently-touched ones are written to
+/// `dir` and dropped from memory until the cap is satisfied again.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct SpillConfig {
+    capacity: usize,
+    dir: PathBuf,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 /// Manages superposed candidates across compression passes.
 ///
 /// Candidates are added freely during a pass. No pruning is performed
@@ -10,25 +22,156 @@ use crate::types::{Candidate, TelomereError};
 /// possibilities remains stable while matching logic runs.
 pub struct SuperpositionManager {
     canonical: HashMap<(usize, usize), Candidate>,
-    superposed: HashMap<usize, Vec<(char, Candidate)>>,
+    /// Per-block candidate lists, dense-indexed by block index rather than a
+    /// `HashMap<usize, _>`. Block indices are always `0..total_blocks`, so
+    /// every lookup on the hot insert/best/promote paths (called once per
+    /// block per pass) becomes a direct array index instead of a hash.
+    superposed: Vec<Vec<(u8, Candidate)>>,
+    /// Number of indices in `superposed` that currently hold at least one
+    /// candidate in memory (touched and not spilled). `superposed.len()` is
+    /// always `total_blocks` now, so this is what [`enforce_spill_cap`]
+    /// compares against the configured capacity instead.
+    resident: usize,
     /// Total number of original blocks in the stream. Used for gap checks.
     total_blocks: usize,
+    /// Delta/keep-count/tie-break policy applied by [`prune_end_of_pass`],
+    /// [`insert_superposed`] and [`collapse_superpositions`]. Shared with the
+    /// block-table path via [`crate::candidate::prune_candidates_with_policy`]
+    /// so both stay consistent from one place instead of each baking in its
+    /// own "8 bits, keep 3" constants.
+    policy: PrunePolicy,
+    /// Bounded-memory mode, if enabled via [`enable_disk_spill`].
+    spill: Option<SpillConfig>,
+    /// Block indices currently spilled to disk under `spill`.
+    spilled: HashSet<usize>,
+    /// Touch order for spill eviction, oldest at the front. Reloading a
+    /// spilled index also re-touches it.
+    touch_order: VecDeque<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InsertResult {
-    Inserted(char),
+    Inserted(u8),
     Pruned,
 }
 
+/// Where a [`FinalizedSpan`]'s winning candidate came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanSource {
+    /// A multi-block bundle from the canonical set.
+    Canonical,
+    /// A single block's best superposed candidate.
+    Superposed,
+}
+
+/// One block span chosen by [`SuperpositionManager::finalize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalizedSpan {
+    /// First block index covered by this span.
+    pub start: usize,
+    /// Number of blocks covered.
+    pub blocks: usize,
+    /// Encoded length in bits of the winning candidate.
+    pub bit_len: usize,
+    pub source: SpanSource,
+}
+
+/// Map a 0-based rank to its branch label.
+///
+/// Labels used to be restricted to the letters `A`-`Z`, which silently
+/// capped retained alternatives at 26 regardless of `PrunePolicy::keep_count`.
+/// A plain index has no such ceiling, so spec experiments that configure
+/// `keep_count` well past 3 (or past 26) still get a distinct label per
+/// branch. `rank` itself is already bounded by `keep_count`, which callers
+/// are expected to keep within `u8::MAX`; ranks beyond that saturate rather
+/// than panic.
+fn label_for_rank(rank: usize) -> u8 {
+    u8::try_from(rank).unwrap_or(u8::MAX)
+}
+
 impl SuperpositionManager {
-    /// Create a new manager for a stream with the given number of blocks.
+    /// Create a new manager for a stream with the given number of blocks,
+    /// using [`PrunePolicy::default`].
     pub fn new(total_blocks: usize) -> Self {
+        Self::with_policy(total_blocks, PrunePolicy::default())
+    }
+
+    /// Create a new manager with an explicit pruning policy.
+    pub fn with_policy(total_blocks: usize, policy: PrunePolicy) -> Self {
         SuperpositionManager {
             canonical: HashMap::new(),
-            superposed: HashMap::new(),
+            superposed: vec![Vec::new(); total_blocks],
+            resident: 0,
             total_blocks,
+            policy,
+            spill: None,
+            spilled: HashSet::new(),
+            touch_order: VecDeque::new(),
+        }
+    }
+
+    /// Replace the pruning policy used by subsequent operations.
+    pub fn set_policy(&mut self, policy: PrunePolicy) {
+        self.policy = policy;
+    }
+
+    /// Enable bounded-memory mode: once more than `capacity` block indices
+    /// are held in memory, the least-recently-touched ones are spilled to
+    /// `dir` (via the [`tile`](crate::tile) module's bincode helpers) and
+    /// reloaded on demand by [`best_superposed`], [`insert_superposed`] and
+    /// [`promote_superposed`].
+    pub fn enable_disk_spill(&mut self, capacity: usize, dir: PathBuf) {
+        self.spill = Some(SpillConfig { capacity, dir });
+    }
+
+    fn spill_path(dir: &std::path::Path, block_index: usize) -> PathBuf {
+        dir.join(format!("block_{block_index}.bin"))
+    }
+
+    /// Record that `block_index` was just accessed, for LRU spill eviction.
+    fn touch(&mut self, block_index: usize) {
+        if self.spill.is_none() {
+            return;
         }
+        self.touch_order.retain(|&i| i != block_index);
+        self.touch_order.push_back(block_index);
+    }
+
+    /// If `block_index` is currently spilled to disk, load it back into
+    /// `superposed` and clear its spilled marker.
+    fn reload_if_spilled(&mut self, block_index: usize) -> Result<(), TelomereError> {
+        if !self.spilled.remove(&block_index) {
+            return Ok(());
+        }
+        let dir = self.spill.as_ref().expect("spilled implies spill enabled").dir.clone();
+        let path = Self::spill_path(&dir, block_index);
+        let list: Vec<(u8, Candidate)> = load_from_disk(&path)?;
+        self.superposed[block_index] = list;
+        self.resident += 1;
+        let _ = std::fs::remove_file(&path);
+        Ok(())
+    }
+
+    /// Spill the least-recently-touched in-memory entries to disk until the
+    /// configured capacity is satisfied.
+    fn enforce_spill_cap(&mut self) -> Result<(), TelomereError> {
+        let Some(spill) = self.spill.clone() else {
+            return Ok(());
+        };
+        while self.resident > spill.capacity {
+            let Some(idx) = self.touch_order.pop_front() else {
+                break;
+            };
+            if self.superposed[idx].is_empty() {
+                continue;
+            }
+            let list = std::mem::take(&mut self.superposed[idx]);
+            let path = Self::spill_path(&spill.dir, idx);
+            spill_to_disk(&path, &list)?;
+            self.spilled.insert(idx);
+            self.resident -= 1;
+        }
+        Ok(())
     }
 
     /// Deprecated wrapper maintained for compatibility. Calls
@@ -37,33 +180,38 @@ impl SuperpositionManager {
         let _ = self.insert_superposed(block_index, cand);
     }
 
-    /// Finalize the state after a pass.  For each block index only the best
-    /// three candidates within an 8-bit delta of the shortest are retained and
-    /// labeled `A`, `B` and `C` respectively.  Ordering is deterministic based
-    /// on length and `seed_index` so repeated runs yield identical results.
+    /// Finalize the state after a pass. For each block index only the
+    /// `policy.keep_count` best candidates within `policy.delta_bits` of the
+    /// shortest are retained and labeled by rank `0`, `1`, `2`, ... Ordering is
+    /// deterministic based on length and `seed_index` so repeated runs yield
+    /// identical results.
     pub fn prune_end_of_pass(&mut self) {
-        for list in self.superposed.values_mut() {
+        #[cfg(feature = "trace-spans")]
+        let _span = tracing::info_span!("prune_end_of_pass", blocks = self.superposed.len()).entered();
+        let policy = self.policy;
+        for list in self.superposed.iter_mut() {
             if list.is_empty() {
                 continue;
             }
 
             list.sort_by(|a, b| {
-                a.1.bit_len
-                    .cmp(&b.1.bit_len)
-                    .then(a.1.seed_index.cmp(&b.1.seed_index))
+                let ord = a.1.bit_len.cmp(&b.1.bit_len);
+                match policy.tie_break {
+                    crate::candidate::TieBreak::SeedAscending => {
+                        ord.then(a.1.seed_index.cmp(&b.1.seed_index))
+                    }
+                    crate::candidate::TieBreak::SeedDescending => {
+                        ord.then(b.1.seed_index.cmp(&a.1.seed_index))
+                    }
+                }
             });
             let best_len = list[0].1.bit_len;
-            list.retain(|(_, c)| c.bit_len <= best_len + 8);
-            if list.len() > 3 {
-                list.truncate(3);
+            list.retain(|(_, c)| c.bit_len <= best_len.saturating_add(policy.delta_bits));
+            if list.len() > policy.keep_count {
+                list.truncate(policy.keep_count);
             }
             for (i, (label, _)) in list.iter_mut().enumerate() {
-                *label = match i {
-                    0 => 'A',
-                    1 => 'B',
-                    2 => 'C',
-                    _ => unreachable!(),
-                };
+                *label = label_for_rank(i);
             }
         }
     }
@@ -128,29 +276,38 @@ impl SuperpositionManager {
             return Err(Superposition("block index out of range".into()));
         }
 
-        let list = self.superposed.entry(block_index).or_default();
-        list.push(('?', cand.clone()));
+        self.reload_if_spilled(block_index)?;
+        self.touch(block_index);
+
+        let policy = self.policy;
+        let list = &mut self.superposed[block_index];
+        let was_empty = list.is_empty();
+        list.push((u8::MAX, cand.clone()));
+        if was_empty {
+            self.resident += 1;
+        }
 
         list.sort_by(|a, b| {
-            a.1.bit_len
-                .cmp(&b.1.bit_len)
-                .then(a.1.seed_index.cmp(&b.1.seed_index))
+            let ord = a.1.bit_len.cmp(&b.1.bit_len);
+            match policy.tie_break {
+                crate::candidate::TieBreak::SeedAscending => {
+                    ord.then(a.1.seed_index.cmp(&b.1.seed_index))
+                }
+                crate::candidate::TieBreak::SeedDescending => {
+                    ord.then(b.1.seed_index.cmp(&a.1.seed_index))
+                }
+            }
         });
 
         let best_len = list[0].1.bit_len;
-        list.retain(|(_, c)| c.bit_len <= best_len + 8);
-        if list.len() > 3 {
-            list.truncate(3);
+        list.retain(|(_, c)| c.bit_len <= best_len.saturating_add(policy.delta_bits));
+        if list.len() > policy.keep_count {
+            list.truncate(policy.keep_count);
         }
 
         let mut inserted = None;
         for (i, (label, c)) in list.iter_mut().enumerate() {
-            *label = match i {
-                0 => 'A',
-                1 => 'B',
-                2 => 'C',
-                _ => unreachable!(),
-            };
+            *label = label_for_rank(i);
             if inserted.is_none()
                 && c.seed_index == cand.seed_index
                 && c.bit_len == cand.bit_len
@@ -160,39 +317,60 @@ impl SuperpositionManager {
             }
         }
 
-        match inserted {
-            Some(label) => Ok(InsertResult::Inserted(label)),
-            None => Ok(InsertResult::Pruned),
-        }
+        let result = match inserted {
+            Some(label) => InsertResult::Inserted(label),
+            None => InsertResult::Pruned,
+        };
+
+        self.enforce_spill_cap()?;
+        Ok(result)
     }
 
     pub fn remove_superposed(&mut self, block_index: usize) {
-        self.superposed.remove(&block_index);
+        if let Some(list) = self.superposed.get_mut(block_index) {
+            if !list.is_empty() {
+                self.resident -= 1;
+            }
+            list.clear();
+        }
     }
 
     pub fn collapse_superpositions(&mut self) {
-        let keys: Vec<usize> = self.superposed.keys().copied().collect();
-        for k in keys {
-            if let Some(list) = self.superposed.get_mut(&k) {
-                if list.len() < 2 {
-                    continue;
-                }
-                if let Some(min) = list.iter().map(|(_, c)| c.bit_len).min() {
-                    list.retain(|(_, c)| c.bit_len <= min + 8);
-                }
+        let delta_bits = self.policy.delta_bits;
+        for list in self.superposed.iter_mut() {
+            if list.len() < 2 {
+                continue;
+            }
+            if let Some(min) = list.iter().map(|(_, c)| c.bit_len).min() {
+                list.retain(|(_, c)| c.bit_len <= min + delta_bits);
             }
         }
     }
 
-    pub fn promote_superposed(&mut self, block_index: usize, label: char) -> Option<Candidate> {
-        let list = self.superposed.remove(&block_index)?;
+    pub fn promote_superposed(&mut self, block_index: usize, label: u8) -> Option<Candidate> {
+        self.reload_if_spilled(block_index).ok()?;
+        let list = self.superposed.get_mut(block_index)?;
+        if list.is_empty() {
+            return None;
+        }
+        let list = std::mem::take(list);
+        self.resident -= 1;
         let winner = list.into_iter().find(|(l, _)| *l == label);
         winner.map(|(_, c)| c)
     }
 
-    pub fn best_superposed(&self, block_index: usize) -> Option<&Candidate> {
+    /// Best candidate currently superposed at `block_index`, transparently
+    /// reloading it from disk first if it was spilled under bounded-memory
+    /// mode (see [`enable_disk_spill`]).
+    pub fn best_superposed(&mut self, block_index: usize) -> Option<&Candidate> {
+        self.reload_if_spilled(block_index).ok()?;
+        self.touch(block_index);
+        // Enforcing the cap here (rather than only after inserts) means a
+        // read-only scan over many spilled blocks doesn't balloon memory by
+        // reloading them all at once without ever spilling the old ones back.
+        self.enforce_spill_cap().ok()?;
         self.superposed
-            .get(&block_index)
+            .get(block_index)
             .and_then(|v| v.iter().min_by_key(|(_, c)| c.bit_len).map(|(_, c)| c))
     }
 
@@ -200,13 +378,151 @@ impl SuperpositionManager {
         self.canonical.iter().map(|(k, v)| (*k, v)).collect()
     }
 
-    pub fn all_superposed(&self) -> Vec<(usize, Vec<(char, Candidate)>)> {
+    /// Resolve canonical bundle spans against per-block superposed picks
+    /// into one globally consistent, minimal-total-bits selection.
+    ///
+    /// Picking the cheapest candidate at each block independently (as
+    /// [`best_superposed`] does) can select a multi-block bundle and also the
+    /// individual branches of the blocks it covers, double-counting those
+    /// blocks. This runs a weighted-interval-scheduling DP over block
+    /// positions: at each position, the best choice is either the cheapest
+    /// single-block candidate there, or a canonical bundle starting there,
+    /// whichever yields the cheaper total over the remainder of the stream.
+    /// Errors if any block has no candidate at all, since that leaves a gap
+    /// no selection can cover.
+    pub fn finalize(&mut self) -> Result<Vec<FinalizedSpan>, TelomereError> {
+        use TelomereError::Superposition;
+
+        let n = self.total_blocks;
+        // Cloned (not borrowed) so the loop below is free to reload/touch
+        // spilled entries through `&mut self` via `best_superposed`.
+        let mut bundles_from: HashMap<usize, Vec<(usize, Candidate)>> = HashMap::new();
+        for (&(start, blocks), cand) in &self.canonical {
+            bundles_from
+                .entry(start)
+                .or_default()
+                .push((blocks, cand.clone()));
+        }
+
+        // best_cost[i] = minimum total bits to cover blocks [i..n).
+        let mut best_cost = vec![usize::MAX; n + 1];
+        let mut choice: Vec<Option<FinalizedSpan>> = vec![None; n + 1];
+        best_cost[n] = 0;
+
+        for i in (0..n).rev() {
+            let mut best: Option<(usize, FinalizedSpan)> = None;
+
+            if let Some(cand) = self.best_superposed(i) {
+                if best_cost[i + 1] != usize::MAX {
+                    let total = cand.bit_len + best_cost[i + 1];
+                    best = Some((
+                        total,
+                        FinalizedSpan {
+                            start: i,
+                            blocks: 1,
+                            bit_len: cand.bit_len,
+                            source: SpanSource::Superposed,
+                        },
+                    ));
+                }
+            }
+
+            if let Some(options) = bundles_from.get(&i) {
+                for &(blocks, ref cand) in options {
+                    if i + blocks > n || best_cost[i + blocks] == usize::MAX {
+                        continue;
+                    }
+                    let total = cand.bit_len + best_cost[i + blocks];
+                    let better = match &best {
+                        Some((b, _)) => total < *b,
+                        None => true,
+                    };
+                    if better {
+                        best = Some((
+                            total,
+                            FinalizedSpan {
+                                start: i,
+                                blocks,
+                                bit_len: cand.bit_len,
+                                source: SpanSource::Canonical,
+                            },
+                        ));
+                    }
+                }
+            }
+
+            if let Some((total, span)) = best {
+                best_cost[i] = total;
+                choice[i] = Some(span);
+            }
+        }
+
+        if n > 0 && best_cost[0] == usize::MAX {
+            return Err(Superposition(
+                "no consistent block selection covers the stream".into(),
+            ));
+        }
+
+        let mut result = Vec::new();
+        let mut i = 0;
+        while i < n {
+            let span = choice[i].clone().ok_or_else(|| {
+                Superposition(format!("no candidate available to cover block {i}"))
+            })?;
+            i += span.blocks;
+            result.push(span);
+        }
+        Ok(result)
+    }
+
+    pub fn all_superposed(&self) -> Vec<(usize, Vec<(u8, Candidate)>)> {
         self.superposed
             .iter()
-            .map(|(k, v)| (*k, v.clone()))
+            .enumerate()
+            .filter(|(_, v)| !v.is_empty())
+            .map(|(k, v)| (k, v.clone()))
             .collect()
     }
 
+    /// Render the canonical and superposed candidate lattice as Graphviz DOT,
+    /// with bit lengths and branch labels, so spec authors can visually debug
+    /// why a particular parse was chosen for a test vector.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        out.push_str("digraph Superposition {\n");
+        out.push_str("  rankdir=LR;\n");
+
+        let mut can: Vec<_> = self.canonical.iter().collect();
+        can.sort_by_key(|(k, _)| *k);
+        for ((start, blocks), cand) in can {
+            out.push_str(&format!(
+                "  \"canonical:{start}+{blocks}\" [label=\"{start}..{}\\n{} bits\",shape=box];\n",
+                start + blocks,
+                cand.bit_len
+            ));
+        }
+
+        // `superposed` is already dense-indexed in block order, so no sort
+        // is needed to visit blocks ascending.
+        for (idx, list) in self.superposed.iter().enumerate() {
+            if list.is_empty() {
+                continue;
+            }
+            let mut tmp = list.clone();
+            tmp.sort_by_key(|x| x.0);
+            for (label, cand) in tmp {
+                out.push_str(&format!(
+                    "  \"{idx}{label}\" [label=\"{idx}{label}\\n{} bits\\nseed {}\"];\n",
+                    cand.bit_len, cand.seed_index
+                ));
+                out.push_str(&format!("  \"block:{idx}\" -> \"{idx}{label}\";\n"));
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
     /// Dump the current state for debugging.
     pub fn debug_dump(&self) -> String {
         let mut out = String::new();
@@ -217,9 +533,10 @@ impl SuperpositionManager {
             out.push_str(&format!("  ({s},{b}) -> {:?}\n", c));
         }
         out.push_str("Superposed:\n");
-        let mut sup: Vec<_> = self.superposed.iter().collect();
-        sup.sort_by_key(|(k, _)| *k);
-        for (idx, list) in sup {
+        for (idx, list) in self.superposed.iter().enumerate() {
+            if list.is_empty() {
+                continue;
+            }
             let mut tmp = list.clone();
             tmp.sort_by_key(|x| x.0);
             for (l, c) in tmp {
@@ -229,3 +546,132 @@ impl SuperpositionManager {
         out
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candidate::{PrunePolicy, TieBreak};
+    use crate::types::CandidateOrigin;
+
+    fn candidate(bit_len: usize, seed_index: u64) -> Candidate {
+        Candidate {
+            seed_index,
+            arity: 1,
+            bit_len,
+            from_bundle: false,
+            origin: CandidateOrigin::default(),
+        }
+    }
+
+    #[test]
+    fn custom_policy_overrides_default_delta_and_keep_count() {
+        let policy = PrunePolicy {
+            delta_bits: 1,
+            keep_count: 1,
+            tie_break: TieBreak::SeedAscending,
+        };
+        let mut mgr = SuperpositionManager::with_policy(1, policy);
+        mgr.insert_superposed(0, candidate(10, 1)).unwrap();
+        mgr.insert_superposed(0, candidate(11, 2)).unwrap();
+        mgr.insert_superposed(0, candidate(12, 3)).unwrap();
+
+        let best = mgr.best_superposed(0).unwrap();
+        assert_eq!(best.bit_len, 10);
+        // keep_count = 1 means only the winner survives.
+        assert_eq!(mgr.all_superposed()[0].1.len(), 1);
+    }
+
+    #[test]
+    fn finalize_prefers_bundle_over_double_counted_singles() {
+        let mut mgr = SuperpositionManager::new(2);
+        // Individually, blocks 0 and 1 each cost 10 bits (total 20), but a
+        // bundle covering both costs only 15.
+        mgr.insert_superposed(0, candidate(10, 1)).unwrap();
+        mgr.insert_superposed(1, candidate(10, 2)).unwrap();
+        mgr.insert_candidate((0, 2), candidate(15, 3)).unwrap();
+
+        let spans = mgr.finalize().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].source, SpanSource::Canonical);
+        assert_eq!(spans[0].blocks, 2);
+        assert_eq!(spans[0].bit_len, 15);
+    }
+
+    #[test]
+    fn finalize_falls_back_to_singles_when_cheaper() {
+        let mut mgr = SuperpositionManager::new(2);
+        mgr.insert_superposed(0, candidate(4, 1)).unwrap();
+        mgr.insert_superposed(1, candidate(4, 2)).unwrap();
+        mgr.insert_candidate((0, 2), candidate(15, 3)).unwrap();
+
+        let spans = mgr.finalize().unwrap();
+        assert_eq!(spans.len(), 2);
+        assert!(spans.iter().all(|s| s.source == SpanSource::Superposed));
+    }
+
+    #[test]
+    fn disk_spill_evicts_and_transparently_reloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut mgr = SuperpositionManager::new(3);
+        mgr.enable_disk_spill(1, dir.path().to_path_buf());
+
+        mgr.insert_superposed(0, candidate(10, 1)).unwrap();
+        mgr.insert_superposed(1, candidate(11, 2)).unwrap();
+        // Capacity is 1, so block 0 (least recently touched) spills to disk.
+        assert!(mgr.spilled.contains(&0));
+        assert!(mgr.superposed[0].is_empty());
+
+        // Reading block 0 transparently reloads it and evicts block 1 instead.
+        let best = mgr.best_superposed(0).unwrap();
+        assert_eq!(best.bit_len, 10);
+        assert!(!mgr.spilled.contains(&0));
+        assert!(mgr.spilled.contains(&1));
+    }
+
+    #[test]
+    fn finalize_errors_on_uncovered_gap() {
+        let mut mgr = SuperpositionManager::new(2);
+        assert!(mgr.finalize().is_err());
+    }
+
+    #[test]
+    fn keep_count_beyond_the_old_26_letter_cap_is_supported() {
+        // Labels used to be `A`..`Z`, silently capping retained alternatives
+        // at 26 regardless of `keep_count`. Request a width past that to
+        // confirm the u8 label scheme has no such ceiling.
+        let policy = PrunePolicy {
+            delta_bits: usize::MAX,
+            keep_count: 30,
+            tie_break: TieBreak::SeedAscending,
+        };
+        let mut mgr = SuperpositionManager::with_policy(1, policy);
+        for seed in 0..30u64 {
+            mgr.insert_superposed(0, candidate(10 + seed as usize, seed))
+                .unwrap();
+        }
+        let list = &mgr.all_superposed()[0].1;
+        assert_eq!(list.len(), 30);
+        let labels: std::collections::HashSet<u8> = list.iter().map(|(l, _)| *l).collect();
+        assert_eq!(labels.len(), 30);
+        assert!(labels.contains(&29));
+    }
+
+    #[test]
+    fn five_retained_alternatives_get_five_distinct_labels() {
+        let policy = PrunePolicy {
+            delta_bits: usize::MAX,
+            keep_count: 5,
+            tie_break: TieBreak::SeedAscending,
+        };
+        let mut mgr = SuperpositionManager::with_policy(1, policy);
+        for seed in 0..5u64 {
+            mgr.insert_superposed(0, candidate(10 + seed as usize, seed))
+                .unwrap();
+        }
+        let list = &mgr.all_superposed()[0].1;
+        assert_eq!(list.len(), 5);
+        for (rank, (label, _)) in list.iter().enumerate() {
+            assert_eq!(*label, rank as u8);
+        }
+    }
+}
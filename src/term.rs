@@ -0,0 +1,45 @@
+//! Minimal ANSI color support for status/error output.
+//!
+//! Honors the [`NO_COLOR`](https://no-color.org/) convention and disables
+//! itself when stderr isn't a tty (piped to a file, captured by CI, etc.),
+//! so `--json`/scripted consumers never see escape codes mixed into text
+//! they parse.
+
+use std::io::IsTerminal;
+
+/// A foreground color for [`paint`]. Kept to the handful this crate
+/// actually needs rather than a full ANSI palette.
+#[derive(Debug, Clone, Copy)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+        }
+    }
+}
+
+/// Whether colored output should be emitted: `NO_COLOR` unset/empty and
+/// stderr attached to a terminal. Checked fresh on every call rather than
+/// cached, since tests and wrapped CLI invocations may change either
+/// condition between calls.
+pub fn color_enabled() -> bool {
+    std::env::var_os("NO_COLOR").is_none() && std::io::stderr().is_terminal()
+}
+
+/// Wrap `text` in `color`'s ANSI escape codes if [`color_enabled`], else
+/// return it unchanged.
+pub fn paint(text: &str, color: Color) -> String {
+    if color_enabled() {
+        format!("\x1b[{}m{text}\x1b[0m", color.code())
+    } else {
+        text.to_string()
+    }
+}
@@ -1,5 +1,25 @@
 use crate::block::BlockId;
 use crate::TelomereError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+
+/// Serialize `value` to `path` via bincode. Used to spill cold tiles (e.g.
+/// superposition lattice entries) to disk under memory pressure.
+pub fn spill_to_disk<T: Serialize>(
+    path: impl AsRef<Path>,
+    value: &T,
+) -> Result<(), TelomereError> {
+    let bytes =
+        bincode::serialize(value).map_err(|e| TelomereError::Internal(format!("tile spill: {e}")))?;
+    std::fs::write(path, bytes).map_err(TelomereError::Io)
+}
+
+/// Deserialize a value previously written by [`spill_to_disk`].
+pub fn load_from_disk<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T, TelomereError> {
+    let bytes = std::fs::read(path).map_err(TelomereError::Io)?;
+    bincode::deserialize(&bytes).map_err(|e| TelomereError::Internal(format!("tile load: {e}")))
+}
 
 /// A contiguous chunk of the global block table.
 ///
@@ -74,3 +94,107 @@ pub fn load_chunk(chunks: &[BlockChunk], index: usize) -> Result<BlockChunk, Tel
 pub fn flush_chunk(_chunk: BlockChunk) -> Result<(), TelomereError> {
     Ok(())
 }
+
+/// Loads tiled chunks one ahead on a background thread, so the search loop
+/// processing the current tile doesn't stall on `loader`'s latency for the
+/// next one. Works over any chunk source `loader` can reach — an in-memory
+/// slice today (see [`prefetch_from_chunks`]), or a disk-backed loader built
+/// on [`load_from_disk`] later — as long as it's safe to call from another
+/// thread. The channel buffers one chunk (capacity 1) and the loader thread
+/// can hold one more finished chunk while it waits to send it, so this
+/// trades at most two extra chunks' memory for hiding their load latency.
+///
+/// Not wired into any real search loop yet — [`crate::gpu::GpuSeedMatcher`]
+/// and the rest of the tiling machinery still pull chunks synchronously.
+/// Exercised directly by this module's own tests as a model of the
+/// background-prefetch strategy for whichever caller adopts it.
+#[allow(dead_code)]
+pub struct TilePrefetcher {
+    next_rx: std::sync::mpsc::Receiver<Result<BlockChunk, TelomereError>>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl TilePrefetcher {
+    /// Spawns a background thread that calls `loader(0)..loader(chunk_count - 1)`
+    /// in order, one ahead of what [`TilePrefetcher::next`] has returned so far.
+    #[allow(dead_code)]
+    pub fn new<F>(chunk_count: usize, loader: F) -> Self
+    where
+        F: Fn(usize) -> Result<BlockChunk, TelomereError> + Send + 'static,
+    {
+        let (tx, next_rx) = std::sync::mpsc::sync_channel(1);
+        let handle = std::thread::spawn(move || {
+            for index in 0..chunk_count {
+                if tx.send(loader(index)).is_err() {
+                    // Receiver dropped; no one is waiting for more chunks.
+                    break;
+                }
+            }
+        });
+        Self {
+            next_rx,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Iterator for TilePrefetcher {
+    type Item = Result<BlockChunk, TelomereError>;
+
+    /// Blocks until the next chunk (already loading in the background) is
+    /// ready, or returns `None` once every chunk has been delivered.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_rx.recv().ok()
+    }
+}
+
+impl Drop for TilePrefetcher {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Prefetcher over an already-split, in-memory chunk list (the
+/// [`chunk_blocks`] output), for callers that haven't moved to a
+/// disk-backed loader yet.
+#[allow(dead_code)]
+pub fn prefetch_from_chunks(chunks: Vec<BlockChunk>) -> TilePrefetcher {
+    let total = chunks.len();
+    TilePrefetcher::new(total, move |index| {
+        chunks
+            .get(index)
+            .cloned()
+            .ok_or_else(|| TelomereError::Internal("invalid chunk".into()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(id: u32) -> BlockId {
+        BlockId(id)
+    }
+
+    #[test]
+    fn prefetcher_delivers_chunks_in_order() {
+        let chunks = chunk_blocks(&[block(0), block(1), block(2), block(3)], 2);
+        let expected = chunks.clone();
+        let mut prefetcher = prefetch_from_chunks(chunks);
+
+        for expected_chunk in expected {
+            let chunk = prefetcher.next().unwrap().unwrap();
+            assert_eq!(chunk.start_index, expected_chunk.start_index);
+            assert_eq!(chunk.blocks, expected_chunk.blocks);
+        }
+        assert!(prefetcher.next().is_none());
+    }
+
+    #[test]
+    fn prefetcher_on_empty_input_yields_nothing() {
+        let mut prefetcher = prefetch_from_chunks(Vec::new());
+        assert!(prefetcher.next().is_none());
+    }
+}
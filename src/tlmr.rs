@@ -1,11 +1,12 @@
 use crate::config::HasherKind;
 use crate::hasher::SeedExpander;
-use crate::header::{LOTUS_J_BITS, LOTUS_TIERS};
+use crate::header::{decode_v1_record_from_reader, LOTUS_J_BITS, LOTUS_TIERS};
 use crate::TelomereError;
 use lotus::{
     lotus_decode_from_reader, lotus_encode_into_writer, BitReader as LotusBitReader,
     BitWriter as LotusBitWriter, LotusError,
 };
+use serde::Serialize;
 
 pub const TLMR_MAGIC: [u8; 4] = *b"TLMR";
 /// V1 format version. Bumped to 2 with the variable-length Lotus-encoded
@@ -28,7 +29,7 @@ pub const V1_MAGIC_VERSION_LEN: usize = 5;
 /// routed through the real lotus crate. `payload_bit_len` is the meaningful
 /// bit count in the records payload that follows the header — the header is
 /// byte-aligned via zero pad so the payload begins at a byte offset.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TlmrHeader {
     pub version: u8,
     pub lotus_preset: u8,
@@ -339,6 +340,73 @@ pub fn tlmr_header_byte_len(data: &[u8]) -> Result<usize, TelomereError> {
     Ok(end)
 }
 
+/// One record in a decoded v1 `.tlmr` payload, as reported by
+/// [`inspect_v1_records`]. `bit_offset`/`bit_len` are relative to the start
+/// of the records payload (i.e. excluding the header), matching
+/// [`TlmrHeader::payload_bit_len`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecordInfo {
+    pub is_literal: bool,
+    pub arity: u8,
+    pub seed_index: Option<u64>,
+    pub bit_offset: usize,
+    pub bit_len: usize,
+}
+
+/// Walk a v1 payload's records without expanding any seed, returning the
+/// kind/arity/seed index/bit span of each in order. Used by `telomere
+/// inspect` to list record structure for debugging interop issues; unlike
+/// [`crate::decompress_with_limit`] this never reconstructs the output bytes.
+pub fn inspect_v1_records(
+    header: &TlmrHeader,
+    payload: &[u8],
+) -> Result<Vec<RecordInfo>, TelomereError> {
+    let mut reader = LotusBitReader::new(payload);
+    let mut records = Vec::new();
+    let mut produced_bytes: u64 = 0;
+
+    while produced_bytes < header.original_len {
+        if reader.bits_consumed() as u64 > header.payload_bit_len {
+            return Err(TelomereError::Header("orphan/truncated bits".into()));
+        }
+        let bit_offset = reader.bits_consumed();
+        let (decoded, _) = decode_v1_record_from_reader(&mut reader)
+            .map_err(|_| TelomereError::Header("orphan/truncated bits".into()))?;
+
+        if decoded.is_literal {
+            while reader.bits_consumed() % 8 != 0 {
+                reader.read_bits(1).map_err(lotus_err)?;
+            }
+            let remaining_output = header.original_len.saturating_sub(produced_bytes);
+            let bytes = if remaining_output <= header.last_block_size as u64 {
+                remaining_output
+            } else {
+                header.block_size as u64
+            };
+            for _ in 0..bytes {
+                reader.read_bits(8).map_err(lotus_err)?;
+            }
+            produced_bytes += bytes;
+        } else {
+            let span_len = decoded.arity as u64 * header.block_size as u64;
+            produced_bytes += span_len;
+        }
+
+        records.push(RecordInfo {
+            is_literal: decoded.is_literal,
+            arity: decoded.arity,
+            seed_index: if decoded.is_literal {
+                None
+            } else {
+                Some(decoded.seed_index)
+            },
+            bit_offset,
+            bit_len: reader.bits_consumed() - bit_offset,
+        });
+    }
+    Ok(records)
+}
+
 /// Compute a low-bit truncated digest of the provided bytes using the given expander.
 pub fn truncated_hash_bits(data: &[u8], expander: &dyn SeedExpander, bits: usize) -> u64 {
     assert!(
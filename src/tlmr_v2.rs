@@ -9,6 +9,7 @@ use crate::TelomereError;
 use lotus::{
     lotus_decode_from_reader, lotus_encode_into_writer, lotus_encoded_bit_len, BitReader, BitWriter,
 };
+use serde::{Deserialize, Serialize};
 
 pub const TLMR_V2_FORMAT_VERSION: u8 = 3;
 pub const LOTUS_PRESET_V2: u8 = 2;
@@ -27,7 +28,7 @@ pub const MAX_V2_SEED_LEN: usize = 6;
 pub const V2_RECORD_TAG_SEED_SPAN: u64 = 0;
 pub const V2_RECORD_TAG_LITERAL: u64 = 1;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TlmrV2Header {
     pub version: u8,
     pub lotus_preset: u8,
@@ -43,7 +44,10 @@ pub struct TlmrV2Header {
     pub output_hash: u64,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// `Deserialize` is needed so a [`crate::checkpoint::IndexedCheckpoint`] or
+/// [`crate::checkpoint::StreamingCheckpoint`] can round-trip the layer stack
+/// accumulated so far when `--resume` reloads a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TlmrV2LayerDescriptor {
     pub decoded_len: u64,
     pub decoded_hash: u64,
@@ -680,7 +684,10 @@ pub fn decompress_v2_with_limit(
     }
     let hash = truncated_hash_bits(&current, expander.as_ref(), header.hash_bits);
     if hash != header.output_hash {
-        return Err(TelomereError::Header("v2 output hash mismatch".into()));
+        return Err(TelomereError::HashMismatch {
+            expected: header.output_hash,
+            actual: hash,
+        });
     }
     Ok(current)
 }
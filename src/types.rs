@@ -1,4 +1,13 @@
-#[derive(Debug, Clone, PartialEq)]
+/// A single encoding option for one or more blocks.
+///
+/// This is the one `Candidate` type used throughout the crate: by
+/// [`crate::superposition::SuperpositionManager`] for per-block and bundle
+/// candidates, and by [`crate::candidate::prune_candidates_with_policy`] for
+/// the block-table pruning path. The two used to be separate structs
+/// (`bits_length`/`seed`/`from_bundle` vs. `bit_len`/`seed_index`/`arity`)
+/// with duplicated pruning logic; merging them keeps both paths sharing one
+/// representation and one [`crate::candidate::PrunePolicy`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Candidate {
     /// Seed enumeration index used for this candidate.
     pub seed_index: u64,
@@ -6,6 +15,58 @@ pub struct Candidate {
     pub arity: u8,
     /// Total encoded length in bits for this candidate.
     pub bit_len: usize,
+    /// Whether this candidate originates from a bundle spanning multiple
+    /// blocks (equivalent to `arity > 1` for seed-matched candidates, but
+    /// kept explicit so literal/fallback candidates can also be tagged).
+    pub from_bundle: bool,
+    /// Where this candidate came from, for attributing compression gains to
+    /// a specific pass, engine, or search strategy when tuning. It rides
+    /// along with the candidate through pruning and is read back out by the
+    /// stats layer (see
+    /// [`crate::compress_stats::CompressionStats::log_match_with_origin`]).
+    pub origin: CandidateOrigin,
+}
+
+/// Provenance of a single [`Candidate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CandidateOrigin {
+    /// Which compression pass produced this candidate (1-based; `0` means
+    /// unknown/not tracked by the caller).
+    pub pass: u32,
+    /// Which backend ran the search.
+    pub engine: Engine,
+    /// How the seed was found.
+    pub method: MatchMethod,
+}
+
+impl Default for CandidateOrigin {
+    fn default() -> Self {
+        CandidateOrigin {
+            pass: 0,
+            engine: Engine::Cpu,
+            method: MatchMethod::BruteForce,
+        }
+    }
+}
+
+/// Which backend performed the seed search for a candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Engine {
+    /// The CPU/rayon search in `seed.rs`.
+    Cpu,
+    /// The experimental GPU pipeline (see `gpu.rs`/`hybrid.rs`).
+    Gpu,
+}
+
+/// How a candidate's seed was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MatchMethod {
+    /// Not a seed match at all; a literal fallback.
+    Literal,
+    /// Found via a precomputed seed-expansion table lookup (e.g. `seed_table.csv`).
+    TableHit,
+    /// Found by brute-force seed enumeration ([`crate::seed::find_seed_match`]).
+    BruteForce,
 }
 
 pub use crate::error::TelomereError;
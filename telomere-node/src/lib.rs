@@ -0,0 +1,93 @@
+//! Node.js bindings for the Telomere codec, so Electron/Node backup tools
+//! can call into the real engine instead of shelling out to the `telomere`
+//! CLI binaries. Mirrors `telomere-ui` (the Tauri desktop app next door):
+//! a thin binding crate over the parent `telomere` library, not a
+//! reimplementation of any codec logic.
+
+#![deny(clippy::all)]
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use telomere::{compress_with_config, decompress_with_limit, Config, TelomereError};
+
+/// Options accepted from the JS side; anything unset falls back to
+/// [`Config::default`].
+#[napi(object)]
+pub struct CodecOptions {
+    pub block_size: Option<u32>,
+    pub max_seed_len: Option<u32>,
+    pub max_arity: Option<u32>,
+    pub hash_bits: Option<u32>,
+}
+
+fn build_config(options: Option<CodecOptions>) -> Config {
+    let mut config = Config::default();
+    let Some(options) = options else {
+        return config;
+    };
+    if let Some(block_size) = options.block_size {
+        config.block_size = block_size as usize;
+    }
+    if let Some(max_seed_len) = options.max_seed_len {
+        config.max_seed_len = max_seed_len as usize;
+    }
+    if let Some(max_arity) = options.max_arity {
+        config.max_arity = max_arity as u8;
+    }
+    if let Some(hash_bits) = options.hash_bits {
+        config.hash_bits = hash_bits as usize;
+    }
+    config
+}
+
+fn to_napi_err(e: TelomereError) -> Error {
+    Error::from_reason(e.to_string())
+}
+
+#[napi]
+pub fn compress_buffer(data: Buffer, options: Option<CodecOptions>) -> Result<Buffer> {
+    let config = build_config(options);
+    config.validate().map_err(to_napi_err)?;
+    compress_with_config(&data, &config)
+        .map(Buffer::from)
+        .map_err(to_napi_err)
+}
+
+#[napi]
+pub fn decompress_buffer(data: Buffer, options: Option<CodecOptions>) -> Result<Buffer> {
+    let config = build_config(options);
+    config.validate().map_err(to_napi_err)?;
+    decompress_with_limit(&data, &config, config.memory_limit)
+        .map(Buffer::from)
+        .map_err(to_napi_err)
+}
+
+#[napi]
+pub async fn compress_buffer_async(data: Buffer, options: Option<CodecOptions>) -> Result<Buffer> {
+    let config = build_config(options);
+    config.validate().map_err(to_napi_err)?;
+    let owned = data.to_vec();
+    napi::tokio::task::spawn_blocking(move || {
+        compress_with_config(&owned, &config).map(Buffer::from)
+    })
+    .await
+    .map_err(|e| Error::from_reason(format!("compress_buffer_async panicked: {e}")))?
+    .map_err(to_napi_err)
+}
+
+#[napi]
+pub async fn decompress_buffer_async(
+    data: Buffer,
+    options: Option<CodecOptions>,
+) -> Result<Buffer> {
+    let config = build_config(options);
+    config.validate().map_err(to_napi_err)?;
+    let owned = data.to_vec();
+    let limit = config.memory_limit;
+    napi::tokio::task::spawn_blocking(move || {
+        decompress_with_limit(&owned, &config, limit).map(Buffer::from)
+    })
+    .await
+    .map_err(|e| Error::from_reason(format!("decompress_buffer_async panicked: {e}")))?
+    .map_err(to_napi_err)
+}
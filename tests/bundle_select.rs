@@ -16,10 +16,10 @@ fn accept_non_overlapping_bundles() {
             original_bits: 48,
         },
     ];
-    let accepted = select_bundles(records);
-    assert_eq!(accepted.len(), 2);
-    assert!(!accepted[0].superposed);
-    assert!(!accepted[1].superposed);
+    let report = select_bundles(records);
+    assert_eq!(report.accepted.len(), 2);
+    assert!(!report.accepted[0].superposed);
+    assert!(!report.accepted[1].superposed);
 }
 
 #[test]
@@ -44,8 +44,8 @@ fn reject_overlap_with_multiple_bundles() {
             original_bits: 48,
         },
     ];
-    let accepted = select_bundles(records);
-    assert_eq!(accepted.len(), 2);
+    let report = select_bundles(records);
+    assert_eq!(report.accepted.len(), 2);
 }
 
 #[test]
@@ -64,8 +64,8 @@ fn reject_non_subset_overlap() {
             original_bits: 72,
         },
     ];
-    let accepted = select_bundles(records);
-    assert_eq!(accepted.len(), 1);
+    let report = select_bundles(records);
+    assert_eq!(report.accepted.len(), 1);
 }
 
 #[test]
@@ -84,8 +84,8 @@ fn reject_large_bit_delta() {
             original_bits: 81,
         },
     ];
-    let accepted = select_bundles(records);
-    assert_eq!(accepted.len(), 1);
+    let report = select_bundles(records);
+    assert_eq!(report.accepted.len(), 1);
 }
 
 #[test]
@@ -104,8 +104,8 @@ fn accept_superposition_when_within_delta() {
             original_bits: 80,
         },
     ];
-    let accepted = select_bundles(records);
-    assert_eq!(accepted.len(), 2);
-    assert!(!accepted[0].superposed);
-    assert!(accepted[1].superposed);
+    let report = select_bundles(records);
+    assert_eq!(report.accepted.len(), 2);
+    assert!(!report.accepted[0].superposed);
+    assert!(report.accepted[1].superposed);
 }
@@ -165,6 +165,7 @@ fn indexed_chunked_target_tables_match_unchunked_selected_spans() {
         1,
         13,
         38,
+        None,
     )
     .unwrap();
 
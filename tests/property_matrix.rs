@@ -34,7 +34,7 @@ proptest! {
     fn superposition_minimality(bit_lens in proptest::collection::vec(8usize..64, 1..8)) {
         let mut mgr = SuperpositionManager::new(1);
         for (i, len) in bit_lens.iter().enumerate() {
-            mgr.push_unpruned(0, Candidate { seed_index: i as u64, arity: 1, bit_len: *len });
+            mgr.push_unpruned(0, Candidate {  seed_index: i as u64, arity: 1, bit_len: *len, from_bundle: false, origin: Default::default(),  });
         }
         mgr.prune_end_of_pass();
         let list = mgr.all_superposed().into_iter().find(|(i, _)| *i == 0).unwrap().1;
@@ -163,6 +163,7 @@ fn public_preset_selective_v2_is_format_native() {
         13,
         None,
         None,
+        None,
     )
     .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
@@ -199,6 +200,7 @@ fn public_preset_selective_log_tokens_win_under_full_accounting() {
         13,
         None,
         None,
+        None,
     )
     .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
@@ -232,6 +234,8 @@ fn public_preset_selective_rust_source_tokens_win_natively() {
             None,
             8,
             16,
+            None,
+            None,
         )
         .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
@@ -259,6 +263,7 @@ fn streaming_single_tier_uses_fixed_span_records() {
         5,
         1,
         13,
+        None,
     )
     .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
@@ -288,6 +293,7 @@ fn streaming_fixed_span_cost_gate_keeps_two_byte_hits() {
         5,
         1,
         13,
+        None,
     )
     .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
@@ -406,6 +412,7 @@ fn streaming_v2_chunked_target_tables_roundtrip_planted_span() {
         1,
         13,
         28,
+        None,
     )
     .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
@@ -524,6 +531,7 @@ fn streaming_span_step_one_finds_offset_span_in_first_pass() {
         2,
         1,
         13,
+        None,
     )
     .unwrap();
     let decoded = decompress_with_limit(&encoded, &Config::default(), usize::MAX).unwrap();
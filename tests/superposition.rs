@@ -12,29 +12,35 @@ fn superposed_label_promotion() {
         seed_index: 1,
         arity: 1,
         bit_len: 24,
+        from_bundle: false,
+        origin: Default::default(),
     };
     let b = Candidate {
         seed_index: 2,
         arity: 1,
         bit_len: 29,
+        from_bundle: false,
+        origin: Default::default(),
     };
     let c = Candidate {
         seed_index: 3,
         arity: 1,
         bit_len: 31,
+        from_bundle: false,
+        origin: Default::default(),
     };
 
     assert_eq!(
         mgr.insert_superposed(0, a.clone()).unwrap(),
-        InsertResult::Inserted('A')
+        InsertResult::Inserted(0)
     );
     assert_eq!(
         mgr.insert_superposed(0, b.clone()).unwrap(),
-        InsertResult::Inserted('B')
+        InsertResult::Inserted(1)
     );
     assert_eq!(
         mgr.insert_superposed(0, c.clone()).unwrap(),
-        InsertResult::Inserted('C')
+        InsertResult::Inserted(2)
     );
 
     // Insert a better candidate (bit_len < all previous)
@@ -42,13 +48,15 @@ fn superposed_label_promotion() {
         seed_index: 4,
         arity: 1,
         bit_len: 23,
+        from_bundle: false,
+        origin: Default::default(),
     };
     assert_eq!(
         mgr.insert_superposed(0, better.clone()).unwrap(),
-        InsertResult::Inserted('A')
+        InsertResult::Inserted(0)
     );
 
-    // After pruning and relabeling, there should be three candidates, best is 'A'
+    // After pruning and relabeling, there should be three candidates, best is label 0
     let list = mgr
         .all_superposed()
         .into_iter()
@@ -57,8 +65,8 @@ fn superposed_label_promotion() {
         .1;
     assert_eq!(list.len(), 3);
 
-    // The best (lowest bit_len) is 'A', must be 'better'
-    assert_eq!(list[0].0, 'A');
+    // The best (lowest bit_len) is label 0, must be 'better'
+    assert_eq!(list[0].0, 0);
     assert_eq!(list[0].1.bit_len, better.bit_len);
 
     // All candidates are within 8 bits of the best
@@ -80,6 +88,8 @@ fn superposed_prune_many() {
                 seed_index: i,
                 arity: 1,
                 bit_len: len,
+                from_bundle: false,
+                origin: Default::default(),
             },
         )
         .unwrap();
@@ -92,7 +102,7 @@ fn superposed_prune_many() {
         .unwrap()
         .1;
     assert!(list.len() <= 3);
-    assert_eq!(list[0].0, 'A');
+    assert_eq!(list[0].0, 0);
     let best = list[0].1.bit_len;
     for (_, c) in &list {
         assert!(c.bit_len - best <= 8);
@@ -114,7 +124,17 @@ proptest::proptest! {
         let original = vals.clone();
         let mut mgr1 = SuperpositionManager::new(1);
         for (len, seed) in original.iter() {
-            mgr1.insert_superposed(0, Candidate { seed_index:*seed, arity:1, bit_len:*len }).unwrap();
+            mgr1.insert_superposed(
+                0,
+                Candidate {
+                    seed_index: *seed,
+                    arity: 1,
+                    bit_len: *len,
+                    from_bundle: false,
+                    origin: Default::default(),
+                },
+            )
+            .unwrap();
         }
         mgr1.prune_end_of_pass();
         let out1 = mgr1.all_superposed();
@@ -122,7 +142,17 @@ proptest::proptest! {
         vals.shuffle(&mut rand::thread_rng());
         let mut mgr2 = SuperpositionManager::new(1);
         for (len, seed) in vals {
-            mgr2.insert_superposed(0, Candidate { seed_index:seed, arity:1, bit_len:len }).unwrap();
+            mgr2.insert_superposed(
+                0,
+                Candidate {
+                    seed_index: seed,
+                    arity: 1,
+                    bit_len: len,
+                    from_bundle: false,
+                    origin: Default::default(),
+                },
+            )
+            .unwrap();
         }
         mgr2.prune_end_of_pass();
         prop_assert_eq!(out1, mgr2.all_superposed());
@@ -136,15 +166,19 @@ fn immediate_delta_pruning() {
         seed_index: 1,
         arity: 1,
         bit_len: 16,
+        from_bundle: false,
+        origin: Default::default(),
     };
     let b = Candidate {
         seed_index: 2,
         arity: 1,
         bit_len: 40,
+        from_bundle: false,
+        origin: Default::default(),
     };
     assert_eq!(
         mgr.insert_superposed(0, a.clone()).unwrap(),
-        InsertResult::Inserted('A')
+        InsertResult::Inserted(0)
     );
     assert_eq!(
         mgr.insert_superposed(0, b.clone()).unwrap(),
@@ -169,6 +203,8 @@ fn gap_free_coverage_enforced() {
             seed_index: 1,
             arity: 3,
             bit_len: 24,
+            from_bundle: false,
+            origin: Default::default(),
         },
     )
     .unwrap();
@@ -178,7 +214,9 @@ fn gap_free_coverage_enforced() {
             Candidate {
                 seed_index: 2,
                 arity: 2,
-                bit_len: 16
+                bit_len: 16,
+                from_bundle: false,
+                origin: Default::default(),
             }
         )
         .is_err());
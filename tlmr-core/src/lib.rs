@@ -0,0 +1,147 @@
+//! `no_std` + `alloc` seed-expansion core, extracted from `telomere`'s
+//! `hasher` module so enclave/embedded decoders can expand a `.tlmr` seed
+//! into its output bytes without linking the full std-based crate.
+//!
+//! This is the first slice of a no_std core, not the whole decoder: the
+//! header codec (`telomere::header`) and the strict record decoder
+//! (`telomere::decompress_with_limit`) still route through `lotus` and
+//! `std::collections::HashMap`, and haven't been confirmed no_std-clean or
+//! ported here yet. Track that as follow-up work; this crate only covers
+//! the hash-expansion step, which has no such dependency.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use blake3::Hasher as Blake3Hasher;
+use sha2::{Digest, Sha256};
+
+/// Mirrors `telomere::hasher::SeedExpander`. Kept as a separate trait here
+/// (rather than a shared crate both depend on) so this crate has zero
+/// dependency on `telomere` itself.
+pub trait SeedExpander {
+    /// Fill `out` with deterministic bytes derived from `seed`.
+    fn expand_into(&self, seed: &[u8], out: &mut [u8]);
+
+    /// Compute a 256-bit digest of arbitrary data (used for file integrity).
+    fn digest(&self, data: &[u8]) -> [u8; 32];
+
+    /// Return true if the first `bits` bits of H(seed) match `target`.
+    fn prefix_matches(&self, seed: &[u8], target: &[u8], bits: usize) -> bool;
+}
+
+/// BLAKE3 XOF-based expander. See `telomere::hasher::Blake3Expander`.
+pub struct Blake3Expander;
+
+impl SeedExpander for Blake3Expander {
+    #[inline]
+    fn expand_into(&self, seed: &[u8], out: &mut [u8]) {
+        let mut hasher = Blake3Hasher::new();
+        hasher.update(seed);
+        hasher.finalize_xof().fill(out);
+    }
+
+    #[inline]
+    fn digest(&self, data: &[u8]) -> [u8; 32] {
+        *blake3::hash(data).as_bytes()
+    }
+
+    #[inline]
+    fn prefix_matches(&self, seed: &[u8], target: &[u8], bits: usize) -> bool {
+        prefix_matches_via_expand(self, seed, target, bits)
+    }
+}
+
+/// SHA-256 counter-mode expander. See `telomere::hasher::Sha256Expander`.
+pub struct Sha256Expander;
+
+impl SeedExpander for Sha256Expander {
+    #[inline]
+    fn expand_into(&self, seed: &[u8], out: &mut [u8]) {
+        let first = Sha256::digest(seed);
+        let n = out.len().min(32);
+        out[..n].copy_from_slice(&first[..n]);
+        let mut filled = n;
+        let mut counter: u64 = 1;
+        while filled < out.len() {
+            let mut hasher = Sha256::new();
+            hasher.update(seed);
+            hasher.update(counter.to_le_bytes());
+            let chunk = hasher.finalize();
+            let take = (out.len() - filled).min(32);
+            out[filled..filled + take].copy_from_slice(&chunk[..take]);
+            filled += take;
+            counter += 1;
+        }
+    }
+
+    #[inline]
+    fn digest(&self, data: &[u8]) -> [u8; 32] {
+        Sha256::digest(data).into()
+    }
+
+    #[inline]
+    fn prefix_matches(&self, seed: &[u8], target: &[u8], bits: usize) -> bool {
+        prefix_matches_via_expand(self, seed, target, bits)
+    }
+}
+
+fn prefix_matches_via_expand(
+    expander: &dyn SeedExpander,
+    seed: &[u8],
+    target: &[u8],
+    bits: usize,
+) -> bool {
+    if bits == 0 {
+        return true;
+    }
+    let bytes_needed = bits.div_ceil(8);
+    if bytes_needed > target.len() {
+        return false;
+    }
+    let mut expanded = vec![0u8; bytes_needed];
+    expander.expand_into(seed, &mut expanded);
+    let full_bytes = bits / 8;
+    if expanded[..full_bytes] != target[..full_bytes] {
+        return false;
+    }
+    let rem = bits % 8;
+    if rem == 0 {
+        return true;
+    }
+    let mask = 0xFF_u8 << (8 - rem);
+    (expanded[full_bytes] & mask) == (target[full_bytes] & mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blake3_matches_direct_xof() {
+        let mut out = [0u8; 16];
+        Blake3Expander.expand_into(b"seed", &mut out);
+        let mut hasher = Blake3Hasher::new();
+        hasher.update(b"seed");
+        let mut expected = [0u8; 16];
+        hasher.finalize_xof().fill(&mut expected);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn sha256_first_32_bytes_match_plain_digest() {
+        let mut out = vec![0u8; 32];
+        Sha256Expander.expand_into(b"seed", &mut out);
+        assert_eq!(out, Sha256::digest(b"seed").to_vec());
+    }
+
+    #[test]
+    fn prefix_matches_is_consistent_with_expand_into() {
+        let mut expanded = [0u8; 4];
+        Blake3Expander.expand_into(b"x", &mut expanded);
+        assert!(Blake3Expander.prefix_matches(b"x", &expanded, 32));
+        let mut wrong = expanded;
+        wrong[0] ^= 0xFF;
+        assert!(!Blake3Expander.prefix_matches(b"x", &wrong, 32));
+    }
+}